@@ -1,4 +1,5 @@
 use std::fs::read;
+use std::io::ErrorKind;
 use std::path::{Path, PathBuf};
 
 use async_trait::async_trait;
@@ -6,9 +7,41 @@ use async_trait::async_trait;
 use crate::code::bytes::AsBytesValue;
 use crate::errors::{CodeError, NovaXError};
 
+/// The magic bytes every WebAssembly module starts with: `\0asm`.
+const WASM_MAGIC_BYTES: [u8; 4] = [0, 97, 115, 109];
+
 /// Represents a file containing code, identified by its path.
 pub struct FileCode(PathBuf);
 
+impl FileCode {
+    /// Creates a `FileCode` from the path of a `.wasm` file. The file is read when deploying or upgrading.
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        FileCode(path.as_ref().to_path_buf())
+    }
+
+    /// Reads the file and checks it is a WebAssembly module, starting with the wasm magic bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CodeError::CodeFileNotFound` if the file doesn't exist, `CodeError::InvalidWasmCode` if it isn't a WebAssembly module,
+    /// and `CodeError::UnableToReadCodeFromFile` for any other reading failure.
+    pub fn read(&self) -> Result<Vec<u8>, NovaXError> {
+        let path = self.0.display().to_string();
+
+        let bytes = read(&self.0)
+            .map_err(|error| match error.kind() {
+                ErrorKind::NotFound => CodeError::CodeFileNotFound { path: path.clone() },
+                _ => CodeError::UnableToReadCodeFromFile
+            })?;
+
+        if !bytes.starts_with(&WASM_MAGIC_BYTES) {
+            return Err(CodeError::InvalidWasmCode { path }.into())
+        }
+
+        Ok(bytes)
+    }
+}
+
 #[async_trait]
 impl AsBytesValue for &FileCode {
     /// Asynchronously reads the content of the file identified by the path contained in `self`,
@@ -16,10 +49,9 @@ impl AsBytesValue for &FileCode {
     ///
     /// # Errors
     ///
-    /// Returns a `CodeError` wrapped in a `NovaXError` if the file cannot be read.
+    /// Returns a `CodeError` wrapped in a `NovaXError` if the file cannot be read or isn't a WebAssembly module.
     async fn into_bytes_value(self) -> Result<Vec<u8>, NovaXError> {
-        read(&self.0)
-            .map_err(|_| CodeError::UnableToReadCodeFromFile.into())
+        self.read()
     }
 }
 
@@ -33,8 +65,8 @@ impl AsBytesValue for &str {
     ///
     /// # Errors
     ///
-    /// Returns a `CodeError` wrapped in a `NovaXError` if the file cannot be read.
+    /// Returns a `CodeError` wrapped in a `NovaXError` if the file cannot be read or isn't a WebAssembly module.
     async fn into_bytes_value(self) -> Result<Vec<u8>, NovaXError> {
-        FileCode(Path::new(self).to_path_buf()).into_bytes_value().await
+        FileCode::new(self).into_bytes_value().await
     }
-}
\ No newline at end of file
+}
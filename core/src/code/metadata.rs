@@ -0,0 +1,85 @@
+use multiversx_sc::types::CodeMetadata;
+
+/// A builder for the `CodeMetadata` of a contract, expressing its flags fluently.
+///
+/// The default value is upgradeable and readable, as most contracts are expected to be, while `CodeMetadata::default()` has no flag set.
+///
+/// ```
+/// # use novax::code::CodeMetadataBuilder;
+/// let metadata = CodeMetadataBuilder::default()
+///     .payable_by_sc(true)
+///     .build();
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CodeMetadataBuilder {
+    upgradeable: bool,
+    readable: bool,
+    payable: bool,
+    payable_by_sc: bool
+}
+
+impl Default for CodeMetadataBuilder {
+    fn default() -> Self {
+        Self {
+            upgradeable: true,
+            readable: true,
+            payable: false,
+            payable_by_sc: false
+        }
+    }
+}
+
+impl CodeMetadataBuilder {
+    /// Sets whether the contract can be upgraded by its owner.
+    pub fn upgradeable(mut self, upgradeable: bool) -> Self {
+        self.upgradeable = upgradeable;
+        self
+    }
+
+    /// Sets whether other contracts can read the contract's storage.
+    pub fn readable(mut self, readable: bool) -> Self {
+        self.readable = readable;
+        self
+    }
+
+    /// Sets whether the contract can receive EGLD and ESDTs outside of its payable endpoints.
+    pub fn payable(mut self, payable: bool) -> Self {
+        self.payable = payable;
+        self
+    }
+
+    /// Sets whether the contract can receive EGLD and ESDTs from other contracts outside of its payable endpoints.
+    pub fn payable_by_sc(mut self, payable_by_sc: bool) -> Self {
+        self.payable_by_sc = payable_by_sc;
+        self
+    }
+
+    /// Builds the `CodeMetadata`, to be passed to a deployment or an upgrade.
+    pub fn build(self) -> CodeMetadata {
+        let mut metadata = CodeMetadata::DEFAULT;
+
+        if self.upgradeable {
+            metadata |= CodeMetadata::UPGRADEABLE;
+        }
+
+        if self.readable {
+            metadata |= CodeMetadata::READABLE;
+        }
+
+        if self.payable {
+            metadata |= CodeMetadata::PAYABLE;
+        }
+
+        if self.payable_by_sc {
+            metadata |= CodeMetadata::PAYABLE_BY_SC;
+        }
+
+        metadata
+    }
+}
+
+impl From<CodeMetadataBuilder> for CodeMetadata {
+    fn from(value: CodeMetadataBuilder) -> Self {
+        value.build()
+    }
+}
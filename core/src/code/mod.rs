@@ -1,7 +1,9 @@
 mod bytes;
 mod file;
 mod deploy;
+mod metadata;
 
 pub use bytes::AsBytesValue;
 pub use file::FileCode;
-pub use deploy::DeployData;
\ No newline at end of file
+pub use deploy::DeployData;
+pub use metadata::CodeMetadataBuilder;
\ No newline at end of file
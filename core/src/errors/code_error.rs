@@ -8,7 +8,9 @@ use crate::errors::novax_error::NovaXError;
 ///
 /// - `UnableToReadCodeFromFile`: This variant represents an error that occurs when the system is
 ///   unable to read the smart contract code from a specified file. This might be due to the file
-///   not existing, permissions issues, or other file system related errors.
+///   not being readable, permissions issues, or other file system related errors.
+/// - `CodeFileNotFound`: The code file doesn't exist at the provided path.
+/// - `InvalidWasmCode`: The code file is empty or isn't a WebAssembly module, as it doesn't start with the wasm magic bytes.
 ///
 /// # Conversion to NovaXError
 ///
@@ -18,6 +20,18 @@ use crate::errors::novax_error::NovaXError;
 pub enum CodeError {
     /// This error occurs when the system is unable to read contract code from a file.
     UnableToReadCodeFromFile,
+
+    /// This error occurs when the code file doesn't exist at the provided path.
+    CodeFileNotFound {
+        /// The path of the missing file.
+        path: String
+    },
+
+    /// This error occurs when the code file is empty or isn't a WebAssembly module.
+    InvalidWasmCode {
+        /// The path of the invalid file.
+        path: String
+    },
 }
 
 impl From<CodeError> for NovaXError {
@@ -13,6 +13,9 @@ use crate::error::data::DataError;
 /// - `CannotParseQueryResult`: This error occurs when it is impossible to decode the result
 ///     into a managed type, as encountered in functions like `parse_query_return_string_data`
 ///     and `parse_query_return_bytes_data`.
+/// - `InvalidAmount`: This error occurs when `parse_amount` is given a string which is not a decimal number.
+/// - `TooManyDecimalsInAmount`: This error occurs when `parse_amount` is given an amount having more
+///   fractional digits than the token's decimals.
 ///
 /// # Example
 /// ```
@@ -24,6 +27,10 @@ use crate::error::data::DataError;
 pub enum UtilsError {
     /// Represents an error case where the result cannot be decoded into a managed type.
     CannotParseQueryResult,
+    /// Represents an error case where the amount is not a decimal number, such as "1,5" or "-1".
+    InvalidAmount { amount: String },
+    /// Represents an error case where the amount cannot be represented with the token's decimals without rounding.
+    TooManyDecimalsInAmount { amount: String, decimals: u32 },
 }
 
 /// Provides a conversion from `UtilsError` to `DataError`.
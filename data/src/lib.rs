@@ -18,7 +18,8 @@
 //!
 //! - **Data Parsing and Error Handling**:
 //!   Utility functions like `parse_query_return_string_data` and `parse_query_return_bytes_data` are provided to
-//!   parse and decode data from blockchain queries. Comprehensive error types like `DataError`, `AddressError`, and
+//!   parse and decode data from blockchain queries. `format_amount` and `parse_amount` convert between amounts in a token's smallest
+//!   denomination and their human-readable decimal representation. Comprehensive error types like `DataError`, `AddressError`, and
 //!   `UtilsError` centralize error handling, making error propagation and management straightforward.
 //!
 //! # Usage
@@ -54,7 +55,9 @@ mod utils;
 pub use error::*;
 pub use crate::types::native::NativeConvertible;
 pub use crate::types::managed::ManagedConvertible;
-pub use crate::types::address::Address;
+pub use crate::types::address::{Address, ESDT_SYSTEM_SC_ADDRESS_BYTES, SYSTEM_ACCOUNT_ADDRESS_BYTES};
 pub use crate::types::payment::Payment;
+pub use crate::types::raw_bytes::RawBytes;
 pub use crate::utils::parse_query_return_data::parse_query_return_string_data;
-pub use crate::utils::parse_query_return_data::parse_query_return_bytes_data;
\ No newline at end of file
+pub use crate::utils::parse_query_return_data::parse_query_return_bytes_data;
+pub use crate::utils::amount::{format_amount, format_amount_rounded, parse_amount};
\ No newline at end of file
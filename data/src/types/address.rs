@@ -1,5 +1,7 @@
+use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::ops::Deref;
+use std::str::FromStr;
 use multiversx_sc::api::ManagedTypeApi;
 use multiversx_sc::types::ManagedAddress;
 use multiversx_sc_codec::{DecodeError, TopDecode, TopDecodeInput};
@@ -13,6 +15,15 @@ use crate::error::DataError;
 use crate::types::managed::ManagedConvertible;
 use crate::types::native::NativeConvertible;
 
+/// The bytes of the ESDT system smart contract's address, which issues and manages the tokens.
+pub const ESDT_SYSTEM_SC_ADDRESS_BYTES: [u8; 32] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 255, 255
+];
+
+/// The bytes of the system account's address, made of `0xff` bytes only, which holds the metadata of the ESDT tokens in every shard.
+pub const SYSTEM_ACCOUNT_ADDRESS_BYTES: [u8; 32] = [255; 32];
+
 /// A struct representing a blockchain address.
 /// This struct provides various utility methods for working with addresses,
 /// including conversions from and to Bech32 string representations and byte arrays.
@@ -26,6 +37,9 @@ use crate::types::native::NativeConvertible;
 /// # Debugging
 /// Debug printouts are supported.
 ///
+/// # Display and parsing
+/// `Display` writes the bech32 representation, and `FromStr` parses it, allowing `format!("{address}")` and `"erd1...".parse::<Address>()`.
+///
 /// # Examples
 ///
 /// Basic usage:
@@ -51,6 +65,8 @@ impl PartialEq for Address {
     }
 }
 
+impl Eq for Address {}
+
 /// The `Address` struct provides an abstraction over a blockchain address,
 /// with various utility methods for working with addresses.
 impl Address {
@@ -126,8 +142,60 @@ impl Address {
     pub fn to_bytes(&self) -> [u8; 32] {
         self.0.to_bytes()
     }
+
+    /// Returns the zero address, made of zero bytes only, such as the receiver of a smart contract deployment.
+    ///
+    /// # Example
+    /// ```
+    /// # use novax_data::Address;
+    /// assert_eq!(Address::zero().to_bytes(), [0; 32]);
+    /// ```
+    pub fn zero() -> Address {
+        Address::from_bytes([0; 32])
+    }
+
+    /// Returns the address of the ESDT system smart contract, living on the metachain, which issues and manages the tokens.
+    ///
+    /// # Example
+    /// ```
+    /// # use novax_data::Address;
+    /// let bech32 = Address::esdt_system_sc().to_bech32_string().unwrap();
+    /// assert_eq!(bech32, "erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls8a5w6u");
+    /// ```
+    pub fn esdt_system_sc() -> Address {
+        Address::from_bytes(ESDT_SYSTEM_SC_ADDRESS_BYTES)
+    }
+
+    /// Returns the address of the system account, made of `0xff` bytes only, which holds the metadata of the ESDT tokens in every shard.
+    pub fn system_account() -> Address {
+        Address::from_bytes(SYSTEM_ACCOUNT_ADDRESS_BYTES)
+    }
+}
+
+
+/// Writes the bech32 representation of the address, such as "erd1qqqqqqqqqqqqqpgq7ykazrzd905zvnlr88dpfw06677lxe9w0n4suz00uh".
+///
+/// The conversion of 32 bytes to bech32 can't fail in practice, the hex representation is written in this case so formatting never fails.
+/// Use `to_bech32_string` to handle the error explicitly.
+impl Display for Address {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.to_bech32_string() {
+            Ok(bech32) => write!(f, "{bech32}"),
+            Err(_) => self.to_bytes()
+                .iter()
+                .try_for_each(|byte| write!(f, "{byte:02x}"))
+        }
+    }
 }
 
+/// Parses a bech32 address, as `Address::from_bech32_string` does.
+impl FromStr for Address {
+    type Err = DataError;
+
+    fn from_str(bech32: &str) -> Result<Self, Self::Err> {
+        Address::from_bech32_string(bech32)
+    }
+}
 
 impl Deref for Address {
     type Target = multiversx_sdk::data::address::Address;
@@ -292,4 +360,34 @@ mod tests {
         assert_eq!(error, expected);
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn test_display() {
+        let address = Address::from_bech32_string("erd1qqqqqqqqqqqqqpgq7ykazrzd905zvnlr88dpfw06677lxe9w0n4suz00uh").unwrap();
+
+        assert_eq!(format!("{address}"), "erd1qqqqqqqqqqqqqpgq7ykazrzd905zvnlr88dpfw06677lxe9w0n4suz00uh");
+    }
+
+    #[test]
+    fn test_from_str_valid_address() {
+        let address = "erd1an4xpn58j7ymd58m2jznr32t0vmas75egrdfa8mta6fzvqn9tkxq4jvghn".parse::<Address>().unwrap();
+
+        assert_eq!(address, Address::from_bech32_string("erd1an4xpn58j7ymd58m2jznr32t0vmas75egrdfa8mta6fzvqn9tkxq4jvghn").unwrap());
+    }
+
+    #[test]
+    fn test_from_str_invalid_address() {
+        let str = "erd1an4xpn58j7ymd58m2jznr32t";
+        let error = str.parse::<Address>().unwrap_err();
+
+        let expected = DataError::Address(AddressError::InvalidBech32String { invalid_value: str.to_string() });
+
+        assert_eq!(error, expected);
+    }
+
+    #[test]
+    fn test_system_addresses() {
+        assert_eq!(Address::zero().to_bech32_string().unwrap(), "erd1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq6gq4hu");
+        assert_eq!(Address::esdt_system_sc().to_bech32_string().unwrap(), "erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls8a5w6u");
+        assert_eq!(Address::system_account().to_bech32_string().unwrap(), "erd1lllllllllllllllllllllllllllllllllllllllllllllllllllsckry7t");
+    }
+}
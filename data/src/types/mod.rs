@@ -15,4 +15,5 @@ pub mod tuples;
 pub mod ignore_value;
 pub mod code_metadata;
 pub mod empty;
-pub mod bytes;
\ No newline at end of file
+pub mod bytes;
+pub mod raw_bytes;
@@ -1,6 +1,6 @@
 use crate::types::native::NativeConvertible;
 use crate::types::managed::ManagedConvertible;
-use multiversx_sc_codec::TopEncodeMulti;
+use multiversx_sc_codec::{DecodeError, TopEncodeMulti};
 
 macro_rules! multi_value_native_convertible_impl {
         ($(($mv_struct:ident $len:tt $($n:tt $name:ident $native:ident)+) )+) => {
@@ -12,6 +12,10 @@ macro_rules! multi_value_native_convertible_impl {
                 fn to_native(&self) -> Self::Native {
                     ($((self.0).$n.to_native()),+)
                 }
+
+                fn try_to_native(&self) -> Result<Self::Native, DecodeError> {
+                    Ok(($((self.0).$n.try_to_native()?),+))
+                }
             }
         
             impl<$($name: TopEncodeMulti, $native: ManagedConvertible<$name>,)+> ManagedConvertible<$mv_struct<$($name,)+>> for ($($native,)+) {
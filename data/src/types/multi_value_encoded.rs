@@ -1,5 +1,5 @@
 use multiversx_sc::api::{ManagedTypeApi};
-use multiversx_sc::codec::{TopDecodeMulti, TopEncodeMulti};
+use multiversx_sc::codec::{DecodeError, TopDecodeMulti, TopEncodeMulti};
 use multiversx_sc::types::MultiValueEncoded;
 use multiversx_sc_scenario::api::StaticApi;
 use crate::types::managed::ManagedConvertible;
@@ -16,6 +16,18 @@ impl<M: ManagedTypeApi, T: NativeConvertible + TopDecodeMulti + Clone> NativeCon
 
         result
     }
+
+    fn try_to_native(&self) -> Result<Self::Native, DecodeError> {
+        let mut raw = self.to_arg_buffer().to_raw_args_vec();
+
+        let mut result: Self::Native = vec![];
+        while !raw.is_empty() {
+            let value = T::multi_decode(&mut raw)?;
+            result.push(value.try_to_native()?);
+        }
+
+        Ok(result)
+    }
 }
 
 impl<C, T> ManagedConvertible<MultiValueEncoded<StaticApi, T>> for Vec<C>
@@ -36,9 +48,12 @@ where
 
 #[cfg(test)]
 mod tests {
+    use multiversx_sc::codec::multi_types::MultiValue2;
+    use multiversx_sc::codec::TopDecodeMulti;
     use multiversx_sc::types::{ManagedBuffer, MultiValueEncoded};
     use multiversx_sc_scenario::api::StaticApi;
     use crate::types::managed::ManagedConvertible;
+    use crate::types::native::NativeConvertible;
 
     #[test]
     fn test_vec_to_multi_value_encoded() {
@@ -60,4 +75,30 @@ mod tests {
             expected_multi_value_encoded
         )
     }
+
+    #[test]
+    fn test_try_to_native_multi_value_encoded() {
+        let managed: MultiValueEncoded<StaticApi, MultiValue2<u64, ManagedBuffer<StaticApi>>> = vec![
+            (1u64, String::from("first")),
+            (2u64, String::from("second"))
+        ].to_managed();
+
+        let result = managed.try_to_native().unwrap();
+
+        let expected = vec![
+            (1u64, String::from("first")),
+            (2u64, String::from("second"))
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_try_to_native_multi_value_encoded_invalid_item() {
+        let managed = MultiValueEncoded::<StaticApi, u64>::multi_decode(&mut vec![vec![1u8; 9]]).unwrap();
+
+        let result = managed.try_to_native();
+
+        assert_eq!(result.map_err(|error| error.message_str()), Err("input too long"));
+    }
 }
\ No newline at end of file
@@ -18,10 +18,19 @@ multiversx_sc::derive_imports!();
 /// # Methods
 /// - `to_native`: Performs the conversion from the managed type to the specified
 /// native Rust type.
+/// - `try_to_native`: Performs the same conversion, returning an error if a lazily decoded value is invalid.
 pub trait NativeConvertible {
     /// The native Rust type to which the managed type will be converted.
     type Native;
 
     /// Converts the managed type to the specified native Rust type.
     fn to_native(&self) -> Self::Native;
+
+    /// Converts the managed type to the specified native Rust type, returning an error if a value still undecoded is invalid.
+    ///
+    /// Types such as `MultiValueEncoded` only store their raw arguments when decoded, their items being decoded during the conversion.
+    /// The default implementation calls `to_native`, which cannot fail for types decoded eagerly.
+    fn try_to_native(&self) -> Result<Self::Native, multiversx_sc::codec::DecodeError> {
+        Ok(self.to_native())
+    }
 }
@@ -1,5 +1,5 @@
 use multiversx_sc_codec::multi_types::OptionalValue;
-use multiversx_sc_codec::{NestedDecode, NestedEncode, TopDecode, TopEncode, TopEncodeMulti};
+use multiversx_sc_codec::{DecodeError, NestedDecode, NestedEncode, TopDecode, TopEncode, TopEncodeMulti};
 use crate::types::managed::ManagedConvertible;
 use crate::types::native::NativeConvertible;
 
@@ -17,6 +17,13 @@ impl<T: Clone + NativeConvertible> NativeConvertible for OptionalValue<T> {
     fn to_native(&self) -> Self::Native {
        self.clone().into_option().to_native()
     }
+
+    fn try_to_native(&self) -> Result<Self::Native, DecodeError> {
+        self.clone()
+            .into_option()
+            .map(|value| value.try_to_native())
+            .transpose()
+    }
 }
 
 impl<N, T> ManagedConvertible<Option<T>> for Option<N>
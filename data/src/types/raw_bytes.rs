@@ -0,0 +1,62 @@
+use multiversx_sc_codec::{DecodeErrorHandler, TopDecodeInput, TopDecodeMulti, TopDecodeMultiInput};
+use crate::types::native::NativeConvertible;
+
+/// A marker output type keeping the raw bytes of every returned value, without decoding them into a managed type.
+///
+/// This is useful when the return type of an endpoint is dynamic, or to decode the values manually with another schema:
+///
+/// ```ignore
+/// let result = executor.sc_call::<RawBytes>(&to, "getValues".to_string(), vec![], gas_limit, BigUint::from(0u8), vec![]).await?;
+/// let raw_values: Option<Vec<Vec<u8>>> = result.result;
+/// ```
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct RawBytes(pub Vec<Vec<u8>>);
+
+impl TopDecodeMulti for RawBytes {
+    fn multi_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeMultiInput,
+        H: DecodeErrorHandler
+    {
+        let mut values = Vec::new();
+        while input.has_next() {
+            let value = input.next_value_input(h)?;
+            values.push(value.into_boxed_slice_u8().into_vec());
+        }
+
+        Ok(RawBytes(values))
+    }
+}
+
+impl NativeConvertible for RawBytes {
+    type Native = Vec<Vec<u8>>;
+
+    fn to_native(&self) -> Self::Native {
+        self.0.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use multiversx_sc_codec::TopDecodeMulti;
+    use crate::types::native::NativeConvertible;
+    use crate::types::raw_bytes::RawBytes;
+
+    #[test]
+    fn test_multi_decode_keeps_raw_values() {
+        let mut input = vec![vec![], vec![0, 1, 2], "test".as_bytes().to_vec()];
+        let result = RawBytes::multi_decode(&mut input).unwrap().to_native();
+
+        let expected = vec![vec![], vec![0, 1, 2], "test".as_bytes().to_vec()];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_multi_decode_no_value() {
+        let mut input: Vec<Vec<u8>> = vec![];
+        let result = RawBytes::multi_decode(&mut input).unwrap().to_native();
+
+        assert!(result.is_empty());
+    }
+}
@@ -0,0 +1,176 @@
+use std::str::FromStr;
+
+use num_bigint::BigUint;
+
+use crate::error::DataError;
+use crate::error::UtilsError;
+
+/// Formats an amount in the smallest denomination of a token as a human-readable decimal number, without losing any digit.
+///
+/// Trailing zeros of the fractional part are removed, and so is the decimal point if the amount is a whole number.
+///
+/// # Examples
+///
+/// ```rust
+/// # use num_bigint::BigUint;
+/// # use novax_data::format_amount;
+/// assert_eq!(format_amount(&BigUint::from(1500000000000000000u64), 18), "1.5");
+/// assert_eq!(format_amount(&BigUint::from(25u8), 6), "0.000025");
+/// assert_eq!(format_amount(&BigUint::from(1000000u64), 6), "1");
+/// ```
+pub fn format_amount(value: &BigUint, decimals: u32) -> String {
+    let digits = value.to_string();
+    let decimals = decimals as usize;
+
+    if decimals == 0 {
+        return digits
+    }
+
+    let padded_digits = format!("{digits:0>width$}", width = decimals + 1);
+    let (integer_part, fractional_part) = padded_digits.split_at(padded_digits.len() - decimals);
+    let fractional_part = fractional_part.trim_end_matches('0');
+
+    if fractional_part.is_empty() {
+        integer_part.to_string()
+    } else {
+        format!("{integer_part}.{fractional_part}")
+    }
+}
+
+/// Formats an amount as `format_amount` does, keeping at most `max_fractional_digits` digits after the decimal point.
+///
+/// The amount is rounded half up, for example to display a balance with 4 digits.
+///
+/// # Examples
+///
+/// ```rust
+/// # use num_bigint::BigUint;
+/// # use novax_data::format_amount_rounded;
+/// assert_eq!(format_amount_rounded(&BigUint::from(1234567890000000000u64), 18, 4), "1.2346");
+/// assert_eq!(format_amount_rounded(&BigUint::from(999990000000000000u64), 18, 2), "1");
+/// ```
+pub fn format_amount_rounded(value: &BigUint, decimals: u32, max_fractional_digits: u32) -> String {
+    if max_fractional_digits >= decimals {
+        return format_amount(value, decimals)
+    }
+
+    let divisor = BigUint::from(10u8).pow(decimals - max_fractional_digits);
+    let rounded = (value + &divisor / 2u8) / divisor;
+
+    format_amount(&rounded, max_fractional_digits)
+}
+
+/// Parses a human-readable decimal number, such as "1.5", into an amount in the smallest denomination of a token having `decimals` decimals.
+///
+/// The amount is never rounded: an error is returned if it has more significant fractional digits than `decimals`.
+/// Signs, exponents, separators and a missing integer or fractional part, as in ".5" or "1.", are rejected.
+///
+/// # Errors
+/// - `UtilsError::InvalidAmount` if `amount` is not a valid decimal number.
+/// - `UtilsError::TooManyDecimalsInAmount` if `amount` cannot be represented with `decimals` decimals.
+///
+/// # Examples
+///
+/// ```rust
+/// # use num_bigint::BigUint;
+/// # use novax_data::parse_amount;
+/// assert_eq!(parse_amount("1.5", 18).unwrap(), BigUint::from(1500000000000000000u64));
+/// assert!(parse_amount("0.0000001", 6).is_err());
+/// ```
+pub fn parse_amount(amount: &str, decimals: u32) -> Result<BigUint, DataError> {
+    let invalid_amount = || -> DataError {
+        UtilsError::InvalidAmount { amount: amount.to_string() }.into()
+    };
+
+    let (integer_part, fractional_part) = match amount.split_once('.') {
+        Some((integer_part, fractional_part)) => (integer_part, fractional_part),
+        None => (amount, "")
+    };
+
+    let is_digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+
+    if integer_part.is_empty() || !is_digits(integer_part) || !is_digits(fractional_part) || amount.ends_with('.') {
+        return Err(invalid_amount())
+    }
+
+    let fractional_part = fractional_part.trim_end_matches('0');
+
+    if fractional_part.len() > decimals as usize {
+        return Err(UtilsError::TooManyDecimalsInAmount { amount: amount.to_string(), decimals }.into())
+    }
+
+    let digits = format!("{integer_part}{fractional_part:0<width$}", width = decimals as usize);
+
+    BigUint::from_str(&digits).map_err(|_| invalid_amount())
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use crate::error::{DataError, UtilsError};
+    use crate::utils::amount::{format_amount, format_amount_rounded, parse_amount};
+
+    #[test]
+    fn test_format_amount() {
+        assert_eq!(format_amount(&BigUint::from(1500000000000000000u64), 18), "1.5");
+        assert_eq!(format_amount(&BigUint::from(123456789000000000000u128), 18), "123.456789");
+        assert_eq!(format_amount(&BigUint::from(1u8), 18), "0.000000000000000001");
+    }
+
+    #[test]
+    fn test_format_amount_whole_number() {
+        assert_eq!(format_amount(&BigUint::from(0u8), 18), "0");
+        assert_eq!(format_amount(&BigUint::from(1000000u64), 6), "1");
+        assert_eq!(format_amount(&BigUint::from(42u8), 0), "42");
+    }
+
+    #[test]
+    fn test_format_amount_rounded() {
+        assert_eq!(format_amount_rounded(&BigUint::from(1234567890000000000u64), 18, 4), "1.2346");
+        assert_eq!(format_amount_rounded(&BigUint::from(1234417890000000000u64), 18, 4), "1.2344");
+        assert_eq!(format_amount_rounded(&BigUint::from(999990000000000000u64), 18, 2), "1");
+        assert_eq!(format_amount_rounded(&BigUint::from(1500000u64), 6, 0), "2");
+    }
+
+    #[test]
+    fn test_format_amount_rounded_more_digits_than_decimals() {
+        assert_eq!(format_amount_rounded(&BigUint::from(1234u16), 2, 4), "12.34");
+    }
+
+    #[test]
+    fn test_parse_amount() {
+        assert_eq!(parse_amount("1.5", 18), Ok(BigUint::from(1500000000000000000u64)));
+        assert_eq!(parse_amount("0.000000000000000001", 18), Ok(BigUint::from(1u8)));
+        assert_eq!(parse_amount("42", 0), Ok(BigUint::from(42u8)));
+        assert_eq!(parse_amount("10", 6), Ok(BigUint::from(10000000u64)));
+    }
+
+    #[test]
+    fn test_parse_amount_trailing_zeros() {
+        assert_eq!(parse_amount("1.500000", 2), Ok(BigUint::from(150u8)));
+    }
+
+    #[test]
+    fn test_parse_amount_format_amount_round_trip() {
+        let value = BigUint::from(123456789012345678901u128);
+
+        assert_eq!(parse_amount(&format_amount(&value, 18), 18), Ok(value));
+    }
+
+    #[test]
+    fn test_parse_amount_too_many_decimals() {
+        let expected: Result<BigUint, DataError> = Err(UtilsError::TooManyDecimalsInAmount { amount: "0.0000001".to_string(), decimals: 6 }.into());
+
+        assert_eq!(parse_amount("0.0000001", 6), expected);
+    }
+
+    #[test]
+    fn test_parse_amount_invalid() {
+        for amount in ["", ".", ".5", "1.", "-1", "+1", "1.2.3", "1,5", "1e18", " 1", "abc"] {
+            let expected: Result<BigUint, DataError> = Err(UtilsError::InvalidAmount { amount: amount.to_string() }.into());
+
+            assert_eq!(parse_amount(amount, 18), expected, "{amount}");
+        }
+    }
+}
@@ -1 +1,2 @@
-pub mod parse_query_return_data;
\ No newline at end of file
+pub mod parse_query_return_data;
+pub mod amount;
\ No newline at end of file
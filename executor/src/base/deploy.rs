@@ -15,6 +15,9 @@ use crate::TopDecodeMulti;
 #[async_trait]
 pub trait DeployExecutor: Send + Sync {
     /// Executes a smart contract deployment step asynchronously.
+    ///
+    /// On success, returns a tuple containing the address of the newly deployed contract and the `CallResult`
+    /// holding the decoded return value of the contract's constructor.
     async fn sc_deploy<
         OutputManaged
     >(
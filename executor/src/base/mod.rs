@@ -1,3 +1,4 @@
 pub mod query;
 pub mod transaction;
-pub mod deploy;
\ No newline at end of file
+pub mod deploy;
+pub mod upgrade;
\ No newline at end of file
@@ -9,6 +9,11 @@ use novax_data::{Address, NativeConvertible};
 
 use crate::call_result::CallResult;
 use crate::error::executor::ExecutorError;
+use crate::error::transaction::TransactionError;
+use crate::network::transaction::models::transaction_on_network::TransactionOnNetwork;
+use crate::utils::transaction::builtin_functions::{CHANGE_OWNER_ADDRESS_FUNCTION, CHANGE_OWNER_ADDRESS_GAS_COST, CLAIM_DEVELOPER_REWARDS_FUNCTION, CLAIM_DEVELOPER_REWARDS_GAS_COST, get_builtin_function_gas_limit};
+use crate::utils::transaction::egld_transfer::TransferGasConfig;
+use crate::utils::transaction::receiver::ToReceiver;
 use crate::utils::transaction::token_transfer::TokenTransfer;
 
 /// A trait defining the necessary operations for executing smart contract transactions.
@@ -28,6 +33,145 @@ pub trait TransactionExecutor: Send + Sync {
     ) -> Result<CallResult<OutputManaged::Native>, ExecutorError>
         where
             OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync;
+
+    /// Returns whether the results of the calls should not be decoded, in which case `sc_call` returns a `CallResult` with a `None` result.
+    ///
+    /// The default implementation returns `false`.
+    async fn should_skip_deserialization(&self) -> bool {
+        false
+    }
+
+    /// Returns the address sending the calls, if known, for example to target the sender itself with a built-in function.
+    ///
+    /// The default implementation returns `None`.
+    async fn get_sender_address(&self) -> Option<Address> {
+        None
+    }
+
+    /// Estimates the gas limit of a smart contract call with the specified parameters, without executing it.
+    ///
+    /// The default implementation returns `TransactionError::GasEstimationNotSupported`.
+    async fn estimate_gas(
+        &self,
+        _to: &Address,
+        _function: String,
+        _arguments: Vec<Vec<u8>>,
+        _egld_value: BigUint,
+        _esdt_transfers: Vec<TokenTransfer>
+    ) -> Result<u64, ExecutorError> {
+        Err(TransactionError::GasEstimationNotSupported.into())
+    }
+
+    /// Returns the gas parameters used to compute the gas limit of the transactions which don't execute any code, such as `send_egld`.
+    ///
+    /// The default implementation returns `TransferGasConfig::default()`, holding the mainnet's values.
+    async fn get_transfer_gas_config(&self) -> TransferGasConfig {
+        TransferGasConfig::default()
+    }
+
+    /// Executes a smart contract call as `sc_call` does, the receiver being either an `Address` or a bech32 string.
+    ///
+    /// An invalid bech32 string leads to an `ExecutorError::DataError`, before anything is executed.
+    async fn sc_call_to<OutputManaged, Receiver>(
+        &mut self,
+        to: &Receiver,
+        function: String,
+        arguments: Vec<Vec<u8>>,
+        gas_limit: u64,
+        egld_value: BigUint,
+        esdt_transfers: Vec<TokenTransfer>
+    ) -> Result<CallResult<OutputManaged::Native>, ExecutorError>
+        where
+            OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync,
+            Receiver: ToReceiver + Send + Sync + ?Sized
+    {
+        let to = to.to_receiver()?;
+
+        self.sc_call::<OutputManaged>(
+            &to,
+            function,
+            arguments,
+            gas_limit,
+            egld_value,
+            esdt_transfers
+        ).await
+    }
+
+    /// Sends `amount` EGLD to `to` without calling any function, and returns the executed transaction.
+    ///
+    /// The optional `data` is sent as-is in the transaction's data field, for example as a note for the receiver.
+    /// The gas limit is the exact cost of a plain transfer, computed from `get_transfer_gas_config`: the network's minimum gas limit,
+    /// plus its gas per byte of `data`, plus the extra gas of a guarded sender.
+    async fn send_egld(
+        &mut self,
+        to: &Address,
+        amount: BigUint,
+        data: Option<String>
+    ) -> Result<TransactionOnNetwork, ExecutorError> {
+        let data = data.unwrap_or_default();
+        let gas_limit = self.get_transfer_gas_config().await.get_egld_transfer_gas_limit(&data);
+
+        // Without arguments nor ESDT transfers, the function name is the transaction's data field, as-is.
+        let result = self.sc_call::<()>(
+            to,
+            data,
+            vec![],
+            gas_limit,
+            amount,
+            vec![]
+        ).await?;
+
+        Ok(result.response)
+    }
+
+    /// Sends the developer rewards accumulated by `contract` to its owner, through the `ClaimDeveloperRewards` built-in function,
+    /// and returns the executed transaction. The sender must be the contract's owner.
+    ///
+    /// The transaction is sent to the contract itself, with a gas limit covering the function's cost and the data field, priced by `get_transfer_gas_config`.
+    async fn claim_developer_rewards(
+        &mut self,
+        contract: &Address
+    ) -> Result<TransactionOnNetwork, ExecutorError> {
+        let gas_config = self.get_transfer_gas_config().await;
+        let gas_limit = get_builtin_function_gas_limit(&gas_config, CLAIM_DEVELOPER_REWARDS_FUNCTION, CLAIM_DEVELOPER_REWARDS_GAS_COST);
+
+        let result = self.sc_call::<()>(
+            contract,
+            CLAIM_DEVELOPER_REWARDS_FUNCTION.to_string(),
+            vec![],
+            gas_limit,
+            BigUint::from(0u8),
+            vec![]
+        ).await?;
+
+        Ok(result.response)
+    }
+
+    /// Transfers the ownership of `contract` to `new_owner`, through the `ChangeOwnerAddress` built-in function,
+    /// and returns the executed transaction. The sender must be the contract's owner.
+    ///
+    /// The transaction is sent to the contract itself, with a gas limit covering the function's cost and the data field, priced by `get_transfer_gas_config`.
+    async fn change_owner(
+        &mut self,
+        contract: &Address,
+        new_owner: &Address
+    ) -> Result<TransactionOnNetwork, ExecutorError> {
+        let arguments = vec![new_owner.to_bytes().to_vec()];
+        let data = format!("{CHANGE_OWNER_ADDRESS_FUNCTION}@{}", hex::encode(new_owner.to_bytes()));
+        let gas_config = self.get_transfer_gas_config().await;
+        let gas_limit = get_builtin_function_gas_limit(&gas_config, &data, CHANGE_OWNER_ADDRESS_GAS_COST);
+
+        let result = self.sc_call::<()>(
+            contract,
+            CHANGE_OWNER_ADDRESS_FUNCTION.to_string(),
+            arguments,
+            gas_limit,
+            BigUint::from(0u8),
+            vec![]
+        ).await?;
+
+        Ok(result.response)
+    }
 }
 
 /// An implementation of `TransactionExecutor` trait for types wrapped in `Arc<Mutex<T>>`.
@@ -61,4 +205,37 @@ impl<T: TransactionExecutor> TransactionExecutor for Arc<Mutex<T>> {
             ).await
         }
     }
+
+    /// Returns whether the underlying `TransactionExecutor` skips the decoding of the results.
+    async fn should_skip_deserialization(&self) -> bool {
+        self.lock().await.should_skip_deserialization().await
+    }
+
+    /// Returns the sender address of the underlying `TransactionExecutor`.
+    async fn get_sender_address(&self) -> Option<Address> {
+        self.lock().await.get_sender_address().await
+    }
+
+    /// Returns the gas parameters of the underlying `TransactionExecutor`.
+    async fn get_transfer_gas_config(&self) -> TransferGasConfig {
+        self.lock().await.get_transfer_gas_config().await
+    }
+
+    /// Estimates the gas limit of a smart contract call using the underlying `TransactionExecutor` implementation.
+    async fn estimate_gas(
+        &self,
+        to: &Address,
+        function: String,
+        arguments: Vec<Vec<u8>>,
+        egld_value: BigUint,
+        esdt_transfers: Vec<TokenTransfer>
+    ) -> Result<u64, ExecutorError> {
+        self.lock().await.estimate_gas(
+            to,
+            function,
+            arguments,
+            egld_value,
+            esdt_transfers
+        ).await
+    }
 }
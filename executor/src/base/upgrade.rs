@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use multiversx_sc::types::CodeMetadata;
+use num_bigint::BigUint;
+use tokio::sync::Mutex;
+
+use novax_data::{Address, NativeConvertible};
+
+use crate::call_result::CallResult;
+use crate::error::executor::ExecutorError;
+use crate::TopDecodeMulti;
+
+/// A trait defining the contract for executing smart contract upgrade operations asynchronously.
+#[async_trait]
+pub trait UpgradeExecutor: Send + Sync {
+    /// Upgrades the smart contract deployed at `contract_address` with the provided code asynchronously.
+    ///
+    /// On success, returns the `CallResult` holding the decoded return value of the contract's `upgrade` endpoint.
+    #[allow(clippy::too_many_arguments)]
+    async fn sc_upgrade<
+        OutputManaged
+    >(
+        &mut self,
+        contract_address: &Address,
+        bytes: Vec<u8>,
+        code_metadata: CodeMetadata,
+        egld_value: BigUint,
+        arguments: Vec<Vec<u8>>,
+        gas_limit: u64
+    ) -> Result<CallResult<OutputManaged::Native>, ExecutorError>
+        where
+            OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync;
+}
+
+/// An implementation of `UpgradeExecutor` for `Arc<Mutex<T>>` where `T: UpgradeExecutor`.
+/// This wrapper allows for thread-safe, shared ownership of an upgrade executor.
+#[async_trait]
+impl<T: UpgradeExecutor> UpgradeExecutor for Arc<Mutex<T>> {
+    /// Executes a smart contract upgrade asynchronously, delegating to the inner `UpgradeExecutor`.
+    async fn sc_upgrade<
+        OutputManaged
+    >(
+        &mut self,
+        contract_address: &Address,
+        bytes: Vec<u8>,
+        code_metadata: CodeMetadata,
+        egld_value: BigUint,
+        arguments: Vec<Vec<u8>>,
+        gas_limit: u64
+    ) -> Result<CallResult<OutputManaged::Native>, ExecutorError>
+        where
+            OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
+    {
+        {
+            let mut locked = self.lock().await;
+            locked.sc_upgrade::<OutputManaged>(contract_address, bytes, code_metadata, egld_value, arguments, gas_limit).await
+        }
+    }
+}
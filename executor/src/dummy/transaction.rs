@@ -7,11 +7,12 @@ use novax_data::{Address, NativeConvertible};
 
 use crate::base::deploy::DeployExecutor;
 use crate::base::transaction::TransactionExecutor;
+use crate::base::upgrade::UpgradeExecutor;
 use crate::call_result::CallResult;
 use crate::error::dummy::DummyExecutorError;
 use crate::error::executor::ExecutorError;
 use crate::utils::transaction::data::{SendableTransaction, SendableTransactionConvertible};
-use crate::utils::transaction::deploy::get_deploy_call_input;
+use crate::utils::transaction::deploy::{get_deploy_call_input, get_upgrade_call_input};
 use crate::utils::transaction::normalization::NormalizationInOut;
 use crate::utils::transaction::token_transfer::TokenTransfer;
 
@@ -26,8 +27,11 @@ pub type DummyDeployExecutor = DummyExecutor<SendableTransaction>;
 pub struct DummyExecutor<Tx: SendableTransactionConvertible> {
     /// Holds the transaction details.
     pub tx: Option<Tx>,
-    /// Optionally holds the caller address.
-    pub caller: Option<Address>
+    /// Optionally holds the caller address, used as the sender of the captured calls.
+    /// If `None`, the receiver of each call is used as its sender.
+    pub caller: Option<Address>,
+    /// The gas limit returned by `estimate_gas`, 0 by default.
+    pub gas_estimate: u64
 }
 
 impl<Tx: SendableTransactionConvertible + Clone> DummyExecutor<Tx> {
@@ -50,9 +54,29 @@ impl<Tx: SendableTransactionConvertible> DummyExecutor<Tx> {
     pub fn new(caller: &Option<Address>) -> DummyExecutor<Tx> {
         DummyExecutor {
             tx: None,
-            caller: caller.clone()
+            caller: caller.clone(),
+            gas_estimate: 0
         }
     }
+
+    /// Sets the caller address used as the sender of the calls captured afterward.
+    pub fn with_caller(mut self, caller: Address) -> Self {
+        self.caller = Some(caller);
+        self
+    }
+
+    /// Changes the caller address used as the sender of the calls captured afterward.
+    ///
+    /// Unlike `with_caller`, this doesn't consume the executor, so a single executor can capture calls from several senders across a test.
+    pub fn set_caller(&mut self, caller: Option<Address>) {
+        self.caller = caller;
+    }
+
+    /// Sets the gas limit returned by `estimate_gas`, for deterministic tests.
+    pub fn with_gas_estimate(mut self, gas_estimate: u64) -> Self {
+        self.gas_estimate = gas_estimate;
+        self
+    }
 }
 
 #[async_trait]
@@ -96,11 +120,35 @@ impl TransactionExecutor for DummyExecutor<SendableTransaction> {
 
         Ok(dummy_result)
     }
+
+    /// No transaction is executed, so there is no result to decode.
+    async fn should_skip_deserialization(&self) -> bool {
+        true
+    }
+
+    /// Returns the caller address, if any.
+    async fn get_sender_address(&self) -> Option<Address> {
+        self.caller.clone()
+    }
+
+    /// Returns the configured gas estimate, without capturing the call.
+    async fn estimate_gas(
+        &self,
+        _to: &Address,
+        _function: String,
+        _arguments: Vec<Vec<u8>>,
+        _egld_value: BigUint,
+        _esdt_transfers: Vec<TokenTransfer>
+    ) -> Result<u64, ExecutorError> {
+        Ok(self.gas_estimate)
+    }
 }
 
 #[async_trait]
 impl DeployExecutor for DummyExecutor<SendableTransaction> {
     /// Captures the smart contract deployment details.
+    ///
+    /// Since nothing is deployed, the returned address is always the zero address (`Address::default()`).
     async fn sc_deploy<
         OutputManaged
     >(
@@ -134,4 +182,42 @@ impl DeployExecutor for DummyExecutor<SendableTransaction> {
 
         Ok((Address::default(), deploy_result))
     }
-}
\ No newline at end of file
+}
+
+#[async_trait]
+impl UpgradeExecutor for DummyExecutor<SendableTransaction> {
+    /// Captures the smart contract upgrade details.
+    async fn sc_upgrade<
+        OutputManaged
+    >(
+        &mut self,
+        contract_address: &Address,
+        bytes: Vec<u8>,
+        code_metadata: CodeMetadata,
+        egld_value: BigUint,
+        arguments: Vec<Vec<u8>>,
+        gas_limit: u64
+    ) -> Result<CallResult<OutputManaged::Native>, ExecutorError>
+        where
+            OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
+    {
+        let upgrade_call_input = get_upgrade_call_input(
+            contract_address,
+            bytes,
+            code_metadata,
+            egld_value,
+            arguments,
+            gas_limit
+        );
+
+        self.sc_call::<OutputManaged>(
+            &upgrade_call_input.to,
+            upgrade_call_input.function,
+            upgrade_call_input.arguments,
+            upgrade_call_input.gas_limit,
+            upgrade_call_input.egld_value,
+            upgrade_call_input.esdt_transfers
+        )
+            .await
+    }
+}
@@ -6,6 +6,7 @@ use crate::error::dummy::DummyExecutorError;
 use crate::error::gateway::GatewayError;
 use crate::error::mock_deploy::MockDeployError;
 use crate::error::mock_transaction::MockTransactionError;
+use crate::error::signer::SignerError;
 use crate::error::transaction::TransactionError;
 use crate::error::wallet::WalletError;
 use crate::SimulationError;
@@ -46,6 +47,40 @@ pub enum ExecutorError {
 
     Transaction(TransactionError),
     Wallet(WalletError),
+
+    /// Wraps errors returned by a `Signer` while signing a transaction.
+    Signer(SignerError),
+
+    /// Wraps an error returned by a call labeled with `set_call_label`, to attribute the failure to a business operation.
+    /// `ExecutorError::unlabeled` returns the wrapped error.
+    Labeled {
+        /// The label of the failed call, such as "swap".
+        label: String,
+        /// The error returned by the call.
+        error: Box<ExecutorError>
+    },
+}
+
+impl ExecutorError {
+    /// Returns the error without its label, if any, allowing to match on the actual error whether the call was labeled or not.
+    pub fn unlabeled(&self) -> &ExecutorError {
+        match self {
+            ExecutorError::Labeled { error, .. } => error.unlabeled(),
+            error => error
+        }
+    }
+
+    /// Wraps the error in `ExecutorError::Labeled` if a label is provided, unless it is already labeled.
+    #[cfg(feature = "network")]
+    pub(crate) fn with_label(self, label: Option<&str>) -> ExecutorError {
+        match (label, self) {
+            (_, error @ ExecutorError::Labeled { .. }) | (None, error) => error,
+            (Some(label), error) => ExecutorError::Labeled {
+                label: label.to_string(),
+                error: Box::new(error)
+            }
+        }
+    }
 }
 
 /// An implementation of the `From` trait to allow for easy conversions from `DataError` to `ExecutorError`.
@@ -28,6 +28,58 @@ pub enum GatewayError {
     /// Occurs when there is a problem parsing the network configuration data fetched from the gateway.
     CannotParseNetworkConfig,
 
+    /// Error encountered when attempting to fetch the status of a shard from the `/network/status/{shard}` endpoint.
+    CannotFetchNetworkStatus {
+        /// The shard whose status was requested.
+        shard: u32
+    },
+
+    /// Occurs when there is a problem parsing the status of a shard fetched from the gateway.
+    CannotParseNetworkStatus {
+        /// The shard whose status was requested.
+        shard: u32
+    },
+
+    /// Error that occurs when fetching the balance of a token from the `/address/{address}/esdt/{token}` endpoint.
+    CannotFetchEsdtBalance {
+        /// The blockchain address whose balance was requested.
+        address: String,
+        /// The identifier of the token whose balance was requested.
+        token_identifier: String
+    },
+
+    /// Represents an error when parsing the token balance fetched from the gateway.
+    CannotParseEsdtBalance {
+        /// The blockchain address whose balance was requested.
+        address: String,
+        /// The identifier of the token whose balance was requested.
+        token_identifier: String
+    },
+
+    /// Occurs when a balance returned by the gateway is not a valid unsigned integer.
+    CannotParseBalance {
+        /// The balance as returned by the gateway.
+        balance: String
+    },
+
+    /// Error that occurs when querying the properties of a token from the ESDT system smart contract.
+    CannotFetchTokenProperties {
+        /// The identifier of the token whose properties were requested.
+        token_identifier: String
+    },
+
+    /// Represents an error when parsing the token properties returned by the ESDT system smart contract.
+    CannotParseTokenProperties {
+        /// The identifier of the token whose properties were requested.
+        token_identifier: String
+    },
+
+    /// Indicates that the ESDT system smart contract doesn't know the requested token.
+    TokenNotFound {
+        /// The identifier of the token whose properties were requested.
+        token_identifier: String
+    },
+
     /// Represents an error when simulating a transaction through the gateway.
     CannotSimulateTransaction,
 
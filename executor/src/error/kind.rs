@@ -0,0 +1,221 @@
+use novax_data::DataError;
+use serde::{Deserialize, Serialize};
+
+use crate::error::gateway::GatewayError;
+use crate::error::network::NetworkQueryError;
+use crate::error::transaction::TransactionError;
+use crate::{ExecutorError, SimulationError};
+
+/// A coarse classification of an `ExecutorError`, allowing to decide how to handle it without matching every variant.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ExecutorErrorKind {
+    /// The request didn't get a readable response, for example because of a connection failure. Retrying may succeed.
+    Http,
+
+    /// The response was received but couldn't be parsed or decoded. This usually denotes a bug or an unexpected response format.
+    Deserialization,
+
+    /// The gateway answered with an error, for example when rejecting a transaction. Retrying the same request is unlikely to succeed.
+    GatewayRejected,
+
+    /// The transaction was executed, but the smart contract failed or didn't return the expected result.
+    SmartContract,
+
+    /// The transaction couldn't be signed, because of the signer or of the wallet's keys.
+    Signing,
+
+    /// The wait for a sent transaction stopped, because of the timeout or a cancellation. The transaction may still be executed.
+    Interrupted,
+
+    /// The provided inputs are invalid, such as an invalid address or conflicting payments. Nothing was sent.
+    InvalidInput,
+
+    /// Any other error, such as errors specific to the mock and dummy executors.
+    Other
+}
+
+impl ExecutorError {
+    /// Returns the kind of this error, allowing to branch the retry logic on it.
+    pub fn kind(&self) -> ExecutorErrorKind {
+        match self {
+            ExecutorError::NetworkQuery(error) => get_network_query_error_kind(error),
+            ExecutorError::Gateway(error) => get_gateway_error_kind(error),
+            ExecutorError::Simulation(error) => get_simulation_error_kind(error),
+            ExecutorError::DataError(error) => get_data_error_kind(error),
+            ExecutorError::Transaction(error) => get_transaction_error_kind(error),
+            ExecutorError::Wallet(_) | ExecutorError::Signer(_) => ExecutorErrorKind::Signing,
+            ExecutorError::Dummy(_)
+                | ExecutorError::Date(_)
+                | ExecutorError::MockDeploy(_)
+                | ExecutorError::MockTransaction(_) => ExecutorErrorKind::Other,
+            ExecutorError::Labeled { error, .. } => error.kind(),
+        }
+    }
+}
+
+fn get_network_query_error_kind(error: &NetworkQueryError) -> ExecutorErrorKind {
+    match error {
+        NetworkQueryError::ErrorWhileSendingRequest { .. } => ExecutorErrorKind::Http,
+        NetworkQueryError::CannotDeserializeVmValuesResponse => ExecutorErrorKind::Deserialization,
+        NetworkQueryError::ErrorInResponse { .. } => ExecutorErrorKind::GatewayRejected,
+        NetworkQueryError::EmptyArgs
+            | NetworkQueryError::CannotSerializeVmValuesRequestBody => ExecutorErrorKind::InvalidInput,
+    }
+}
+
+fn get_gateway_error_kind(error: &GatewayError) -> ExecutorErrorKind {
+    match error {
+        GatewayError::CannotFetchAddressInfo { .. }
+            | GatewayError::CannotFetchNetworkConfig
+            | GatewayError::CannotFetchNetworkStatus { .. }
+            | GatewayError::CannotFetchEsdtBalance { .. }
+            | GatewayError::CannotFetchTokenProperties { .. }
+            | GatewayError::CannotSimulateTransaction => ExecutorErrorKind::Http,
+        GatewayError::CannotParseAddressInfo { .. }
+            | GatewayError::CannotParseNetworkConfig
+            | GatewayError::CannotParseNetworkStatus { .. }
+            | GatewayError::CannotParseEsdtBalance { .. }
+            | GatewayError::CannotParseBalance { .. }
+            | GatewayError::CannotParseTokenProperties { .. }
+            | GatewayError::CannotParseSimulationResponse => ExecutorErrorKind::Deserialization,
+        GatewayError::NoDataForAddressInfo { .. }
+            | GatewayError::TokenNotFound { .. } => ExecutorErrorKind::GatewayRejected,
+    }
+}
+
+fn get_simulation_error_kind(error: &SimulationError) -> ExecutorErrorKind {
+    match error {
+        SimulationError::ErrorInTx { .. } => ExecutorErrorKind::GatewayRejected,
+        SimulationError::NoSmartContractResult => ExecutorErrorKind::SmartContract,
+    }
+}
+
+fn get_data_error_kind(error: &DataError) -> ExecutorErrorKind {
+    match error {
+        DataError::Address(_) => ExecutorErrorKind::InvalidInput,
+        DataError::Utils(_) => ExecutorErrorKind::Deserialization,
+    }
+}
+
+fn get_transaction_error_kind(error: &TransactionError) -> ExecutorErrorKind {
+    match error {
+        TransactionError::ErrorWhileSendingTheTransaction
+            | TransactionError::ErrorWhileGettingTransactionOnNetwork { .. }
+            | TransactionError::ErrorWhileGettingTransactionProcessStatus { .. } => ExecutorErrorKind::Http,
+        TransactionError::CannotDeserializeTransactionSendingResponse { .. }
+            | TransactionError::CannotDeserializeTransactionOnNetworkResponse { .. }
+            | TransactionError::CannotDeserializeTransactionProcessStatusResponse { .. }
+            | TransactionError::CannotDecodeSmartContractResult { .. }
+            | TransactionError::CannotDecodeBase64
+            | TransactionError::CannotDecodeTopic
+            | TransactionError::WrongTopicsCountForSignalErrorEvent
+            | TransactionError::WrongTopicsCountForTransferEvent { .. } => ExecutorErrorKind::Deserialization,
+        TransactionError::FailedToSendTheTransaction { .. }
+            | TransactionError::TransactionRejectedInBatch { .. } => ExecutorErrorKind::GatewayRejected,
+        TransactionError::NoSmartContractResult { .. }
+            | TransactionError::SmartContractExecutionError { .. }
+            | TransactionError::SmartContractExecutionFailed { .. }
+            | TransactionError::TransactionFailed { .. }
+            | TransactionError::NoSCDeployLogInTheResponse => ExecutorErrorKind::SmartContract,
+        TransactionError::TimeoutWhenRetrievingTransactionOnNetwork { .. }
+            | TransactionError::StuckPending { .. }
+            | TransactionError::WaitingForTransactionCancelled { .. } => ExecutorErrorKind::Interrupted,
+        TransactionError::EgldAndEsdtPaymentsDetected
+            | TransactionError::CannotEncodeString { .. }
+            | TransactionError::CannotEncodeU64 { .. }
+            | TransactionError::CannotEncodeTransfer
+            | TransactionError::CannotSerializeTransactionData
+            | TransactionError::InvalidTokenIdentifier { .. }
+            | TransactionError::DryRunNotSupported
+            | TransactionError::GasEstimationNotSupported
+            | TransactionError::GasPriceBelowNetworkMinimum { .. }
+            | TransactionError::InsufficientBalance { .. }
+            | TransactionError::InvalidGasPriceMultiplier { .. }
+            | TransactionError::InvalidGasEstimationMultiplier { .. }
+            | TransactionError::ConflictingRelayerAddress { .. } => ExecutorErrorKind::InvalidInput,
+        TransactionError::MissingBatchResult { .. } => ExecutorErrorKind::Other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use novax_data::{AddressError, DataError};
+
+    use crate::error::kind::ExecutorErrorKind;
+    use crate::error::transaction::TransactionError;
+    use crate::{ExecutorError, GatewayError, SignerError};
+
+    #[test]
+    fn test_kind_http() {
+        let error: ExecutorError = GatewayError::CannotFetchNetworkConfig.into();
+
+        assert_eq!(error.kind(), ExecutorErrorKind::Http);
+    }
+
+    #[test]
+    fn test_kind_deserialization() {
+        let error: ExecutorError = TransactionError::CannotDeserializeTransactionOnNetworkResponse { response: "".to_string() }.into();
+
+        assert_eq!(error.kind(), ExecutorErrorKind::Deserialization);
+    }
+
+    #[test]
+    fn test_kind_gateway_rejected() {
+        let error: ExecutorError = TransactionError::FailedToSendTheTransaction { message: "invalid nonce".to_string() }.into();
+
+        assert_eq!(error.kind(), ExecutorErrorKind::GatewayRejected);
+    }
+
+    #[test]
+    fn test_kind_signing() {
+        let error: ExecutorError = SignerError::SigningFailed { message: "".to_string() }.into();
+
+        assert_eq!(error.kind(), ExecutorErrorKind::Signing);
+    }
+
+    #[test]
+    fn test_kind_invalid_input() {
+        let error: ExecutorError = DataError::Address(AddressError::CannotConvertToBech32String).into();
+
+        assert_eq!(error.kind(), ExecutorErrorKind::InvalidInput);
+    }
+
+    #[cfg(feature = "network")]
+    #[test]
+    fn test_kind_labeled() {
+        let error = ExecutorError::from(GatewayError::CannotFetchNetworkConfig).with_label(Some("swap"));
+
+        assert_eq!(error.kind(), ExecutorErrorKind::Http);
+    }
+
+    #[cfg(feature = "network")]
+    #[test]
+    fn test_with_label() {
+        let error: ExecutorError = GatewayError::CannotFetchNetworkConfig.into();
+        let labeled = error.clone().with_label(Some("swap"));
+
+        let expected = ExecutorError::Labeled {
+            label: "swap".to_string(),
+            error: Box::new(error.clone())
+        };
+
+        assert_eq!(labeled, expected);
+        assert_eq!(labeled.unlabeled(), &error);
+    }
+
+    #[cfg(feature = "network")]
+    #[test]
+    fn test_with_label_none() {
+        let error: ExecutorError = GatewayError::CannotFetchNetworkConfig.into();
+
+        assert_eq!(error.clone().with_label(None), error);
+    }
+
+    #[cfg(feature = "network")]
+    #[test]
+    fn test_with_label_already_labeled() {
+        let error = ExecutorError::from(GatewayError::CannotFetchNetworkConfig).with_label(Some("swap"));
+
+        assert_eq!(error.clone().with_label(Some("other")), error);
+    }
+}
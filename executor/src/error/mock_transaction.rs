@@ -7,6 +7,9 @@ pub enum MockTransactionError {
     /// In the context of a `MockExecutor`, a caller's wallet address is essential for calling a contract, and if the
     /// `opt_caller` field is `None`, this error will be thrown.
     CallerAddressNotPresent,
+
+    /// This error variant is triggered when a `MockInteractor` receives a call matching none of its remaining expectations.
+    NoExpectationMatchingCall { to: String, data: String },
 }
 
 /// An implementation of the `From` trait to allow for easy conversions from `MockTransactionError` to `ExecutorError`.
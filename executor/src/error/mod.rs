@@ -7,4 +7,6 @@ pub mod transaction;
 pub mod dummy;
 pub mod wallet;
 pub mod mock_transaction;
-pub mod date;
\ No newline at end of file
+pub mod date;
+pub mod signer;
+pub mod kind;
\ No newline at end of file
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ExecutorError;
+
+/// An enumeration representing the errors that can occur when signing a transaction through a `Signer`.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub enum SignerError {
+    /// The signer was unable to produce a signature, the reason is provided in `message`.
+    SigningFailed {
+        /// A description of why the signing failed.
+        message: String
+    },
+    /// The signature of a signed transaction is not a valid hex-encoded ed25519 signature.
+    InvalidSignature {
+        /// The invalid signature, as found in the transaction.
+        signature: String
+    },
+    /// The signature produced by the signer doesn't validate against the sender's public key,
+    /// for example because the signer uses another key than the sender's one.
+    SignatureMismatch {
+        /// The bech32 address of the transaction's sender.
+        sender: String
+    }
+}
+
+impl From<SignerError> for ExecutorError {
+    fn from(value: SignerError) -> Self {
+        ExecutorError::Signer(value)
+    }
+}
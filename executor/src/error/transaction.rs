@@ -1,6 +1,8 @@
+use std::fmt::{Display, Formatter};
+
 use serde::{Deserialize, Serialize};
 
-use crate::ExecutorError;
+use crate::{ExecutorError, TransactionOnNetwork};
 
 #[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
 pub enum TransactionError {
@@ -9,11 +11,26 @@ pub enum TransactionError {
     ErrorWhileSendingTheTransaction,
     ErrorWhileGettingTransactionOnNetwork { tx_hash: String },
     CannotDeserializeTransactionOnNetworkResponse { response: String },
+    ErrorWhileGettingTransactionProcessStatus { tx_hash: String },
+    CannotDeserializeTransactionProcessStatusResponse { response: String },
     FailedToSendTheTransaction { message: String },
-    NoSmartContractResult,
+    /// The gateway accepted a batch of transactions but not the one at `index`, such as one having an invalid nonce.
+    TransactionRejectedInBatch { index: usize },
+    /// The interactor returned fewer results than the calls of a batch, none being left for the call at `index`.
+    /// This denotes a bug in the `BlockchainInteractor` implementation, not a state of the transaction.
+    MissingBatchResult { index: usize },
+    /// The transaction has no smart contract result, and the expected output cannot be decoded from empty data.
+    /// The executed transaction is kept in `response`, so its logs, gas used and fee can still be inspected.
+    NoSmartContractResult { response: Box<TransactionOnNetwork> },
     SmartContractExecutionError { status: u64, message: String },
-    TimeoutWhenRetrievingTransactionOnNetwork,
-    CannotDecodeSmartContractResult,
+    SmartContractExecutionFailed { code: String, message: String },
+    /// The transaction failed without any smart contract error event, `return_message` being the proxy's explanation, such as "out of gas", if it gave one.
+    TransactionFailed { tx_hash: String, return_message: Option<String> },
+    TimeoutWhenRetrievingTransactionOnNetwork { tx_hash: String },
+    /// The transaction stayed in the "pending" status longer than allowed by the `PendingTransactionPolicy`, for example because its gas price is too low.
+    StuckPending { tx_hash: String },
+    /// The smart contract result couldn't be decoded, `raw` holding the undecoded arguments and `reason` the decoding error.
+    CannotDecodeSmartContractResult { raw: Vec<Vec<u8>>, reason: String },
     NoSCDeployLogInTheResponse,
     CannotEncodeString { string: String },
     CannotEncodeU64 { value: u64 },
@@ -21,11 +38,102 @@ pub enum TransactionError {
     CannotSerializeTransactionData,
     CannotDecodeBase64,
     CannotDecodeTopic,
-    WrongTopicsCountForSignalErrorEvent
+    WrongTopicsCountForSignalErrorEvent,
+    WrongTopicsCountForTransferEvent { identifier: String },
+    InvalidTokenIdentifier { identifier: String },
+    DryRunNotSupported,
+    GasEstimationNotSupported,
+    GasPriceBelowNetworkMinimum { gas_price: u64, min_gas_price: u64 },
+    /// The sender's EGLD balance, `available`, cannot cover the transaction's value and maximum fee, `required`, both in the smallest unit. Nothing was sent.
+    InsufficientBalance { required: String, available: String },
+    WaitingForTransactionCancelled { tx_hash: String },
+    /// The `PendingTransactionPolicy::BumpGasPrice` multiplier is not a finite number above 1, so it wouldn't raise the gas price.
+    InvalidGasPriceMultiplier { multiplier: f64 },
+    /// The gas estimation multiplier is below 1 or not a finite number, so the estimated gas limits would be too low or unbounded.
+    InvalidGasEstimationMultiplier { multiplier: f64 },
+    /// The `relayer` address set on the executor differs from the address of its relayer signer, which signs the relayed transactions.
+    ConflictingRelayerAddress { relayer: String, relayer_signer: String }
 }
 
 impl From<TransactionError> for ExecutorError {
     fn from(value: TransactionError) -> Self {
         ExecutorError::Transaction(value)
     }
-}
\ No newline at end of file
+}
+
+/// Writes a message explaining the error and, when possible, its likely cause and how to fix it.
+impl Display for TransactionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransactionError::EgldAndEsdtPaymentsDetected => write!(f, "a call cannot send both EGLD and ESDTs: wrap the EGLD as WEGLD, or split the call"),
+            TransactionError::CannotDeserializeTransactionSendingResponse { response } => write!(f, "cannot parse the gateway's response to the sent transaction, check that the gateway URL points to a MultiversX gateway or proxy: {response}"),
+            TransactionError::ErrorWhileSendingTheTransaction => write!(f, "the request sending the transaction failed, check the network connection and the gateway URL"),
+            TransactionError::ErrorWhileGettingTransactionOnNetwork { tx_hash } => write!(f, "the request fetching the transaction {tx_hash} failed, check the network connection or retry with a retry policy"),
+            TransactionError::CannotDeserializeTransactionOnNetworkResponse { response } => write!(f, "cannot parse the gateway's response containing the transaction, the gateway may run an unsupported version: {response}"),
+            TransactionError::ErrorWhileGettingTransactionProcessStatus { tx_hash } => write!(f, "the request fetching the process status of the transaction {tx_hash} failed, check the network connection or retry with a retry policy"),
+            TransactionError::CannotDeserializeTransactionProcessStatusResponse { response } => write!(f, "cannot parse the gateway's process status response, the gateway may not support the process-status endpoint: {response}"),
+            TransactionError::FailedToSendTheTransaction { message } => write!(f, "the gateway rejected the transaction: {message}. Common causes are a wrong nonce, an insufficient balance or a gas limit above the block limit"),
+            TransactionError::TransactionRejectedInBatch { index } => write!(f, "the gateway rejected the call at index {index} of the batch, usually because of its nonce. The following calls may wait for the missing nonce"),
+            TransactionError::MissingBatchResult { index } => write!(f, "the interactor returned no result for the call at index {index} of the batch, it must return one result per call"),
+            TransactionError::NoSmartContractResult { .. } => write!(f, "the transaction has no smart contract result to decode, check that the called endpoint returns a value"),
+            TransactionError::SmartContractExecutionError { status, message } => write!(f, "the smart contract failed with status {status}: {message}"),
+            TransactionError::SmartContractExecutionFailed { code, message } => write!(f, "the smart contract returned the error code \"{code}\": {message}"),
+            TransactionError::TransactionFailed { tx_hash, return_message: Some(return_message) } => write!(f, "the transaction {tx_hash} failed: {return_message}"),
+            TransactionError::TransactionFailed { tx_hash, return_message: None } => write!(f, "the transaction {tx_hash} failed without explanation, inspect it in the explorer or simulate it to find the cause"),
+            TransactionError::TimeoutWhenRetrievingTransactionOnNetwork { tx_hash } => write!(f, "the transaction {tx_hash} wasn't executed before the timeout. It may still be executed: wait for it with `wait_for_transaction`, or increase the timeout"),
+            TransactionError::StuckPending { tx_hash } => write!(f, "the transaction {tx_hash} stayed pending for too long, its gas price may be too low or its nonce may be ahead of the account's"),
+            TransactionError::CannotDecodeSmartContractResult { raw, reason } => write!(
+                f,
+                "cannot decode the smart contract result [{}]: {reason}. The ABI used to generate the bindings may not match the deployed contract",
+                raw.iter().map(hex::encode).collect::<Vec<_>>().join(", ")
+            ),
+            TransactionError::NoSCDeployLogInTheResponse => write!(f, "the deployment transaction has no \"SCDeploy\" event, the deployment may have failed or the gateway may not return the logs"),
+            TransactionError::CannotEncodeString { string } => write!(f, "cannot encode the string \"{string}\" as a transaction argument"),
+            TransactionError::CannotEncodeU64 { value } => write!(f, "cannot encode the value {value} as a transaction argument"),
+            TransactionError::CannotEncodeTransfer => write!(f, "cannot encode the token transfers of the call"),
+            TransactionError::CannotSerializeTransactionData => write!(f, "cannot serialize the transaction to send it"),
+            TransactionError::CannotDecodeBase64 => write!(f, "cannot decode base64 data returned by the gateway"),
+            TransactionError::CannotDecodeTopic => write!(f, "cannot decode an event topic returned by the gateway, it is not valid base64 or UTF-8"),
+            TransactionError::WrongTopicsCountForSignalErrorEvent => write!(f, "the \"signalError\" event doesn't have the expected 2 topics, the gateway may run an unsupported version"),
+            TransactionError::WrongTopicsCountForTransferEvent { identifier } => write!(f, "the \"{identifier}\" event doesn't have the expected topics, the gateway may run an unsupported version"),
+            TransactionError::InvalidTokenIdentifier { identifier } => write!(f, "\"{identifier}\" is not a valid token identifier, expected a form such as \"WEGLD-abcdef\""),
+            TransactionError::DryRunNotSupported => write!(f, "the dry-run mode is not supported by this interactor or operation, disable it with `set_dry_run(false)`"),
+            TransactionError::GasEstimationNotSupported => write!(f, "gas estimation is not supported by this executor, provide a gas limit instead"),
+            TransactionError::GasPriceBelowNetworkMinimum { gas_price, min_gas_price } => write!(f, "the gas price {gas_price} is below the network's minimum gas price {min_gas_price}, the transactions would be rejected"),
+            TransactionError::InsufficientBalance { required, available } => write!(f, "the sender's balance of {available} cannot cover the {required} required for the value and the maximum fee, fund the account or lower the gas limit"),
+            TransactionError::WaitingForTransactionCancelled { tx_hash } => write!(f, "the wait for the transaction {tx_hash} was cancelled. It may still be executed: wait for it with `wait_for_transaction`"),
+            TransactionError::InvalidGasPriceMultiplier { multiplier } => write!(f, "the gas price multiplier {multiplier} must be a finite number above 1 for the replacement of a stuck transaction to have a higher gas price"),
+            TransactionError::InvalidGasEstimationMultiplier { multiplier } => write!(f, "the gas estimation multiplier {multiplier} must be a finite number of at least 1, otherwise the transactions may run out of gas"),
+            TransactionError::ConflictingRelayerAddress { relayer, relayer_signer } => write!(f, "the relayer {relayer} differs from the address of the relayer signer, {relayer_signer}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::transaction::TransactionError;
+
+    #[test]
+    fn test_display_cannot_decode_smart_contract_result() {
+        let error = TransactionError::CannotDecodeSmartContractResult {
+            raw: vec![vec![1, 2], vec![]],
+            reason: "input too long".to_string()
+        };
+
+        let expected = "cannot decode the smart contract result [0102, ]: input too long. The ABI used to generate the bindings may not match the deployed contract";
+
+        assert_eq!(error.to_string(), expected);
+    }
+
+    #[test]
+    fn test_display_gas_price_below_network_minimum() {
+        let error = TransactionError::GasPriceBelowNetworkMinimum {
+            gas_price: 1,
+            min_gas_price: 1000000000
+        };
+
+        let expected = "the gas price 1 is below the network's minimum gas price 1000000000, the transactions would be rejected";
+
+        assert_eq!(error.to_string(), expected);
+    }
+}
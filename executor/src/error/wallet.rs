@@ -2,14 +2,61 @@ use serde::{Deserialize, Serialize};
 
 use crate::ExecutorError;
 
+/// An enumeration representing the errors that can occur when loading a `Wallet`.
+///
+/// Errors occurring while signing are represented by `SignerError`.
 #[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
 pub enum WalletError {
+    /// The provided private key is not a valid hex-encoded ed25519 private key.
     InvalidPrivateKey,
-    InvalidPemFile
+
+    /// The wallet file doesn't exist.
+    FileNotFound {
+        /// The path of the missing file.
+        path: String
+    },
+
+    /// The wallet file exists but cannot be read, for example because of missing permissions.
+    CannotReadFile {
+        /// The path of the unreadable file.
+        path: String
+    },
+
+    /// The PEM contents are malformed, or their body is not a valid private key.
+    InvalidPemFile,
+
+    /// The PEM contents hold a number of entries other than one.
+    InvalidPemEntriesCount {
+        /// The number of entries found.
+        count: usize
+    },
+
+    /// The PEM entry's body doesn't have the length of a hex-encoded private key followed by its public key.
+    InvalidPemKeyLength {
+        /// The length of the entry's body.
+        length: usize
+    },
+
+    /// The address in the PEM entry's label doesn't match the one derived from its private key.
+    PemAddressMismatch {
+        /// The address found in the label.
+        expected: String,
+        /// The address derived from the private key.
+        found: String
+    },
+
+    /// The keystore JSON is malformed or uses unsupported cipher or key derivation parameters.
+    InvalidKeystoreFile,
+
+    /// The password doesn't decrypt the keystore.
+    InvalidPassword,
+
+    /// The mnemonic phrase is not a valid English BIP39 mnemonic.
+    InvalidMnemonic
 }
 
 impl From<WalletError> for ExecutorError {
     fn from(value: WalletError) -> Self {
         ExecutorError::Wallet(value)
     }
-}
\ No newline at end of file
+}
@@ -8,25 +8,34 @@
 //!   - `QueryExecutor`: An asynchronous trait for executing queries on the blockchain.
 //!   - `TransactionExecutor`: An asynchronous trait for executing transactions on the blockchain.
 //!   - `DeployExecutor`: An asynchronous trait for deploying smart contracts on the blockchain.
+//!   - `UpgradeExecutor`: An asynchronous trait for upgrading already deployed smart contracts.
 //!
 //! - **Network Interaction**:
 //!   - `BlockchainInteractor`: A trait abstracting over a blockchain interactor to allow mocking.
 //!   - `BlockchainProxy`: A trait abstracting the communication proxy to allow mocking, providing methods to interact with the blockchain gateway.
 //!   - `ProxyQueryExecutor` and `QueryNetworkExecutor`: Implementations for executing queries on the blockchain.
 //!   - `NetworkExecutor` and `BaseTransactionNetworkExecutor`: Implementations for executing transactions on the blockchain.
+//!   - `NetworkExecutorBuilder`: A builder configuring a `BaseTransactionNetworkExecutor`, every unset setting keeping its default.
+//!   - `TransactionEventsSubscriber`: A trait abstracting a push-based source of transaction events, used to confirm sent transactions without polling.
+//!   - `TransactionStatus`: A step of a transaction's execution, such as pending or cross-shard, yielded by the stream returned by `watch_transaction`.
+//!   - `RelayedExecutor`: An implementation sending relayed v3 transactions, a relayer co-signing them and paying their gas.
 //!
 //! - **Mocking Framework**:
 //!   - `MockExecutor`: A structure to help mock blockchain interactions during testing using the MultiversX Rust Testing Framework.
 //!   - `StandardMockExecutor`: An extension of `MockExecutor` providing standard mocking behaviors.
+//!   - `MockInteractor`: A `BlockchainInteractor` answering calls with scripted responses, to unit test the building of calls made through a `BaseTransactionNetworkExecutor`.
 //!
 //! - **Dummy Framework**:
 //!   - `DummyExecutor`, `DummyTransactionExecutor`, and `DummyDeployExecutor`: Implementations to assist in testing and development without actual blockchain interaction.
 //!
 //! - **Utility Types and Traits**:
 //!   - `SendableTransaction` and `SendableTransactionConvertible`: Utility types and traits to facilitate transaction handling.
+//!   - `ToReceiver`: Allows passing either an `Address` or a bech32 string as the receiver of a call.
+//!   - `NormalizationInOut`: Builds the exact data payload of a contract call, including ESDT transfers, without sending it.
 //!
 //! - **Error Handling**:
 //!   - `ExecutorError`: A comprehensive enumeration of errors that could occur during blockchain interaction, encompassing data errors, network query errors, and mock deploy errors.
+//!   - `ExecutorErrorKind`: A coarse classification of `ExecutorError`, such as HTTP, deserialization or gateway rejection errors, returned by `ExecutorError::kind`.
 //!
 //! The abstraction layers provided by this crate are designed to make it easy to implement mock or dummy executors, allowing for thorough testing and development
 //! without requiring a live blockchain network. This is especially helpful in early stages of development or in testing scenarios where the blockchain's state
@@ -37,6 +46,11 @@
 //! # Features
 //! - `async-trait`: This crate uses the [`async-trait`](https://crates.io/crates/async-trait) crate to allow for async trait methods,
 //!   enabling asynchronous blockchain interaction.
+//! - `network` (enabled by default): Compiles the executors talking to a gateway, such as `NetworkExecutor`, `QueryNetworkExecutor`,
+//!   `SimulationNetworkExecutor` and `MockInteractor`, along with the `reqwest`, `futures` and `novax-request` dependencies they use.
+//!   Disabling it keeps the transaction-building types, such as `NormalizationInOut`, `TokenTransfer` and `SendableTransaction`, and the `DummyExecutor`.
+//!   The crate still depends on `multiversx-sdk` and `multiversx-sc-scenario`, directly and through `novax-data`, which pull `reqwest` in,
+//!   so disabling the feature doesn't make it build for `wasm32-unknown-unknown`.
 //!
 //! # Error Handling
 //! Error handling in `novax-executor` is comprehensive and designed to cover a range of issues that might arise while interacting with the blockchain.
@@ -52,31 +66,67 @@ mod dummy;
 mod utils;
 
 pub use error::executor::ExecutorError;
+pub use error::kind::ExecutorErrorKind;
 pub use error::network::NetworkQueryError;
 pub use error::mock_deploy::MockDeployError;
 pub use error::mock_transaction::MockTransactionError;
 pub use error::gateway::GatewayError;
 pub use error::simulation::SimulationError;
+pub use error::signer::SignerError;
 
 pub use base::query::QueryExecutor;
 pub use base::transaction::TransactionExecutor;
 pub use base::deploy::DeployExecutor;
+pub use base::upgrade::UpgradeExecutor;
 
+#[cfg(feature = "network")]
 pub use network::query::executor::ProxyQueryExecutor;
+#[cfg(feature = "network")]
 pub use network::query::executor::QueryNetworkExecutor;
 pub use network::utils::wallet::Wallet;
+pub use network::utils::signer::{AddressSigner, Signer};
+#[cfg(feature = "network")]
 pub use network::transaction::executor::NetworkExecutor;
+#[cfg(feature = "network")]
 pub use network::transaction::executor::BaseTransactionNetworkExecutor;
+#[cfg(feature = "network")]
+pub use network::transaction::builder::NetworkExecutorBuilder;
+#[cfg(feature = "network")]
+pub use network::transaction::relayed::RelayedExecutor;
+#[cfg(feature = "network")]
 pub use network::transaction::interactor::BlockchainInteractor;
+#[cfg(feature = "network")]
+pub use network::transaction::interactor::InteractorCall;
+#[cfg(feature = "network")]
 pub use network::transaction::interactor::TransactionRefreshStrategy;
+#[cfg(feature = "network")]
+pub use network::transaction::interactor::PendingTransactionPolicy;
+#[cfg(feature = "network")]
+pub use network::transaction::subscriber::TransactionEventsSubscriber;
+#[cfg(feature = "network")]
+pub use network::transaction::status::TransactionStatus;
+#[cfg(feature = "network")]
+pub use network::utils::retry::RetryPolicy;
+#[cfg(feature = "network")]
+pub use network::utils::tracing::GatewayRequestInfo;
+#[cfg(feature = "network")]
 pub use network::query::proxy::BlockchainProxy;
+#[cfg(feature = "network")]
 pub use network::simulate::SimulationNetworkExecutor;
+#[cfg(feature = "network")]
 pub use network::simulate::BaseSimulationNetworkExecutor;
+pub use network::models::network::config::NetworkGatewayConfigData;
+pub use network::models::network::status::NetworkStatus;
+pub use network::models::address::account::AccountInfo;
+pub use network::models::address::account::METACHAIN_SHARD_ID;
+pub use network::models::token::properties::TokenProperties;
 pub use network::models::simulate::request::SimulationGatewayRequest;
 pub use network::models::simulate::response::SimulationGatewayResponse;
 pub use network::query::models::request::VmValuesQueryRequest;
+pub use network::query::models::request::BlockCoordinate;
 pub use network::query::models::response::VmValuesQueryResponseData;
 pub use network::query::models::response::VmValuesQueryResponseDataData;
+pub use network::transaction::models::send_request::SignedTransaction;
 pub use network::transaction::models::transaction_on_network::TransactionOnNetworkResponse;
 pub use network::transaction::models::transaction_on_network::TransactionOnNetwork;
 pub use network::transaction::models::transaction_on_network::TransactionOnNetworkTransaction;
@@ -86,6 +136,10 @@ pub use network::transaction::models::transaction_on_network::TransactionOnNetwo
 
 pub use mocking::executor::StandardMockExecutor;
 pub use mocking::executor::MockExecutor;
+#[cfg(feature = "network")]
+pub use mocking::interactor::MockInteractor;
+#[cfg(feature = "network")]
+pub use mocking::interactor::MockScCall;
 
 pub use dummy::transaction::DummyExecutor;
 pub use dummy::transaction::DummyTransactionExecutor;
@@ -94,6 +148,10 @@ pub use dummy::transaction::DummyDeployExecutor;
 pub use utils::transaction::data::SendableTransaction;
 pub use utils::transaction::data::SendableTransactionConvertible;
 pub use utils::transaction::token_transfer::TokenTransfer;
+pub use utils::transaction::normalization::NormalizationInOut;
+pub use utils::transaction::batch::BatchCall;
+pub use utils::transaction::receiver::ToReceiver;
+pub use utils::transaction::egld_transfer::TransferGasConfig;
 pub use utils::transaction::call_result;
 
 pub use multiversx_sc_scenario::ScenarioWorld;
@@ -20,9 +20,9 @@ use crate::call_result::CallResult;
 use crate::error::executor::ExecutorError;
 use crate::error::mock_deploy::MockDeployError;
 use crate::error::mock_transaction::MockTransactionError;
-use crate::error::transaction::TransactionError;
 use crate::{ScenarioWorld, TransactionOnNetwork};
 use crate::utils::transaction::token_transfer::TokenTransfer;
+use crate::utils::transaction::results::decode_output;
 use crate::utils::transaction::transfers::get_egld_or_esdt_transfers;
 
 /// A convenient type alias for `MockExecutor` with `String` as the generic type.
@@ -109,26 +109,29 @@ impl<A> TransactionExecutor for MockExecutor<A>
         }
 
         let raw_result_managed = tx.run();
-        let mut raw_result: Vec<Vec<u8>> = raw_result_managed
+        let raw_result: Vec<Vec<u8>> = raw_result_managed
             .into_vec()
             .iter()
             .map(|buffer| buffer.to_boxed_bytes().into_vec())
             .collect();
 
-        let Ok(output_managed) = OutputManaged::multi_decode(&mut raw_result) else {
-            return Err(TransactionError::CannotDecodeSmartContractResult.into())
-        };
+        let native_result = decode_output::<OutputManaged>(raw_result)?;
 
         let mut response = TransactionOnNetwork::default();
         response.transaction.status = "successful".to_string();
 
         let call_result = CallResult {
             response,
-            result: Some(output_managed.to_native()),
+            result: Some(native_result),
         };
 
         Ok(call_result)
     }
+
+    /// Returns the caller address, if any.
+    async fn get_sender_address(&self) -> Option<Address> {
+        self.opt_caller.as_ref().map(|caller| Address::from(caller.deref()))
+    }
 }
 
 /// Mock implementation of the `DeployExecutor` trait for testing and development purposes.
@@ -183,19 +186,17 @@ impl<A> DeployExecutor for MockExecutor<A>
         }
 
         let (new_address, raw_result_managed) = tx.run();
-        let mut raw_result: Vec<Vec<u8>> = raw_result_managed
+        let raw_result: Vec<Vec<u8>> = raw_result_managed
             .into_vec()
             .iter()
             .map(|buffer| buffer.to_boxed_bytes().into_vec())
             .collect();
 
-        let Ok(output_managed) = OutputManaged::multi_decode(&mut raw_result) else {
-            return Err(TransactionError::CannotDecodeSmartContractResult.into())
-        };
+        let native_result = decode_output::<OutputManaged>(raw_result)?;
 
         let call_result = CallResult {
             response: Default::default(),
-            result: Some(output_managed.to_native()),
+            result: Some(native_result),
         };
 
         Ok((Address::from_bytes(*new_address.as_array()), call_result))
@@ -242,16 +243,14 @@ impl<A> QueryExecutor for MockExecutor<A>
         }
 
         let raw_result_managed = tx.run();
-        let mut raw_result: Vec<Vec<u8>> = raw_result_managed
+        let raw_result: Vec<Vec<u8>> = raw_result_managed
             .into_vec()
             .iter()
             .map(|buffer| buffer.to_boxed_bytes().into_vec())
             .collect();
 
-        let Ok(output_managed) = OutputManaged::multi_decode(&mut raw_result) else {
-            return Err(TransactionError::CannotDecodeSmartContractResult.into())
-        };
+        let native_result = decode_output::<OutputManaged>(raw_result)?;
 
-        Ok(output_managed.to_native())
+        Ok(native_result)
     }
 }
\ No newline at end of file
@@ -0,0 +1,175 @@
+use async_trait::async_trait;
+use num_bigint::BigUint;
+
+use novax_data::Address;
+
+use crate::error::executor::ExecutorError;
+use crate::error::mock_transaction::MockTransactionError;
+use crate::network::transaction::interactor::{BlockchainInteractor, InteractorCall};
+use crate::network::transaction::models::transaction_on_network::{TransactionOnNetwork, TransactionOnNetworkTransactionSmartContractResult};
+use crate::network::utils::signer::Signer;
+use crate::network::utils::wallet::Wallet;
+
+/// A `BlockchainInteractor` answering the calls with scripted responses, without any network.
+///
+/// Used with `BaseTransactionNetworkExecutor::from_interactor`, it allows unit testing the building of calls:
+/// the calls are normalized by the executor as they would be for a real network, and the received ones can be asserted with `get_received_calls`.
+///
+/// Each call is answered by the first expectation it matches, which is then consumed.
+/// A call matching no expectation returns `MockTransactionError::NoExpectationMatchingCall`.
+#[derive(Clone)]
+pub struct MockInteractor<S: Signer = Wallet> {
+    signer: S,
+    expectations: Vec<MockScCall>,
+    received_calls: Vec<InteractorCall>
+}
+
+/// A scripted answer to a call received by a `MockInteractor`, created with `MockInteractor::expect_sc_call`.
+///
+/// By default, the expectation matches any call and returns a successful transaction without any smart contract result.
+#[derive(Clone, Default)]
+pub struct MockScCall {
+    to: Option<String>,
+    value: Option<BigUint>,
+    data: Option<String>,
+    response: Option<Result<TransactionOnNetwork, ExecutorError>>
+}
+
+impl<S: Signer> MockInteractor<S> {
+    /// Creates a new `MockInteractor` without any expectation, the calls being sent from the signer's address.
+    pub fn new(signer: S) -> Self {
+        MockInteractor {
+            signer,
+            expectations: vec![],
+            received_calls: vec![],
+        }
+    }
+
+    /// Adds an expectation answering a single call, returning it so it can be configured.
+    pub fn expect_sc_call(&mut self) -> &mut MockScCall {
+        self.expectations.push(MockScCall::default());
+        self.expectations.last_mut().unwrap()
+    }
+
+    /// Returns the calls received so far, in the order they were sent, with their normalized data payload.
+    pub fn get_received_calls(&self) -> &[InteractorCall] {
+        &self.received_calls
+    }
+
+    /// Returns whether every expectation has answered a call.
+    pub fn are_all_expectations_met(&self) -> bool {
+        self.expectations.is_empty()
+    }
+}
+
+impl MockScCall {
+    /// Matches only the calls sent to the given bech32 address.
+    pub fn with_to(&mut self, to: &str) -> &mut Self {
+        self.to = Some(to.to_string());
+        self
+    }
+
+    /// Matches only the calls sending the given EGLD value.
+    pub fn with_value(&mut self, value: BigUint) -> &mut Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Matches only the calls having the given normalized data payload, such as "add@0a".
+    pub fn with_data(&mut self, data: &str) -> &mut Self {
+        self.data = Some(data.to_string());
+        self
+    }
+
+    /// Answers the call with the given transaction.
+    pub fn returns(&mut self, response: TransactionOnNetwork) -> &mut Self {
+        self.response = Some(Ok(response));
+        self
+    }
+
+    /// Answers the call with a successful transaction having a smart contract result with the given data, such as "@6f6b@05".
+    pub fn returns_data(&mut self, data: &str) -> &mut Self {
+        let mut response = TransactionOnNetwork::default();
+        response.transaction.status = "success".to_string();
+        response.transaction.smart_contract_results = Some(vec![
+            TransactionOnNetworkTransactionSmartContractResult {
+                nonce: 1,
+                data: data.to_string(),
+                ..Default::default()
+            }
+        ]);
+
+        self.returns(response)
+    }
+
+    /// Answers the call with the given error.
+    pub fn returns_error(&mut self, error: ExecutorError) -> &mut Self {
+        self.response = Some(Err(error));
+        self
+    }
+
+    fn matches(&self, call: &InteractorCall) -> bool {
+        self.to.as_ref().is_none_or(|to| *to == call.to)
+            && self.value.as_ref().is_none_or(|value| *value == call.value)
+            && self.data.as_ref().is_none_or(|data| *data == call.data)
+    }
+
+    fn into_response(self) -> Result<TransactionOnNetwork, ExecutorError> {
+        self.response.unwrap_or_else(|| {
+            let mut response = TransactionOnNetwork::default();
+            response.transaction.status = "success".to_string();
+
+            Ok(response)
+        })
+    }
+}
+
+#[async_trait]
+impl<S: Signer> BlockchainInteractor for MockInteractor<S> {
+    type Signer = S;
+
+    /// Creates a new `MockInteractor` without any expectation, the gateway URL being ignored.
+    async fn new(_gateway_url: String, signer: S) -> Result<Self, ExecutorError> {
+        Ok(MockInteractor::new(signer))
+    }
+
+    async fn sc_call(
+        &mut self,
+        to: String,
+        value: BigUint,
+        data: String,
+        gas_limit: u64
+    ) -> Result<TransactionOnNetwork, ExecutorError> {
+        let call = InteractorCall {
+            to,
+            value,
+            data,
+            gas_limit,
+        };
+
+        let expectation_index = self.expectations
+            .iter()
+            .position(|expectation| expectation.matches(&call));
+
+        let Some(expectation_index) = expectation_index else {
+            let error = MockTransactionError::NoExpectationMatchingCall {
+                to: call.to.clone(),
+                data: call.data.clone()
+            };
+
+            self.received_calls.push(call);
+
+            return Err(error.into())
+        };
+
+        self.received_calls.push(call);
+
+        self.expectations
+            .remove(expectation_index)
+            .into_response()
+    }
+
+    fn get_sender_address(&self) -> Address {
+        self.signer.get_address()
+    }
+}
@@ -1 +1,3 @@
-pub mod executor;
\ No newline at end of file
+pub mod executor;
+#[cfg(feature = "network")]
+pub mod interactor;
\ No newline at end of file
@@ -1,4 +1,5 @@
 pub mod transaction;
+#[cfg(feature = "network")]
 pub mod simulate;
 pub mod models;
 pub mod utils;
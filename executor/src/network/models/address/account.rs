@@ -0,0 +1,114 @@
+use num_bigint::BigUint;
+
+use novax_data::Address;
+
+/// The shard ID of the metachain, which hosts the system smart contracts.
+pub const METACHAIN_SHARD_ID: u32 = u32::MAX;
+
+/// The state of an account, as returned by `Interactor::get_account`.
+#[derive(PartialEq, Clone, Debug)]
+pub struct AccountInfo {
+    /// The address of the account.
+    pub address: Address,
+    /// The nonce to use for the next transaction sent by the account.
+    pub nonce: u64,
+    /// The EGLD balance of the account, in the smallest denomination.
+    pub balance: BigUint,
+    /// The herotag of the account, such as "alice.elrond", or `None` if it has none.
+    pub username: Option<String>,
+    /// The base64-encoded hash of the account's code, or `None` if the account is not a smart contract.
+    pub code_hash: Option<String>,
+    /// The shard of the account, computed from its address and the network's number of shards.
+    /// The metachain's shard is `METACHAIN_SHARD_ID`.
+    pub shard: u32
+}
+
+#[cfg(feature = "network")]
+/// Computes the shard of an address, as the protocol does, from the last byte of the address and the number of shards, excluding the metachain.
+pub(crate) fn compute_shard(address: &Address, num_shards: u32) -> u32 {
+    let bytes = address.to_bytes();
+
+    if is_metachain_smart_contract(&bytes) {
+        return METACHAIN_SHARD_ID
+    }
+
+    if num_shards <= 1 {
+        return 0
+    }
+
+    let bits = u32::BITS - (num_shards - 1).leading_zeros();
+    let mask_high = (1u32 << bits) - 1;
+    let mask_low = (1u32 << (bits - 1)) - 1;
+
+    let last_byte = bytes[31] as u32;
+    let shard = last_byte & mask_high;
+
+    if shard > num_shards - 1 {
+        last_byte & mask_low
+    } else {
+        shard
+    }
+}
+
+#[cfg(feature = "network")]
+/// Smart contract addresses start with 8 zero bytes followed by the 2 bytes of the VM type.
+/// System smart contracts, living on the metachain, have 5 more zero bytes and end with the 0xffff shard bytes.
+fn is_metachain_smart_contract(bytes: &[u8; 32]) -> bool {
+    bytes[..8].iter().all(|byte| *byte == 0)
+        && bytes[10..15].iter().all(|byte| *byte == 0)
+        && bytes[30..] == [0xff, 0xff]
+}
+
+#[cfg(all(test, feature = "network"))]
+mod tests {
+    use novax_data::Address;
+
+    use crate::network::models::address::account::{compute_shard, METACHAIN_SHARD_ID};
+
+    #[test]
+    fn test_compute_shard() {
+        let mut bytes = [1u8; 32];
+
+        bytes[31] = 0x8c;
+        assert_eq!(compute_shard(&Address::from_bytes(bytes), 3), 0);
+
+        bytes[31] = 0x8d;
+        assert_eq!(compute_shard(&Address::from_bytes(bytes), 3), 1);
+
+        bytes[31] = 0x8e;
+        assert_eq!(compute_shard(&Address::from_bytes(bytes), 3), 2);
+
+        bytes[31] = 0x8f;
+        assert_eq!(compute_shard(&Address::from_bytes(bytes), 3), 1);
+    }
+
+    #[test]
+    fn test_compute_shard_single_shard() {
+        let address = Address::from_bytes([255u8; 32]);
+
+        assert_eq!(compute_shard(&address, 1), 0);
+    }
+
+    #[test]
+    fn test_compute_shard_metachain() {
+        let address = Address::from_bech32_string("erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqllls0lczs7").unwrap();
+
+        assert_eq!(compute_shard(&address, 3), METACHAIN_SHARD_ID);
+    }
+
+    #[test]
+    fn test_compute_shard_not_metachain_without_shard_bytes() {
+        let mut bytes = [0u8; 32];
+        bytes[8] = 5;
+        bytes[31] = 1;
+
+        assert_eq!(compute_shard(&Address::from_bytes(bytes), 3), 1);
+    }
+
+    #[test]
+    fn test_compute_shard_smart_contract() {
+        let address = Address::from_bech32_string("erd1qqqqqqqqqqqqqpgqtqfhy99su9xzjjrq59kpzpp25udtc9eq0n4sr90ax6").unwrap();
+
+        assert_eq!(compute_shard(&address, 3), 1);
+    }
+}
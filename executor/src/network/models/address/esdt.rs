@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use crate::network::models::generic::response::GatewayResponse;
+
+pub type AddressGatewayEsdtResponse = GatewayResponse<AddressGatewayEsdtInfo>;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressGatewayEsdtInfo {
+    pub token_data: AddressGatewayEsdtInfoTokenData
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressGatewayEsdtInfoTokenData {
+    pub token_identifier: String,
+    pub balance: String
+}
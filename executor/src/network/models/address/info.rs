@@ -12,5 +12,9 @@ pub struct AddressGatewayInfo {
 pub struct AddressGatewayInfoAccount {
     pub address: String,
     pub nonce: u64,
-    pub balance: String
+    pub balance: String,
+    #[serde(default)]
+    pub username: String,
+    #[serde(default, rename = "codeHash")]
+    pub code_hash: Option<String>
 }
\ No newline at end of file
@@ -1 +1,5 @@
-pub mod info;
\ No newline at end of file
+#[cfg(feature = "network")]
+pub mod info;
+#[cfg(feature = "network")]
+pub mod esdt;
+pub mod account;
\ No newline at end of file
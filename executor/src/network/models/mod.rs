@@ -1,4 +1,5 @@
 pub mod simulate;
 pub mod address;
 pub mod generic;
-pub mod network;
\ No newline at end of file
+pub mod network;
+pub mod token;
@@ -1,16 +1,33 @@
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "network")]
 use crate::network::models::generic::response::GatewayResponse;
 
+#[cfg(feature = "network")]
 pub type NetworkGatewayConfigResponse = GatewayResponse<NetworkGatewayConfig>;
 
+#[cfg(feature = "network")]
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct NetworkGatewayConfig {
     pub config: NetworkGatewayConfigData
 }
 
+/// The network parameters returned by the gateway's `/network/config` endpoint, used to build transactions.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct NetworkGatewayConfigData {
+    /// The chain ID, such as "1" for the mainnet, "D" for the devnet or "T" for the testnet.
     pub erd_chain_id: String,
+    /// The minimum gas price accepted by the network.
     pub erd_min_gas_price: u64,
+    /// The minimum gas limit of any transaction.
+    pub erd_min_gas_limit: u64,
+    /// The gas consumed by each byte of a transaction's data field.
+    pub erd_gas_per_data_byte: u64,
+    /// The minimum transaction version accepted by the network.
     pub erd_min_transaction_version: u8,
+    /// The number of shards, excluding the metachain.
+    #[serde(default)]
+    pub erd_num_shards_without_meta: u32,
+    /// The extra gas consumed by each transaction sent by a guarded account.
+    #[serde(default)]
+    pub erd_extra_gas_limit_guarded_tx: u64,
 }
\ No newline at end of file
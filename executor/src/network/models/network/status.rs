@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "network")]
+use crate::network::models::generic::response::GatewayResponse;
+
+#[cfg(feature = "network")]
+pub type NetworkGatewayStatusResponse = GatewayResponse<NetworkGatewayStatus>;
+
+#[cfg(feature = "network")]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NetworkGatewayStatus {
+    pub status: NetworkStatus
+}
+
+/// The state of a shard's chain returned by the gateway's `/network/status/{shard}` endpoint, used to reason about time on the network.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct NetworkStatus {
+    /// The current round of the shard.
+    pub erd_current_round: u64,
+    /// The current epoch of the shard.
+    pub erd_epoch_number: u32,
+    /// The nonce of the shard's latest block.
+    pub erd_nonce: u64,
+    /// The nonce of the shard's latest final block.
+    pub erd_highest_final_nonce: u64,
+}
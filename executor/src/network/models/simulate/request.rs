@@ -1,3 +1,4 @@
+#[cfg(feature = "network")]
 use serde::Serialize;
 
 /// A convenient struct in NovaX used to accumulate information necessary for creating a transaction simulation request.
@@ -21,6 +22,7 @@ pub struct SimulationGatewayRequest {
 
 /// Struct representing the body of a request for transaction simulation, sent to the MultiversX gateway.
 /// This structure is used to simulate transactions via the `/transaction/cost` endpoint.
+#[cfg(feature = "network")]
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SimulationGatewayRequestBody {
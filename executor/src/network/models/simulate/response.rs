@@ -18,6 +18,7 @@ pub struct SimulationGatewayResponseData {
 
     /// A collection of results from smart contracts invoked during the simulation.
     /// Each entry in the map corresponds to a smart contract result, keyed by a unique identifier.
+    #[serde(default)]
     pub smart_contract_results: SimulationGatewayResponseDataScResults
 }
 
@@ -0,0 +1,57 @@
+use num_bigint::BigUint;
+
+use novax_data::Address;
+
+/// The properties of a token, as returned by `Interactor::get_token_properties`.
+#[derive(PartialEq, Clone, Debug)]
+pub struct TokenProperties {
+    /// The identifier of the token, such as "WEGLD-bd4d79".
+    pub identifier: String,
+    /// The name of the token, such as "WrappedEGLD".
+    pub name: String,
+    /// The type of the token, such as "FungibleESDT", "SemiFungibleESDT", "NonFungibleESDT" or "MetaESDT".
+    pub token_type: String,
+    /// The owner of the token.
+    pub owner: Address,
+    /// The amount minted since the token's issuance, in the smallest denomination.
+    pub minted_value: BigUint,
+    /// The amount burnt since the token's issuance, in the smallest denomination.
+    pub burnt_value: BigUint,
+    /// The number of decimals of the token.
+    pub decimals: u8,
+    /// Whether all the transfers of the token are paused.
+    pub is_paused: bool,
+    /// Whether the owner can upgrade the token's properties.
+    pub can_upgrade: bool,
+    /// Whether new units of the token can be minted.
+    pub can_mint: bool,
+    /// Whether units of the token can be burnt.
+    pub can_burn: bool,
+    /// Whether the ownership of the token can be transferred.
+    pub can_change_owner: bool,
+    /// Whether the owner can pause all the transfers of the token.
+    pub can_pause: bool,
+    /// Whether the owner can freeze the token in a specific account.
+    pub can_freeze: bool,
+    /// Whether the owner can wipe the token from a frozen account.
+    pub can_wipe: bool,
+    /// Whether the owner can assign special roles, such as local mint or NFT create, to accounts.
+    pub can_add_special_roles: bool,
+    /// Whether the NFT create role can be transferred to another account.
+    pub can_transfer_nft_create_role: bool,
+    /// Whether the creation of new NFTs of this collection is stopped for good.
+    pub nft_create_stopped: bool,
+    /// The amount wiped from frozen accounts, in the smallest denomination.
+    pub wiped_amount: BigUint
+}
+
+impl TokenProperties {
+    /// Returns the current supply of the token, that is the minted value minus the burnt one.
+    pub fn get_supply(&self) -> BigUint {
+        if self.burnt_value > self.minted_value {
+            return BigUint::default()
+        }
+
+        &self.minted_value - &self.burnt_value
+    }
+}
@@ -7,6 +7,7 @@ use num_bigint::BigUint;
 use novax_data::{NativeConvertible, parse_query_return_string_data};
 
 use crate::{BlockchainProxy, ExecutorError, NetworkQueryError, QueryExecutor, TokenTransfer, VmValuesQueryRequest};
+use crate::network::query::models::request::BlockCoordinate;
 use crate::network::query::proxy::NetworkBlockchainProxy;
 use crate::utils::transaction::normalization::NormalizationInOut;
 
@@ -22,6 +23,8 @@ pub type ProxyQueryExecutor = QueryNetworkExecutor<NetworkBlockchainProxy>;
 pub struct QueryNetworkExecutor<Proxy: BlockchainProxy> {
     /// The URL of the gateway to the blockchain network.
     pub gateway_url: String,
+    /// The block whose state the queries are executed against, the latest one if `None`.
+    pub at_block: Option<BlockCoordinate>,
     /// A phantom data field to keep the generic `Proxy` type.
     _data: PhantomData<Proxy>
 }
@@ -37,9 +40,18 @@ impl<Proxy: BlockchainProxy> QueryNetworkExecutor<Proxy> {
     pub fn new(gateway_url: String) -> Self {
         QueryNetworkExecutor {
             gateway_url,
+            at_block: None,
             _data: PhantomData
         }
     }
+
+    /// Executes the queries against the state of the blockchain at the provided block, to read historical contract state.
+    ///
+    /// The gateway's observers have to keep historical states ("deep history" observers), otherwise queries on older blocks fail.
+    pub fn with_block(mut self, at_block: BlockCoordinate) -> Self {
+        self.at_block = Some(at_block);
+        self
+    }
 }
 
 #[async_trait]
@@ -73,6 +85,7 @@ impl<Proxy: BlockchainProxy> QueryExecutor for QueryNetworkExecutor<Proxy> {
             caller: Some(normalized.sender),
             value: Some(normalized.egld_value.to_string()),
             args: encode_arguments(&normalized.arguments),
+            at_block: self.at_block.clone(),
         };
 
         let blockchain = Proxy::new(self.gateway_url.clone());
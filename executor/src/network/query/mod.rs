@@ -1,3 +1,5 @@
+#[cfg(feature = "network")]
 pub mod executor;
 pub mod models;
+#[cfg(feature = "network")]
 pub mod proxy;
\ No newline at end of file
@@ -7,5 +7,63 @@ pub struct VmValuesQueryRequest {
     pub func_name: String,
     pub args: Vec<String>,
     pub caller: Option<String>,
-    pub value: Option<String>
-}
\ No newline at end of file
+    pub value: Option<String>,
+    /// The block whose state the query is executed against, the latest one if `None`.
+    ///
+    /// It is not part of the body but sent as a query parameter of the URL, see `BlockCoordinate::to_query_parameter`.
+    #[serde(skip)]
+    pub at_block: Option<BlockCoordinate>
+}
+
+/// Identifies a past block, to execute a query against the state of the blockchain at this block.
+///
+/// Historical queries require the gateway's observers to keep the state of past blocks ("deep history" observers).
+/// Regular observers only keep recent states, so the gateway answers with an error for older blocks.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum BlockCoordinate {
+    /// The nonce of the block.
+    Nonce(u64),
+    /// The hex-encoded hash of the block.
+    Hash(String)
+}
+
+impl BlockCoordinate {
+    /// Returns the query parameter of the `/vm-values/query` endpoint targeting this block, such as "blockNonce=100".
+    pub fn to_query_parameter(&self) -> String {
+        match self {
+            BlockCoordinate::Nonce(nonce) => format!("blockNonce={nonce}"),
+            BlockCoordinate::Hash(hash) => format!("blockHash={hash}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::network::query::models::request::{BlockCoordinate, VmValuesQueryRequest};
+
+    #[test]
+    fn test_to_query_parameter_nonce() {
+        assert_eq!(BlockCoordinate::Nonce(100).to_query_parameter(), "blockNonce=100");
+    }
+
+    #[test]
+    fn test_to_query_parameter_hash() {
+        assert_eq!(BlockCoordinate::Hash("abcdef".to_string()).to_query_parameter(), "blockHash=abcdef");
+    }
+
+    #[test]
+    fn test_serialize_request_skips_block() {
+        let request = VmValuesQueryRequest {
+            sc_address: "erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p".to_string(),
+            func_name: "getSum".to_string(),
+            args: vec![],
+            caller: None,
+            value: None,
+            at_block: Some(BlockCoordinate::Nonce(100)),
+        };
+
+        let expected = r#"{"scAddress":"erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p","funcName":"getSum","args":[],"caller":null,"value":null}"#;
+
+        assert_eq!(serde_json::to_string(&request).unwrap(), expected);
+    }
+}
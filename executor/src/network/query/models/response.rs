@@ -1,5 +1,6 @@
 use serde::Deserialize;
 
+#[cfg(feature = "network")]
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VmValuesQueryResponse {
@@ -20,7 +21,7 @@ pub struct VmValuesQueryResponseDataData {
     pub return_message: String
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "network"))]
 mod tests {
     use crate::network::query::models::response::VmValuesQueryResponse;
 
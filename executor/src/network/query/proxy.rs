@@ -2,21 +2,30 @@ use async_trait::async_trait;
 use reqwest::Client;
 
 use crate::error::executor::ExecutorError;
-use crate::network::query::models::request::VmValuesQueryRequest;
+use crate::network::query::models::request::{BlockCoordinate, VmValuesQueryRequest};
 use crate::network::query::models::response::{VmValuesQueryResponse, VmValuesQueryResponseData};
 use crate::NetworkQueryError;
 
+/// A trait abstracting the communication with the gateway's `/vm-values/query` endpoint, allowing it to be mocked.
+///
+/// Queries are read-only: no transaction is signed or sent, therefore no gas or nonce is involved.
 #[async_trait]
 pub trait BlockchainProxy: Send + Sync {
+    /// Creates a new proxy targeting the given gateway URL.
     fn new(gateway_url: String) -> Self;
 
+    /// Sends the VM query to the gateway and returns the raw response data.
+    ///
+    /// If the request's `at_block` is set, the query has to be executed against the state at this block.
     async fn execute_vmquery(
         &self,
         vm_request: &VmValuesQueryRequest,
     ) -> Result<VmValuesQueryResponseData, ExecutorError>;
 }
 
+/// The default `BlockchainProxy` implementation, sending the queries to a real gateway.
 pub struct NetworkBlockchainProxy {
+    /// The URL of the gateway to the blockchain network.
     pub gateway_url: String
 }
 
@@ -29,7 +38,7 @@ impl BlockchainProxy for NetworkBlockchainProxy {
     }
 
     async fn execute_vmquery(&self, vm_request: &VmValuesQueryRequest) -> Result<VmValuesQueryResponseData, ExecutorError> {
-        let url = format!("{}/vm-values/query", self.gateway_url);
+        let url = get_vm_query_url(&self.gateway_url, vm_request.at_block.as_ref());
 
         let json = serde_json::to_string(vm_request)
             .map_err(|_| NetworkQueryError::CannotSerializeVmValuesRequestBody)?;
@@ -55,4 +64,34 @@ impl BlockchainProxy for NetworkBlockchainProxy {
 
         Ok(response_data)
     }
-}
\ No newline at end of file
+}
+
+/// Builds the URL of the gateway's `/vm-values/query` endpoint, targeting the given block if any.
+fn get_vm_query_url(gateway_url: &str, at_block: Option<&BlockCoordinate>) -> String {
+    let url = format!("{gateway_url}/vm-values/query");
+
+    match at_block {
+        Some(at_block) => format!("{url}?{}", at_block.to_query_parameter()),
+        None => url
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::network::query::models::request::BlockCoordinate;
+    use crate::network::query::proxy::get_vm_query_url;
+
+    #[test]
+    fn test_get_vm_query_url_latest_block() {
+        let url = get_vm_query_url("https://gateway.multiversx.com", None);
+
+        assert_eq!(url, "https://gateway.multiversx.com/vm-values/query");
+    }
+
+    #[test]
+    fn test_get_vm_query_url_at_block() {
+        let url = get_vm_query_url("https://gateway.multiversx.com", Some(&BlockCoordinate::Nonce(100)));
+
+        assert_eq!(url, "https://gateway.multiversx.com/vm-values/query?blockNonce=100");
+    }
+}
@@ -11,12 +11,11 @@ use novax_request::gateway::client::GatewayClient;
 
 use crate::{ExecutorError, GatewayError, SimulationError, SimulationGatewayRequest, SimulationGatewayResponse, TransactionExecutor, TransactionOnNetwork, TransactionOnNetworkTransactionSmartContractResult};
 use crate::call_result::CallResult;
-use crate::error::transaction::TransactionError;
 use crate::network::models::simulate::request::SimulationGatewayRequestBody;
 use crate::network::utils::address::get_address_info;
 use crate::network::utils::network::get_network_config;
 use crate::utils::transaction::normalization::NormalizationInOut;
-use crate::utils::transaction::results::find_smart_contract_result;
+use crate::utils::transaction::results::{decode_output, find_smart_contract_result};
 use crate::utils::transaction::token_transfer::TokenTransfer;
 
 /// Type alias for `BaseSimulationNetworkExecutor` with the `String` type as the generic `Client`.
@@ -93,6 +92,42 @@ impl<Client: GatewayClient> BaseSimulationNetworkExecutor<Client> {
 
         Ok(results)
     }
+
+    /// Builds the simulation request of a call, the call going through the same normalization as `NetworkExecutor`.
+    fn get_simulation_request(
+        &self,
+        to: &Address,
+        function: String,
+        arguments: Vec<Vec<u8>>,
+        gas_limit: u64,
+        egld_value: BigUint,
+        esdt_transfers: Vec<TokenTransfer>
+    ) -> Result<SimulationGatewayRequest, ExecutorError> {
+        let function_name = if function.is_empty() {
+            None
+        } else {
+            Some(function)
+        };
+
+        let normalized = NormalizationInOut {
+            sender: self.sender_address.to_bech32_string()?,
+            receiver: to.to_bech32_string()?,
+            function_name,
+            arguments,
+            egld_value,
+            esdt_transfers,
+        }.normalize()?;
+
+        let simulation_data = SimulationGatewayRequest {
+            value: normalized.egld_value.to_string(),
+            receiver: normalized.receiver.clone(),
+            sender: normalized.sender.clone(),
+            gas_limit,
+            data: normalized.get_transaction_data(),
+        };
+
+        Ok(simulation_data)
+    }
 }
 
 impl<Client> Clone for BaseSimulationNetworkExecutor<Client>
@@ -123,7 +158,10 @@ impl<Client> Debug for BaseSimulationNetworkExecutor<Client>
 
 #[async_trait]
 impl<Client: GatewayClient> TransactionExecutor for BaseSimulationNetworkExecutor<Client> {
-    /// Executes a smart contract call in a simulated environment.
+    /// Executes a smart contract call in a simulated environment, without broadcasting it.
+    ///
+    /// The call goes through the same normalization as `NetworkExecutor`, so the simulated payload is identical to the one that would be sent.
+    /// The returned `CallResult`'s response holds the simulated status, the gas used and the smart contract results.
     async fn sc_call<OutputManaged>(
         &mut self,
         to: &Address,
@@ -136,32 +174,14 @@ impl<Client: GatewayClient> TransactionExecutor for BaseSimulationNetworkExecuto
         where
             OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
     {
-        let function_name = if function.is_empty() {
-            None
-        } else {
-            Some(function)
-        };
-
-        let normalized = NormalizationInOut {
-            sender: self.sender_address.to_bech32_string()?,
-            receiver: to.to_bech32_string()?,
-            function_name,
+        let simulation_data = self.get_simulation_request(
+            to,
+            function,
             arguments,
-            egld_value,
-            esdt_transfers,
-        }.normalize()?;
-
-        let normalized_egld_value = normalized.egld_value.clone();
-        let normalized_receiver = normalized.receiver.clone();
-        let normalized_sender = normalized.sender.clone();
-
-        let simulation_data = SimulationGatewayRequest {
-            value: normalized_egld_value.to_string(),
-            receiver: normalized_receiver,
-            sender: normalized_sender,
             gas_limit,
-            data: normalized.get_transaction_data(),
-        };
+            egld_value,
+            esdt_transfers
+        )?;
 
         let response = self.simulate_transaction(simulation_data).await?;
 
@@ -169,32 +189,69 @@ impl<Client: GatewayClient> TransactionExecutor for BaseSimulationNetworkExecuto
             return Err(SimulationError::ErrorInTx { code: response.code, error: response.error }.into())
         };
 
-        let scrs = data.smart_contract_results
+        let scrs: Vec<TransactionOnNetworkTransactionSmartContractResult> = data.smart_contract_results
             .into_iter()
             .map(|(hash, result)| {
                 TransactionOnNetworkTransactionSmartContractResult {
                     hash,
                     nonce: result.nonce,
                     data: result.data,
+                    sender: result.sender,
+                    receiver: result.receiver,
+                    prev_tx_hash: "".to_string(),
                 }
             })
             .collect();
 
-        let mut raw_result = find_smart_contract_result(&Some(scrs), None)?
+        let raw_result = find_smart_contract_result("", "", &Some(scrs.clone()), None)?
+            .map(|sc_result| sc_result.return_data)
             .unwrap_or_default();
 
-        let Ok(output_managed) = OutputManaged::multi_decode(&mut raw_result) else {
-            return Err(TransactionError::CannotDecodeSmartContractResult.into())
-        };
+        let native_result = decode_output::<OutputManaged>(raw_result)?;
 
         let mut response = TransactionOnNetwork::default();
         response.transaction.status = "success".to_string();
+        response.transaction.sender = self.sender_address.to_bech32_string()?;
+        response.transaction.gas_used = data.tx_gas_units;
+        response.transaction.smart_contract_results = Some(scrs);
 
         let call_result = CallResult {
             response,
-            result: Some(output_managed.to_native()),
+            result: Some(native_result),
         };
 
         Ok(call_result)
     }
+
+    /// Returns the address the calls are simulated from.
+    async fn get_sender_address(&self) -> Option<Address> {
+        Some(self.sender_address.clone())
+    }
+
+    /// Estimates the gas limit of a call by simulating it, returning the gas units it consumes.
+    async fn estimate_gas(
+        &self,
+        to: &Address,
+        function: String,
+        arguments: Vec<Vec<u8>>,
+        egld_value: BigUint,
+        esdt_transfers: Vec<TokenTransfer>
+    ) -> Result<u64, ExecutorError> {
+        let simulation_data = self.get_simulation_request(
+            to,
+            function,
+            arguments,
+            0,
+            egld_value,
+            esdt_transfers
+        )?;
+
+        let response = self.simulate_transaction(simulation_data).await?;
+
+        let Some(data) = response.data else {
+            return Err(SimulationError::ErrorInTx { code: response.code, error: response.error }.into())
+        };
+
+        Ok(data.tx_gas_units)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,553 @@
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+
+use novax_data::Address;
+
+use novax_request::gateway::reqwest::get_shared_client;
+
+use crate::error::executor::ExecutorError;
+use crate::network::models::network::config::NetworkGatewayConfigData;
+use crate::network::transaction::executor::BaseTransactionNetworkExecutor;
+use crate::network::transaction::interactor::{Interactor, PendingTransactionPolicy, SharedSigner, TransactionRefreshStrategy, TransactionSentCallback};
+use crate::network::transaction::subscriber::{SharedTransactionEventsSubscriber, TransactionEventsSubscriber};
+use crate::network::utils::retry::RetryPolicy;
+use crate::network::utils::signer::Signer;
+use crate::network::utils::tracing::{GatewayRequestCallback, GatewayRequestInfo};
+use crate::network::utils::wallet::Wallet;
+
+/// A builder for `BaseTransactionNetworkExecutor`, configuring the executor before it is created.
+///
+/// Every setting left unset keeps the executor's default, as documented on each setter.
+/// Each setting mirrors one of the executor's `set_*` methods, which can be used to change it once the executor is created.
+///
+/// ```ignore
+/// let wallet = Wallet::from_private_key("69417ce717e43d0d3a598f68b5e562d7d2a532a5a3ac1e8b3342515e0b2d950f")?;
+///
+/// let executor = NetworkExecutorBuilder::new("https://devnet-gateway.multiversx.com", wallet)
+///     .timeout(Duration::from_secs(60))
+///     .retry_policy(3, Duration::from_millis(500))
+///     .nonce_cache(true)
+///     .build()
+///     .await?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct NetworkExecutorBuilder<S: Signer = Wallet> {
+    gateway_url: String,
+    signer: S,
+    http_client: Option<reqwest::Client>,
+    network_config: Option<NetworkGatewayConfigData>,
+    settings: NetworkExecutorSettings
+}
+
+/// The settings applied to the executor once created, `None` keeping the executor's default.
+#[derive(Clone, Default, Debug)]
+struct NetworkExecutorSettings {
+    refresh_strategy: Option<TransactionRefreshStrategy>,
+    pending_transaction_policy: Option<PendingTransactionPolicy>,
+    timeout: Option<Duration>,
+    gas_estimation_multiplier: Option<f64>,
+    retry_policy: Option<RetryPolicy>,
+    chain_id: Option<String>,
+    gas_price: Option<u64>,
+    nonce_cache: bool,
+    process_status_check: bool,
+    balance_precheck: bool,
+    guardian: Option<SharedSigner>,
+    version: Option<u32>,
+    options: Option<u32>,
+    hash_signing: bool,
+    relayer_signer: Option<SharedSigner>,
+    relayer: Option<Address>,
+    sender: Option<Address>,
+    on_transaction_sent: Option<TransactionSentCallback>,
+    on_gateway_request: Option<GatewayRequestCallback>,
+    request_id: Option<String>,
+    events_subscriber: Option<SharedTransactionEventsSubscriber>,
+    cancellation_flag: Option<Arc<AtomicBool>>,
+    dry_run: bool,
+    skip_deserialization: bool
+}
+
+impl<S: Signer> NetworkExecutorBuilder<S> {
+    /// Creates a new builder for an executor sending its transactions to `gateway_url`, signed by `signer`.
+    pub fn new(gateway_url: impl Into<String>, signer: S) -> Self {
+        Self {
+            gateway_url: gateway_url.into(),
+            signer,
+            http_client: None,
+            network_config: None,
+            settings: NetworkExecutorSettings::default()
+        }
+    }
+
+    /// Sends all the requests through the provided `reqwest::Client`, instead of the client shared by all the executors.
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Uses the provided network configuration instead of fetching it from the gateway's `/network/config` endpoint, so `build` makes no request.
+    pub fn network_config(mut self, network_config: NetworkGatewayConfigData) -> Self {
+        self.network_config = Some(network_config);
+        self
+    }
+
+    /// Sets how often the gateway is polled while waiting for a sent transaction. Defaults to `TransactionRefreshStrategy::EachBlock`.
+    pub fn refresh_strategy(mut self, strategy: TransactionRefreshStrategy) -> Self {
+        self.settings.refresh_strategy = Some(strategy);
+        self
+    }
+
+    /// Sets the behavior when a sent transaction stays in the "pending" status. Defaults to `PendingTransactionPolicy::Wait`.
+    ///
+    /// `build` returns `TransactionError::InvalidGasPriceMultiplier` if the `BumpGasPrice` multiplier is not a finite number above 1.
+    pub fn pending_transaction_policy(mut self, policy: PendingTransactionPolicy) -> Self {
+        self.settings.pending_transaction_policy = Some(policy);
+        self
+    }
+
+    /// Sets the maximum duration to wait for a sent transaction to be executed. Defaults to 10 seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.settings.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the multiplier applied to the estimated gas of calls sent with a gas limit of zero. Defaults to 1.1.
+    ///
+    /// `build` returns `TransactionError::InvalidGasEstimationMultiplier` if it is below 1 or not a finite number.
+    pub fn gas_estimation_multiplier(mut self, multiplier: f64) -> Self {
+        self.settings.gas_estimation_multiplier = Some(multiplier);
+        self
+    }
+
+    /// Retries the failed idempotent gateway requests, with an exponential backoff starting at `base_delay`. By default, no request is retried.
+    pub fn retry_policy(mut self, max_retries: u32, base_delay: Duration) -> Self {
+        self.settings.retry_policy = Some(RetryPolicy::new(max_retries, base_delay));
+        self
+    }
+
+    /// Overrides the chain ID used to sign transactions. Defaults to the one fetched from the gateway.
+    pub fn chain_id(mut self, chain_id: impl Into<String>) -> Self {
+        self.settings.chain_id = Some(chain_id.into());
+        self
+    }
+
+    /// Sets the gas price of every transaction. Defaults to the network's minimum gas price.
+    ///
+    /// `build` returns `TransactionError::GasPriceBelowNetworkMinimum` if it is below the network's minimum gas price.
+    pub fn gas_price(mut self, gas_price: u64) -> Self {
+        self.settings.gas_price = Some(gas_price);
+        self
+    }
+
+    /// Enables or disables the local caching of the sender's nonce. Disabled by default.
+    pub fn nonce_cache(mut self, enabled: bool) -> Self {
+        self.settings.nonce_cache = enabled;
+        self
+    }
+
+    /// Enables or disables the check of the process status before resolving a call, waiting for cross-shard calls to complete. Disabled by default.
+    pub fn process_status_check(mut self, enabled: bool) -> Self {
+        self.settings.process_status_check = enabled;
+        self
+    }
+
+    /// Enables or disables checking the sender's balance before broadcasting each transaction. Disabled by default.
+    pub fn balance_precheck(mut self, enabled: bool) -> Self {
+        self.settings.balance_precheck = enabled;
+        self
+    }
+
+    /// Co-signs every transaction with the provided guardian. See `BaseTransactionNetworkExecutor::set_guardian`.
+    pub fn guardian<G: Signer + 'static>(mut self, guardian: G) -> Self {
+        self.settings.guardian = Some(SharedSigner(Arc::new(guardian)));
+        self
+    }
+
+    /// Sets the version of every transaction. Defaults to the network's minimum version, raised to 2 if any option is set.
+    pub fn version(mut self, version: u32) -> Self {
+        self.settings.version = Some(version);
+        self
+    }
+
+    /// Sets the options bits of every transaction, the ones required by the guardian or by hash signing being added. None by default.
+    pub fn options(mut self, options: u32) -> Self {
+        self.settings.options = Some(options);
+        self
+    }
+
+    /// Enables or disables signing the Keccak256 hash of the transactions instead of their JSON serialization. Disabled by default.
+    pub fn hash_signing(mut self, enabled: bool) -> Self {
+        self.settings.hash_signing = enabled;
+        self
+    }
+
+    /// Sends every transaction as a relayed v3 transaction, signed and paid for by the provided relayer. See `BaseTransactionNetworkExecutor::set_relayer_signer`.
+    pub fn relayer_signer<R: Signer + 'static>(mut self, relayer: R) -> Self {
+        self.settings.relayer_signer = Some(SharedSigner(Arc::new(relayer)));
+        self
+    }
+
+    /// Sets the `relayer` field of the transactions, without signing them on behalf of the relayer.
+    ///
+    /// `build` returns `TransactionError::ConflictingRelayerAddress` if a relayer signer is also set and `relayer` is not its address.
+    pub fn relayer(mut self, relayer: Address) -> Self {
+        self.settings.relayer = Some(relayer);
+        self
+    }
+
+    /// Sets the sender of the transactions, instead of the signer's address. See `BaseTransactionNetworkExecutor::set_sender`.
+    pub fn sender(mut self, sender: Address) -> Self {
+        self.settings.sender = Some(sender);
+        self
+    }
+
+    /// Sets a callback invoked with the hash of each transaction right after it is broadcast.
+    pub fn on_transaction_sent<F>(mut self, callback: F) -> Self
+        where
+            F: Fn(&str) + Send + Sync + 'static
+    {
+        self.settings.on_transaction_sent = Some(TransactionSentCallback(Arc::new(callback)));
+        self
+    }
+
+    /// Sets a callback invoked after each request sent to the gateway, with its method, URL, status and duration.
+    pub fn on_gateway_request<F>(mut self, callback: F) -> Self
+        where
+            F: Fn(&GatewayRequestInfo) + Send + Sync + 'static
+    {
+        self.settings.on_gateway_request = Some(GatewayRequestCallback(Arc::new(callback)));
+        self
+    }
+
+    /// Sets a correlation ID passed to the callback set with `on_gateway_request` for each request.
+    pub fn request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.settings.request_id = Some(request_id.into());
+        self
+    }
+
+    /// Sets a push-based source of transaction events, resolving the wait for sent transactions without polling the gateway. See `BaseTransactionNetworkExecutor::set_events_subscriber`.
+    pub fn events_subscriber<T: TransactionEventsSubscriber + 'static>(mut self, subscriber: T) -> Self {
+        self.settings.events_subscriber = Some(SharedTransactionEventsSubscriber(Arc::new(subscriber)));
+        self
+    }
+
+    /// Sets a flag allowing to stop waiting for sent transactions to be executed. See `BaseTransactionNetworkExecutor::set_cancellation_flag`.
+    pub fn cancellation_flag(mut self, flag: Arc<AtomicBool>) -> Self {
+        self.settings.cancellation_flag = Some(flag);
+        self
+    }
+
+    /// Enables or disables the dry-run mode, building and signing the transactions without broadcasting them. Disabled by default.
+    pub fn dry_run(mut self, enabled: bool) -> Self {
+        self.settings.dry_run = enabled;
+        self
+    }
+
+    /// Enables or disables skipping the decoding of the calls' results. Disabled by default.
+    pub fn skip_deserialization(mut self, enabled: bool) -> Self {
+        self.settings.skip_deserialization = enabled;
+        self
+    }
+
+    /// Creates the executor, fetching the network configuration from the gateway unless it was provided, then applies the settings.
+    pub async fn build(self) -> Result<BaseTransactionNetworkExecutor<Interactor<S>>, ExecutorError> {
+        let http_client = self.http_client
+            .unwrap_or_else(|| get_shared_client().clone());
+
+        let interactor = match self.network_config {
+            Some(network_config) => Interactor::new_with_network_config(
+                self.gateway_url,
+                self.signer,
+                http_client,
+                network_config
+            ),
+            None => Interactor::new_with_client(
+                self.gateway_url,
+                self.signer,
+                http_client
+            ).await?
+        };
+
+        self.settings.configure(BaseTransactionNetworkExecutor::from_interactor(interactor))
+    }
+}
+
+impl NetworkExecutorSettings {
+    /// Applies the settings to an already created executor.
+    fn configure<S: Signer>(self, mut executor: BaseTransactionNetworkExecutor<Interactor<S>>) -> Result<BaseTransactionNetworkExecutor<Interactor<S>>, ExecutorError> {
+        if let Some(strategy) = self.refresh_strategy {
+            executor.set_refresh_strategy(strategy);
+        }
+
+        if let Some(policy) = self.pending_transaction_policy {
+            executor.set_pending_transaction_policy(policy)?;
+        }
+
+        if let Some(timeout) = self.timeout {
+            executor.set_timeout(timeout);
+        }
+
+        if let Some(multiplier) = self.gas_estimation_multiplier {
+            executor.set_gas_estimation_multiplier(multiplier)?;
+        }
+
+        if let Some(retry_policy) = self.retry_policy {
+            executor.set_retry_policy(retry_policy.max_retries, retry_policy.base_delay);
+        }
+
+        if let Some(chain_id) = self.chain_id {
+            executor.set_chain_id(chain_id);
+        }
+
+        executor.set_nonce_cache(self.nonce_cache);
+        executor.set_process_status_check(self.process_status_check);
+        executor.set_balance_precheck(self.balance_precheck);
+        executor.set_hash_signing(self.hash_signing);
+        executor.set_dry_run(self.dry_run);
+        executor.set_skip_deserialization(self.skip_deserialization);
+
+        if let Some(version) = self.version {
+            executor.set_version(version);
+        }
+
+        if let Some(options) = self.options {
+            executor.set_options(options);
+        }
+
+        if let Some(relayer_signer) = self.relayer_signer {
+            executor.set_shared_relayer_signer(relayer_signer);
+        }
+
+        if let Some(relayer) = self.relayer {
+            executor.set_relayer(relayer)?;
+        }
+
+        if let Some(sender) = self.sender {
+            executor.set_sender(Some(sender));
+        }
+
+        executor.set_request_id(self.request_id);
+
+        if let Some(flag) = self.cancellation_flag {
+            executor.set_cancellation_flag(flag);
+        }
+
+        let interactor = executor.interactor_mut();
+
+        if let Some(guardian) = self.guardian {
+            interactor.guardian = Some(guardian);
+        }
+
+        if let Some(callback) = self.on_transaction_sent {
+            interactor.on_transaction_sent = Some(callback);
+        }
+
+        if let Some(callback) = self.on_gateway_request {
+            interactor.on_gateway_request = Some(callback);
+        }
+
+        if let Some(subscriber) = self.events_subscriber {
+            interactor.events_subscriber = Some(subscriber);
+        }
+
+        if let Some(gas_price) = self.gas_price {
+            executor.set_gas_price(gas_price)?;
+        }
+
+        Ok(executor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicBool;
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+
+    use crate::error::transaction::TransactionError;
+    use crate::ExecutorError;
+    use crate::network::transaction::builder::NetworkExecutorBuilder;
+    use crate::network::transaction::executor::BaseTransactionNetworkExecutor;
+    use crate::network::transaction::interactor::{PendingTransactionPolicy, TransactionRefreshStrategy};
+    use crate::network::transaction::interactor::tests::get_interactor;
+    use crate::network::transaction::subscriber::TransactionEventsSubscriber;
+    use crate::network::utils::wallet::Wallet;
+
+    struct NoopEventsSubscriber;
+
+    #[async_trait]
+    impl TransactionEventsSubscriber for NoopEventsSubscriber {
+        async fn wait_for_transaction(&self, _tx_hash: &str) -> Result<(), ExecutorError> {
+            Ok(())
+        }
+    }
+
+    fn get_builder() -> NetworkExecutorBuilder {
+        let wallet = Wallet::from_private_key("69417ce717e43d0d3a598f68b5e562d7d2a532a5a3ac1e8b3342515e0b2d950f").unwrap();
+
+        NetworkExecutorBuilder::new("", wallet)
+    }
+
+    fn get_other_wallet() -> Wallet {
+        Wallet::from_private_key("1648ad209d6b157a289884933e3bb30f161ec7113221ec16f87c3578b05830b0").unwrap()
+    }
+
+    #[test]
+    fn test_configure_defaults() {
+        let executor = get_builder()
+            .settings
+            .configure(BaseTransactionNetworkExecutor::from_interactor(get_interactor(None)))
+            .unwrap();
+
+        let interactor = executor.interactor();
+
+        assert!(matches!(interactor.refresh_strategy, TransactionRefreshStrategy::EachBlock));
+        assert!(matches!(interactor.pending_transaction_policy, PendingTransactionPolicy::Wait));
+        assert_eq!(interactor.timeout, Duration::from_secs(10));
+        assert_eq!(interactor.gas_estimation_multiplier, 1.1);
+        assert_eq!(interactor.retry_policy.max_retries, 0);
+        assert_eq!(interactor.chain_id_override, None);
+        assert_eq!(interactor.gas_price_override, None);
+        assert!(!interactor.nonce_cache_enabled);
+        assert!(!interactor.process_status_check);
+        assert!(!interactor.balance_precheck);
+        assert!(interactor.guardian.is_none());
+        assert_eq!(interactor.version_override, None);
+        assert_eq!(interactor.options_override, None);
+        assert!(!interactor.hash_signing);
+        assert!(interactor.relayer_signer.is_none());
+        assert_eq!(interactor.relayer, None);
+        assert_eq!(interactor.sender_override, None);
+        assert!(interactor.on_transaction_sent.is_none());
+        assert!(interactor.on_gateway_request.is_none());
+        assert_eq!(interactor.request_id, None);
+        assert!(interactor.events_subscriber.is_none());
+        assert!(interactor.cancellation_flag.is_none());
+        assert!(!executor.dry_run);
+        assert!(!executor.skip_deserialization);
+    }
+
+    #[test]
+    fn test_configure_all_settings() {
+        let other_address = get_other_wallet().get_address();
+
+        let executor = get_builder()
+            .refresh_strategy(TransactionRefreshStrategy::EachDuration(Duration::from_secs(1)))
+            .pending_transaction_policy(PendingTransactionPolicy::Fail { after: Duration::from_secs(30) })
+            .timeout(Duration::from_secs(60))
+            .gas_estimation_multiplier(1.5)
+            .retry_policy(3, Duration::from_millis(100))
+            .chain_id("chain")
+            .gas_price(2000000000)
+            .nonce_cache(true)
+            .process_status_check(true)
+            .balance_precheck(true)
+            .guardian(get_other_wallet())
+            .version(2)
+            .options(4)
+            .hash_signing(true)
+            .relayer_signer(get_other_wallet())
+            .relayer(other_address.clone())
+            .sender(other_address.clone())
+            .on_transaction_sent(|_| {})
+            .on_gateway_request(|_| {})
+            .request_id("request")
+            .events_subscriber(NoopEventsSubscriber)
+            .cancellation_flag(Arc::new(AtomicBool::new(false)))
+            .dry_run(true)
+            .skip_deserialization(true)
+            .settings
+            .configure(BaseTransactionNetworkExecutor::from_interactor(get_interactor(None)))
+            .unwrap();
+
+        let interactor = executor.interactor();
+
+        assert!(matches!(interactor.refresh_strategy, TransactionRefreshStrategy::EachDuration(duration) if duration == Duration::from_secs(1)));
+        assert!(matches!(interactor.pending_transaction_policy, PendingTransactionPolicy::Fail { after } if after == Duration::from_secs(30)));
+        assert_eq!(interactor.timeout, Duration::from_secs(60));
+        assert_eq!(interactor.gas_estimation_multiplier, 1.5);
+        assert_eq!(interactor.retry_policy.max_retries, 3);
+        assert_eq!(interactor.retry_policy.base_delay, Duration::from_millis(100));
+        assert_eq!(interactor.chain_id_override, Some("chain".to_string()));
+        assert_eq!(interactor.gas_price_override, Some(2000000000));
+        assert!(interactor.nonce_cache_enabled);
+        assert!(interactor.process_status_check);
+        assert!(interactor.balance_precheck);
+        assert_eq!(interactor.guardian.as_ref().map(|guardian| guardian.0.get_address()), Some(other_address.clone()));
+        assert_eq!(interactor.version_override, Some(2));
+        assert_eq!(interactor.options_override, Some(4));
+        assert!(interactor.hash_signing);
+        assert_eq!(interactor.relayer_signer.as_ref().map(|relayer| relayer.0.get_address()), Some(other_address.clone()));
+        assert_eq!(interactor.relayer, Some(other_address.clone()));
+        assert_eq!(interactor.sender_override, Some(other_address));
+        assert!(interactor.on_transaction_sent.is_some());
+        assert!(interactor.on_gateway_request.is_some());
+        assert_eq!(interactor.request_id, Some("request".to_string()));
+        assert!(interactor.events_subscriber.is_some());
+        assert!(interactor.cancellation_flag.is_some());
+        assert!(executor.dry_run);
+        assert!(executor.skip_deserialization);
+    }
+
+    #[test]
+    fn test_configure_relayer_conflicting_with_relayer_signer() {
+        let result = get_builder()
+            .relayer_signer(get_other_wallet())
+            .relayer(get_builder().signer.get_address())
+            .settings
+            .configure(BaseTransactionNetworkExecutor::from_interactor(get_interactor(None)));
+
+        let expected: ExecutorError = TransactionError::ConflictingRelayerAddress {
+            relayer: get_builder().signer.get_address().to_string(),
+            relayer_signer: get_other_wallet().get_address().to_string()
+        }.into();
+
+        assert_eq!(result.err(), Some(expected));
+    }
+
+    #[test]
+    fn test_configure_gas_price_below_network_minimum() {
+        let result = get_builder()
+            .gas_price(1)
+            .settings
+            .configure(BaseTransactionNetworkExecutor::from_interactor(get_interactor(None)));
+
+        let expected: ExecutorError = TransactionError::GasPriceBelowNetworkMinimum {
+            gas_price: 1,
+            min_gas_price: 1000000000,
+        }.into();
+
+        assert_eq!(result.err(), Some(expected));
+    }
+
+    #[test]
+    fn test_configure_bump_gas_price_multiplier_not_above_one() {
+        let result = get_builder()
+            .pending_transaction_policy(PendingTransactionPolicy::BumpGasPrice { after: Duration::from_secs(30), multiplier: 1.0, max_attempts: 3 })
+            .settings
+            .configure(BaseTransactionNetworkExecutor::from_interactor(get_interactor(None)));
+
+        let expected: ExecutorError = TransactionError::InvalidGasPriceMultiplier { multiplier: 1.0 }.into();
+
+        assert_eq!(result.err(), Some(expected));
+    }
+
+    #[tokio::test]
+    async fn test_build_with_network_config() {
+        let network_config = get_interactor(None).network_config.config;
+
+        let executor = get_builder()
+            .network_config(network_config)
+            .chain_id("chain")
+            .build()
+            .await
+            .unwrap();
+
+        assert_eq!(executor.get_network_config().erd_min_gas_price, 1000000000);
+        assert_eq!(executor.interactor().chain_id_override, Some("chain".to_string()));
+    }
+}
@@ -1,23 +1,42 @@
 use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use futures::Stream;
+use tracing::Instrument;
 use multiversx_sc::codec::TopDecodeMulti;
 use multiversx_sc::imports::CodeMetadata;
 use num_bigint::BigUint;
 
 use novax_data::{Address, NativeConvertible};
+use novax_request::gateway::reqwest::get_shared_client;
 
 use crate::base::deploy::DeployExecutor;
 use crate::base::transaction::TransactionExecutor;
+use crate::base::upgrade::UpgradeExecutor;
 use crate::call_result::CallResult;
 use crate::error::executor::ExecutorError;
 use crate::error::transaction::TransactionError;
-use crate::network::transaction::interactor::{BlockchainInteractor, Interactor, TransactionRefreshStrategy};
-use crate::network::utils::wallet::Wallet;
-use crate::utils::transaction::deploy::get_deploy_call_input;
+use crate::network::models::address::account::AccountInfo;
+use crate::network::models::network::config::NetworkGatewayConfigData;
+use crate::network::models::network::status::NetworkStatus;
+use crate::network::models::token::properties::TokenProperties;
+use crate::network::transaction::builder::NetworkExecutorBuilder;
+use crate::network::transaction::interactor::{BlockchainInteractor, SharedSigner, Interactor, InteractorCall, PendingTransactionPolicy, TransactionRefreshStrategy, TransactionSentCallback};
+use crate::network::transaction::models::send_request::SignedTransaction;
+use crate::network::transaction::models::transaction_on_network::{FAILED_TRANSACTION_STATUS, TransactionOnNetwork};
+use crate::network::transaction::status::TransactionStatus;
+use crate::network::transaction::subscriber::{SharedTransactionEventsSubscriber, TransactionEventsSubscriber};
+use crate::network::utils::retry::RetryPolicy;
+use crate::network::utils::signer::Signer;
+use crate::network::utils::tracing::{GatewayRequestCallback, GatewayRequestInfo};
+use crate::utils::transaction::batch::BatchCall;
+use crate::utils::transaction::deploy::{get_deploy_call_input, get_upgrade_call_input};
+use crate::utils::transaction::egld_transfer::TransferGasConfig;
 use crate::utils::transaction::normalization::NormalizationInOut;
-use crate::utils::transaction::results::{find_sc_deploy_event, find_sc_error, find_smart_contract_result};
+use crate::utils::transaction::results::{decode_output, find_sc_deploy_event, find_sc_error, find_smart_contract_result};
 use crate::utils::transaction::token_transfer::TokenTransfer;
 
 /// Alias for the `BaseTransactionNetworkExecutor` struct, parameterized with the `Interactor` type.
@@ -28,17 +47,417 @@ pub type NetworkExecutor = BaseTransactionNetworkExecutor<Interactor>;
 /// This executor is designed to interact with a blockchain network via a specified gateway URL and a wallet
 /// for signing transactions. It is parameterized by a type `Interactor` that encapsulates the blockchain interaction logic.
 pub struct BaseTransactionNetworkExecutor<Interactor: BlockchainInteractor> {
-    interactor: Interactor
+    interactor: Interactor,
+    pub(crate) dry_run: bool,
+    dry_run_transaction: Option<SignedTransaction>,
+    pub(crate) skip_deserialization: bool,
+    call_label: Option<String>
 }
 
-impl BaseTransactionNetworkExecutor<Interactor> {
+impl<S: Signer> BaseTransactionNetworkExecutor<Interactor<S>> {
+    /// Creates a new instance of `BaseTransactionNetworkExecutor` sending all its requests through the provided `reqwest::Client`.
+    ///
+    /// Use this constructor to go through a proxy, set custom TLS settings, default headers or timeouts.
+    pub async fn new_with_client(gateway_url: String, signer: S, http_client: reqwest::Client) -> Result<Self, ExecutorError> {
+        let interactor = Interactor::new_with_client(
+            gateway_url,
+            signer,
+            http_client
+        ).await?;
+
+        Ok(
+            BaseTransactionNetworkExecutor {
+                interactor,
+                dry_run: false,
+                dry_run_transaction: None,
+                skip_deserialization: false,
+                call_label: None
+            }
+        )
+    }
+
+    /// Creates a new instance of `BaseTransactionNetworkExecutor` using the provided network configuration, without fetching it from the gateway.
+    ///
+    /// No request is made, which speeds up the startup. Combined with `set_nonce` and non-zero gas limits,
+    /// `build_signed_transaction` builds and signs transactions fully offline.
+    pub fn new_with_network_config(gateway_url: String, signer: S, network_config: NetworkGatewayConfigData) -> Self {
+        let interactor = Interactor::new_with_network_config(
+            gateway_url,
+            signer,
+            get_shared_client().clone(),
+            network_config
+        );
+
+        Self::from_interactor(interactor)
+    }
+
+    /// Returns a `NetworkExecutorBuilder`, to configure the executor before creating it.
+    pub fn builder(gateway_url: impl Into<String>, signer: S) -> NetworkExecutorBuilder<S> {
+        NetworkExecutorBuilder::new(gateway_url, signer)
+    }
+
+    /// Returns the network parameters fetched from the gateway's `/network/config` endpoint, such as the chain ID and the gas parameters.
+    ///
+    /// They are fetched once when the executor is created, or again when calling `reconnect`, and used to build every transaction.
+    pub fn get_network_config(&self) -> &NetworkGatewayConfigData {
+        &self.interactor.network_config.config
+    }
+
+    /// Builds a contract call and signs it without broadcasting it, for cold-signing workflows.
+    ///
+    /// The call goes through the same normalization as `sc_call`, and the nonce, gas price, chain ID and version are filled as if it was sent.
+    /// The returned transaction serializes to the JSON expected by the gateway's `/transaction/send` endpoint, so it can be broadcast later through any channel.
+    /// If the nonce cache is enabled, the cached nonce is incremented, so successive transactions get successive nonces.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn build_signed_transaction(
+        &mut self,
+        to: &Address,
+        function: String,
+        arguments: Vec<Vec<u8>>,
+        gas_limit: u64,
+        egld_value: BigUint,
+        esdt_transfers: Vec<TokenTransfer>
+    ) -> Result<SignedTransaction, ExecutorError> {
+        let call = self.get_interactor_call(
+            to,
+            function,
+            arguments,
+            gas_limit,
+            egld_value,
+            esdt_transfers
+        )?;
+
+        self.interactor.build_signed_transaction(call).await
+    }
+
+    /// Waits for a transaction sent elsewhere, for example whose hash was persisted before a restart, and decodes its result as `sc_call` does.
+    ///
+    /// The gateway is polled with the executor's refresh strategy, timeout and retry policy.
+    pub async fn wait_for_transaction<OutputManaged>(&self, tx_hash: &str) -> Result<CallResult<OutputManaged::Native>, ExecutorError>
+        where
+            OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
+    {
+        let result = self.interactor.wait_for_transaction(tx_hash).await?;
+
+        if self.skip_deserialization {
+            return skip_call_result_decoding(result)
+        }
+
+        decode_call_result::<OutputManaged>(result)
+    }
+
+    /// Waits for several transactions sent elsewhere, such as broadcast with `sc_call_no_wait`, and decodes each result as `sc_call` does.
+    ///
+    /// The transactions are polled concurrently, and a result is returned for each hash in the same order, a failure not stopping the others.
+    pub async fn wait_for_many<OutputManaged>(&self, tx_hashes: &[String]) -> Vec<Result<CallResult<OutputManaged::Native>, ExecutorError>>
+        where
+            OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
+    {
+        self.interactor.wait_for_many(tx_hashes)
+            .await
+            .into_iter()
+            .map(|result| {
+                let result = result?;
+
+                if self.skip_deserialization {
+                    return skip_call_result_decoding(result)
+                }
+
+                decode_call_result::<OutputManaged>(result)
+            })
+            .collect()
+    }
+
+    /// Watches a transaction sent elsewhere, such as broadcast with `sc_call_no_wait`, and returns a stream of its status transitions:
+    /// `Sent`, then `Pending` and `CrossShard` as they are observed, and finally `Completed`.
+    ///
+    /// Unlike the result of `wait_for_transaction`, the completed transaction is not decoded.
+    pub fn watch_transaction<'a>(&'a self, tx_hash: &'a str) -> impl Stream<Item = TransactionStatus> + 'a {
+        self.interactor.watch_transaction(tx_hash)
+    }
+
+    /// Fetches the state of `address`, such as its nonce and balance, allowing pre-flight checks before sending transactions.
+    pub async fn get_account(&self, address: &Address) -> Result<AccountInfo, ExecutorError> {
+        self.interactor.get_account(address).await
+    }
+
+    /// Fetches the EGLD balance of `address`.
+    pub async fn get_egld_balance(&self, address: &Address) -> Result<BigUint, ExecutorError> {
+        self.interactor.get_egld_balance(address).await
+    }
+
+    /// Fetches the balance of `token_identifier` held by `address`, allowing to check a transfer is possible before sending it.
+    pub async fn get_esdt_balance(&self, address: &Address, token_identifier: &str) -> Result<BigUint, ExecutorError> {
+        self.interactor.get_esdt_balance(address, token_identifier).await
+    }
+
+    /// Fetches the current round, epoch and nonces of `shard`, or of the metachain if `None`, to schedule calls or compute unbonding periods.
+    pub async fn get_network_status(&self, shard: Option<u32>) -> Result<NetworkStatus, ExecutorError> {
+        self.interactor.get_network_status(shard).await
+    }
+
+    /// Fetches the properties of `token_identifier`, such as its decimals, owner and supply, without decoding the raw `getTokenProperties` response.
+    pub async fn get_token_properties(&self, token_identifier: &str) -> Result<TokenProperties, ExecutorError> {
+        self.interactor.get_token_properties(token_identifier).await
+    }
+
+    /// Co-signs every transaction with the provided guardian, as required for accounts that opted into guardians.
+    ///
+    /// The transactions are sent with the `guardian` and `guardianSignature` fields, the guarded option and at least version 2.
+    /// The gas limit must account for the extra gas charged to guarded transactions.
+    pub fn set_guardian<G: Signer + 'static>(&mut self, guardian: G) {
+        self.interactor.guardian = Some(SharedSigner(Arc::new(guardian)));
+    }
+
+    /// Sets the version of every transaction, instead of the network's minimum version.
+    ///
+    /// Options, such as the guarded or hash-signing ones, are only supported from version 2.
+    /// If any option is set, the version is raised to at least 2.
+    pub fn set_version(&mut self, version: u32) {
+        self.interactor.version_override = Some(version);
+    }
+
+    /// Sets the gas price of every transaction, instead of the network's minimum gas price, for example to prioritize them during congestion.
+    ///
+    /// Returns `TransactionError::GasPriceBelowNetworkMinimum`, leaving the gas price unchanged, if `gas_price` is below the network's minimum gas price.
+    pub fn set_gas_price(&mut self, gas_price: u64) -> Result<(), ExecutorError> {
+        let previous_gas_price = self.interactor.gas_price_override.replace(gas_price);
+
+        if let Err(error) = self.interactor.get_gas_price() {
+            self.interactor.gas_price_override = previous_gas_price;
+            return Err(error)
+        }
+
+        Ok(())
+    }
+
+    /// Sets the options bits of every transaction, none being set by default.
+    ///
+    /// The options required by the guardian or by hash signing, if enabled, are added to the provided ones.
+    pub fn set_options(&mut self, options: u32) {
+        self.interactor.options_override = Some(options);
+    }
+
+    /// Enables or disables signing the Keccak256 hash of the transactions instead of their JSON serialization.
+    ///
+    /// This is recommended for transactions with large data payloads, for example when the signer is a hardware wallet.
+    /// When enabled, the transactions are sent with the hash signing option and at least version 2. Disabled by default.
+    pub fn set_hash_signing(&mut self, enabled: bool) {
+        self.interactor.hash_signing = enabled;
+    }
+
+    /// Enables or disables checking the sender's EGLD balance before broadcasting each transaction.
+    ///
+    /// When enabled, the balance is fetched and the call fails with `TransactionError::InsufficientBalance` if it cannot cover the value
+    /// and the maximum fee, the gas limit times the gas price, instead of being rejected by the gateway with a generic message.
+    /// This costs one more request per call, or per batch. Disabled by default.
+    pub fn set_balance_precheck(&mut self, enabled: bool) {
+        self.interactor.balance_precheck = enabled;
+    }
+
+    /// Sends every transaction as a relayed v3 transaction, the provided relayer paying its gas.
+    ///
+    /// The transactions are sent with the `relayer` and `relayerSignature` fields, the relayer signing the same payload as the sender.
+    /// The `relayer` field is set to the relayer signer's address, replacing any address previously set with `set_relayer`.
+    /// The network's minimum gas limit is added to the gas limit of each transaction, to account for the relayer's move balance cost.
+    pub fn set_relayer_signer<R: Signer + 'static>(&mut self, relayer: R) {
+        self.set_shared_relayer_signer(SharedSigner(Arc::new(relayer)));
+    }
+
+    pub(crate) fn set_shared_relayer_signer(&mut self, relayer: SharedSigner) {
+        self.interactor.relayer = Some(relayer.0.get_address());
+        self.interactor.relayer_signer = Some(relayer);
+    }
+
+    /// Sets the `relayer` field of the transactions, the relayer paying their gas.
+    ///
+    /// A relayed transaction must also be signed by the relayer before being broadcast:
+    /// use `build_signed_transaction` and hand the result to the relayer instead of sending it with `sc_call`.
+    /// If a relayer signer was set with `set_relayer_signer`, returns `TransactionError::ConflictingRelayerAddress` when `relayer` is not its address.
+    pub fn set_relayer(&mut self, relayer: Address) -> Result<(), ExecutorError> {
+        if let Some(relayer_signer) = self.interactor.relayer_signer.as_ref() {
+            let relayer_signer_address = relayer_signer.0.get_address();
+
+            if relayer != relayer_signer_address {
+                return Err(TransactionError::ConflictingRelayerAddress {
+                    relayer: relayer.to_string(),
+                    relayer_signer: relayer_signer_address.to_string()
+                }.into())
+            }
+        }
+
+        self.interactor.relayer = Some(relayer);
+
+        Ok(())
+    }
+
+    /// Overrides the chain ID used to sign transactions, instead of the one fetched from the gateway's `/network/config` endpoint.
+    ///
+    /// This is useful for local chains, such as the chain simulator, whose chain ID differs from the public networks ones.
+    /// The override is kept when calling `reconnect`.
+    pub fn set_chain_id(&mut self, chain_id: impl Into<String>) {
+        self.interactor.chain_id_override = Some(chain_id.into());
+    }
+
+    /// Sets how often the gateway is polled while waiting for a sent transaction to be executed.
+    ///
+    /// The default strategy is `TransactionRefreshStrategy::EachBlock`.
     pub fn set_refresh_strategy(&mut self, strategy: TransactionRefreshStrategy) {
         self.interactor.refresh_strategy = strategy;
     }
 
+    /// Sets the behavior when a sent transaction stays in the "pending" status, for example because its gas price is too low during a spike.
+    ///
+    /// The default policy is `PendingTransactionPolicy::Wait`, polling until the timeout elapses.
+    /// `PendingTransactionPolicy::BumpGasPrice` sends again the calls made through `sc_call` with a higher gas price, the batched calls failing instead.
+    ///
+    /// Returns `TransactionError::InvalidGasPriceMultiplier`, leaving the policy unchanged, if the `BumpGasPrice` multiplier is not a finite number above 1.
+    pub fn set_pending_transaction_policy(&mut self, policy: PendingTransactionPolicy) -> Result<(), ExecutorError> {
+        policy.validate()?;
+        self.interactor.pending_transaction_policy = policy;
+
+        Ok(())
+    }
+
+    /// Sets the maximum duration to wait for a sent transaction to be executed.
+    ///
+    /// Once elapsed, `sc_call` returns `TransactionError::TimeoutWhenRetrievingTransactionOnNetwork` with the transaction's hash.
+    /// The default timeout is 10 seconds.
     pub fn set_timeout(&mut self, timeout: Duration) {
         self.interactor.timeout = timeout;
     }
+
+    /// Sets the multiplier applied to the gas estimated by the `/transaction/cost` endpoint.
+    ///
+    /// The gas is estimated only when `sc_call` is called with a `gas_limit` of zero. The default multiplier is 1.1.
+    ///
+    /// Returns `TransactionError::InvalidGasEstimationMultiplier`, leaving the multiplier unchanged, if it is below 1 or not a finite number,
+    /// since the transactions would run out of gas or have an unbounded gas limit.
+    pub fn set_gas_estimation_multiplier(&mut self, multiplier: f64) -> Result<(), ExecutorError> {
+        if multiplier < 1.0 || !multiplier.is_finite() {
+            return Err(TransactionError::InvalidGasEstimationMultiplier { multiplier }.into())
+        }
+
+        self.interactor.gas_estimation_multiplier = multiplier;
+
+        Ok(())
+    }
+
+    /// Sets how failed gateway requests are retried, using an exponential backoff starting at `base_delay`.
+    ///
+    /// Only idempotent requests are retried: account fetching, gas estimation and transaction status polling.
+    /// Broadcasting the transaction is never retried. By default, no request is retried.
+    pub fn set_retry_policy(&mut self, max_retries: u32, base_delay: Duration) {
+        self.interactor.retry_policy = RetryPolicy::new(max_retries, base_delay);
+    }
+
+    /// Enables or disables the local caching of the sender's nonce.
+    ///
+    /// When enabled, the nonce is fetched from the gateway only for the first transaction, then incremented locally after each successful broadcast.
+    /// This saves a request per transaction and avoids nonce collisions when sending many transactions in a row.
+    /// Disabled by default. If another transaction is sent from the same account outside of this executor, call `refresh_nonce`.
+    pub fn set_nonce_cache(&mut self, enabled: bool) {
+        self.interactor.nonce_cache_enabled = enabled;
+        self.interactor.cached_nonce = None;
+    }
+
+    /// Enables or disables the check of the transaction's process status before resolving a call.
+    ///
+    /// A cross-shard call is executed in the sender's shard before its smart contract results are processed in the destination shard.
+    /// When enabled, the gateway's `/transaction/{hash}/process-status` endpoint is polled too, and the call resolves only once it is no longer "pending",
+    /// so the returned value is the one of the completed call. This costs one more request per poll. Disabled by default.
+    pub fn set_process_status_check(&mut self, enabled: bool) {
+        self.interactor.process_status_check = enabled;
+    }
+
+    /// Fetches the network configuration again, for example after a network upgrade, and empties the cached nonce.
+    ///
+    /// The network configuration is otherwise fetched only once, when the executor is created, and shared by all the calls.
+    pub async fn reconnect(&mut self) -> Result<(), ExecutorError> {
+        self.interactor.reconnect().await
+    }
+
+    /// Empties the cached nonce, forcing it to be fetched from the gateway on the next transaction.
+    pub fn refresh_nonce(&mut self) {
+        self.interactor.cached_nonce = None;
+    }
+
+    /// Enables the nonce cache and sets the nonce of the next transaction, so it is not fetched from the gateway.
+    ///
+    /// This allows building transactions offline, for example with an executor created by `new_with_network_config`.
+    pub fn set_nonce(&mut self, nonce: u64) {
+        self.interactor.nonce_cache_enabled = true;
+        self.interactor.cached_nonce = Some(nonce);
+    }
+
+    /// Sets the nonce of the next transaction only, skipping the account lookup, for example to rebroadcast a specific transaction
+    /// or to coordinate several senders. It is cleared once a transaction is sent or built with `build_signed_transaction`, whether it succeeds or not.
+    ///
+    /// For a batch, it is the nonce of the first call, the following ones being incremented from it.
+    /// Unlike `set_nonce`, the nonce cache is neither enabled nor updated.
+    pub fn set_next_nonce(&mut self, nonce: u64) {
+        self.interactor.next_nonce = Some(nonce);
+    }
+
+    /// Sets the sender of the next transactions, `None` restoring the signer's address, and empties the cached nonce.
+    ///
+    /// The transactions are still signed by the executor's signer, so the gateway rejects them unless they are signed again on behalf of `sender`.
+    /// This allows building transactions for another account, such as a multisig proposal: use `build_signed_transaction`,
+    /// or the dry-run mode for deployments, then retrieve the built transaction with `get_dry_run_transaction`.
+    /// The nonce is the one of `sender`, unless set with `set_nonce` or `set_next_nonce`.
+    pub fn set_sender(&mut self, sender: Option<Address>) {
+        self.interactor.sender_override = sender;
+        self.interactor.cached_nonce = None;
+    }
+
+    /// Sets a callback invoked with the hash of each transaction right after it is broadcast, before waiting for its execution.
+    ///
+    /// This allows persisting the hash as soon as possible, so the transaction can be looked up even if the process stops while waiting.
+    pub fn set_on_transaction_sent<F>(&mut self, callback: F)
+        where
+            F: Fn(&str) + Send + Sync + 'static
+    {
+        self.interactor.on_transaction_sent = Some(TransactionSentCallback(Arc::new(callback)));
+    }
+
+    /// Sets a callback invoked after each request sent to the gateway, with its method, URL, status and duration.
+    ///
+    /// This allows tracing the network activity of the executor, for example to log the latency of each request.
+    /// The requests include the account and network configuration lookups, the gas estimations, the broadcasts and the transaction status polls.
+    pub fn set_on_gateway_request<F>(&mut self, callback: F)
+        where
+            F: Fn(&GatewayRequestInfo) + Send + Sync + 'static
+    {
+        self.interactor.on_gateway_request = Some(GatewayRequestCallback(Arc::new(callback)));
+    }
+
+    /// Sets a correlation ID passed to the callback set with `set_on_gateway_request` for each request, until it is changed or removed.
+    ///
+    /// Setting a new ID before each call allows correlating the requests of the call with the application's logs.
+    pub fn set_request_id(&mut self, request_id: Option<String>) {
+        self.interactor.request_id = request_id;
+    }
+
+    /// Sets a push-based source of transaction events, such as a client of the MultiversX notifier's WebSocket service,
+    /// to resolve the wait for sent transactions as soon as they are executed instead of polling the gateway.
+    ///
+    /// The gateway is still polled if the subscriber fails, for example because the socket dropped,
+    /// and is always requested once to retrieve the executed transaction.
+    pub fn set_events_subscriber<T: TransactionEventsSubscriber + 'static>(&mut self, subscriber: T) {
+        self.interactor.events_subscriber = Some(SharedTransactionEventsSubscriber(Arc::new(subscriber)));
+    }
+
+    /// Sets a flag allowing to stop waiting for sent transactions to be executed, from another task or thread.
+    ///
+    /// Once the flag is set to `true`, `sc_call` stops polling the gateway and returns `TransactionError::WaitingForTransactionCancelled`
+    /// with the hash of the already broadcast transaction, which may still be executed. The flag is checked before each poll,
+    /// so cancelling takes effect at most one refresh interval later. It is never reset by the executor: set it back to `false` before sending other transactions.
+    ///
+    /// Regarding cancellation safety, dropping the `sc_call` future is also possible: if it is dropped before the transaction is broadcast, nothing is sent.
+    /// Otherwise, the transaction may have been sent, and its hash is only known through the callback set with `set_on_transaction_sent`.
+    pub fn set_cancellation_flag(&mut self, flag: Arc<AtomicBool>) {
+        self.interactor.cancellation_flag = Some(flag);
+    }
 }
 
 /// Custom implementation of `Clone` for `BaseTransactionNetworkExecutor`, when `Interactor` is `Clone`.
@@ -48,7 +467,11 @@ impl<Interactor> Clone for BaseTransactionNetworkExecutor<Interactor>
 {
     fn clone(&self) -> Self {
         Self {
-            interactor: self.interactor.clone()
+            interactor: self.interactor.clone(),
+            dry_run: self.dry_run,
+            dry_run_transaction: self.dry_run_transaction.clone(),
+            skip_deserialization: self.skip_deserialization,
+            call_label: self.call_label.clone()
         }
     }
 }
@@ -67,21 +490,306 @@ impl<Interactor> Debug for BaseTransactionNetworkExecutor<Interactor>
 }
 
 impl<Interactor: BlockchainInteractor> BaseTransactionNetworkExecutor<Interactor> {
+    /// Sends many independent contract calls at once, and returns the result of each of them in the same order.
+    ///
+    /// Unlike calling `sc_call` sequentially, the calls are all broadcast before waiting for their executions,
+    /// nonces being incremented locally from the account's current nonce. A failing call doesn't prevent the others from being executed.
+    pub async fn sc_call_batch<OutputManaged>(
+        &mut self,
+        calls: Vec<BatchCall>
+    ) -> Vec<Result<CallResult<OutputManaged::Native>, ExecutorError>>
+        where
+            OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
+    {
+        let (label, span) = self.start_labeled_call();
+
+        let results = self.send_call_batch::<OutputManaged>(calls)
+            .instrument(span)
+            .await;
+
+        self.interactor.set_call_label(None);
+
+        results
+            .into_iter()
+            .map(|result| result.map_err(|error| error.with_label(label.as_deref())))
+            .collect()
+    }
+
+    async fn send_call_batch<OutputManaged>(
+        &mut self,
+        calls: Vec<BatchCall>
+    ) -> Vec<Result<CallResult<OutputManaged::Native>, ExecutorError>>
+        where
+            OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
+    {
+        if self.dry_run {
+            return calls
+                .iter()
+                .map(|_| Err(TransactionError::DryRunNotSupported.into()))
+                .collect()
+        }
+
+        let interactor_calls: Vec<Result<InteractorCall, ExecutorError>> = calls
+            .into_iter()
+            .map(|call| self.get_interactor_call(
+                &call.to,
+                call.function,
+                call.arguments,
+                call.gas_limit,
+                call.egld_value,
+                call.esdt_transfers
+            ))
+            .collect();
+
+        let valid_calls: Vec<InteractorCall> = interactor_calls
+            .iter()
+            .filter_map(|call| call.as_ref().ok().cloned())
+            .collect();
+
+        valid_calls.iter().for_each(log_call_before_broadcast);
+
+        let mut sent_results = self.interactor.sc_call_batch(valid_calls).await.into_iter();
+
+        interactor_calls
+            .into_iter()
+            .enumerate()
+            .map(|(index, call)| {
+                call?;
+
+                let Some(sent_result) = sent_results.next() else {
+                    return Err(TransactionError::MissingBatchResult { index }.into())
+                };
+
+                let sent_result = sent_result?;
+
+                if self.skip_deserialization {
+                    return skip_call_result_decoding(sent_result)
+                }
+
+                decode_call_result::<OutputManaged>(sent_result)
+            })
+            .collect()
+    }
+
+    /// Broadcasts a contract call and returns its transaction hash as soon as the gateway accepts it,
+    /// without waiting for its execution nor decoding its result.
+    ///
+    /// This suits fire-and-forget calls, such as heartbeats, which don't need to block until the transaction is executed.
+    /// A call failing on-chain is not reported, the returned hash allowing to check it later with `wait_for_transaction` if needed.
+    pub async fn sc_call_no_wait(
+        &mut self,
+        to: &Address,
+        function: String,
+        arguments: Vec<Vec<u8>>,
+        gas_limit: u64,
+        egld_value: BigUint,
+        esdt_transfers: Vec<TokenTransfer>
+    ) -> Result<String, ExecutorError> {
+        let (label, span) = self.start_labeled_call();
+
+        let result = self.send_call_no_wait(
+            to,
+            function,
+            arguments,
+            gas_limit,
+            egld_value,
+            esdt_transfers
+        )
+            .instrument(span)
+            .await;
+
+        self.interactor.set_call_label(None);
+
+        result.map_err(|error| error.with_label(label.as_deref()))
+    }
+
+    async fn send_call_no_wait(
+        &mut self,
+        to: &Address,
+        function: String,
+        arguments: Vec<Vec<u8>>,
+        gas_limit: u64,
+        egld_value: BigUint,
+        esdt_transfers: Vec<TokenTransfer>
+    ) -> Result<String, ExecutorError> {
+        if self.dry_run {
+            return Err(TransactionError::DryRunNotSupported.into())
+        }
+
+        let call = self.get_interactor_call(
+            to,
+            function,
+            arguments,
+            gas_limit,
+            egld_value,
+            esdt_transfers
+        )?;
+
+        log_call_before_broadcast(&call);
+
+        self.interactor.sc_call_no_wait(call).await
+    }
+
+    async fn send_call<OutputManaged>(
+        &mut self,
+        to: &Address,
+        function: String,
+        arguments: Vec<Vec<u8>>,
+        gas_limit: u64,
+        egld_value: BigUint,
+        esdt_transfers: Vec<TokenTransfer>
+    ) -> Result<CallResult<OutputManaged::Native>, ExecutorError>
+        where
+            OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
+    {
+        let call = self.get_interactor_call(
+            to,
+            function,
+            arguments,
+            gas_limit,
+            egld_value,
+            esdt_transfers
+        )?;
+
+        if self.dry_run {
+            self.dry_run_transaction = Some(self.interactor.build_transaction(call).await?);
+
+            let dry_run_result = CallResult {
+                response: Default::default(),
+                result: None,
+            };
+
+            return Ok(dry_run_result)
+        }
+
+        log_call_before_broadcast(&call);
+
+        let result = self.interactor.sc_call(
+            call.to,
+            call.value,
+            call.data,
+            call.gas_limit,
+        )
+            .await?;
+
+        if self.skip_deserialization {
+            return skip_call_result_decoding(result)
+        }
+
+        decode_call_result::<OutputManaged>(result)
+    }
+
+    /// Labels the next call made by the executor, such as "swap", to attribute it to a business operation.
+    ///
+    /// The label applies to a single call, made with `sc_call`, `sc_call_batch`, `sc_call_no_wait`, a deployment or an upgrade, and is removed once the call returns.
+    /// The call runs in a `novax_call` tracing span recording the label, the label is passed to the callback set with `set_on_gateway_request`,
+    /// and the errors returned by the call are wrapped in `ExecutorError::Labeled`. Use `ExecutorError::unlabeled` to match on the wrapped error.
+    pub fn set_call_label(&mut self, label: Option<String>) {
+        self.call_label = label;
+    }
+
+    /// Takes the label set with `set_call_label` for the call about to be made, hands it to the interactor,
+    /// and returns it along with the tracing span to run the call in.
+    fn start_labeled_call(&mut self) -> (Option<String>, tracing::Span) {
+        let label = self.call_label.take();
+        let span = tracing::debug_span!("novax_call", label = label.as_deref());
+
+        self.interactor.set_call_label(label.clone());
+
+        (label, span)
+    }
+
+    fn get_interactor_call(
+        &self,
+        to: &Address,
+        function: String,
+        arguments: Vec<Vec<u8>>,
+        gas_limit: u64,
+        egld_value: BigUint,
+        esdt_transfers: Vec<TokenTransfer>
+    ) -> Result<InteractorCall, ExecutorError> {
+        let function_name = if function.is_empty() {
+            None
+        } else {
+            Some(function)
+        };
+
+        let normalized = NormalizationInOut {
+            sender: self.interactor.get_sender_address().to_bech32_string()?,
+            receiver: to.to_bech32_string()?,
+            function_name,
+            arguments,
+            egld_value,
+            esdt_transfers,
+        }.normalize()?;
+
+        let call = InteractorCall {
+            to: normalized.receiver.clone(),
+            value: normalized.egld_value.clone(),
+            data: normalized.get_transaction_data(),
+            gas_limit,
+        };
+
+        Ok(call)
+    }
+
     /// Creates a new instance of `BaseTransactionNetworkExecutor`.
     ///
     /// This function is async because the Interactor may perform some requests, such as retrieving the network configuration.
     /// Those async operations might fail, thus the Result return type.
-    pub async fn new(gateway_url: String, wallet: Wallet) -> Result<Self, ExecutorError> {
+    ///
+    /// The signer is used to sign every sent transaction, it is usually a `Wallet`.
+    pub async fn new(gateway_url: String, signer: Interactor::Signer) -> Result<Self, ExecutorError> {
         let interactor = Interactor::new(
             gateway_url,
-            wallet
+            signer
         ).await?;
 
-        Ok(
-            BaseTransactionNetworkExecutor {
-                interactor
-            }
-        )
+        Ok(Self::from_interactor(interactor))
+    }
+
+    /// Creates a new instance of `BaseTransactionNetworkExecutor` from an already created interactor, such as a `MockInteractor`.
+    pub fn from_interactor(interactor: Interactor) -> Self {
+        BaseTransactionNetworkExecutor {
+            interactor,
+            dry_run: false,
+            dry_run_transaction: None,
+            skip_deserialization: false,
+            call_label: None
+        }
+    }
+
+    /// Returns the underlying interactor, for example to assert on the calls received by a `MockInteractor`.
+    pub fn interactor(&self) -> &Interactor {
+        &self.interactor
+    }
+
+    /// Returns the underlying interactor mutably, for example to add expectations to a `MockInteractor`.
+    pub fn interactor_mut(&mut self) -> &mut Interactor {
+        &mut self.interactor
+    }
+
+    /// Enables or disables the dry-run mode.
+    ///
+    /// In dry-run mode, `sc_call` performs all the live lookups, such as the nonce and the gas estimation, then builds and signs the transaction without broadcasting it.
+    /// The built transaction can be retrieved with `get_dry_run_transaction`, and the returned `CallResult` holds a default response and no result.
+    /// Deployments return the zero address (`Address::default()`), `sc_call_batch` returns `TransactionError::DryRunNotSupported` for each call, and `sc_call_no_wait` returns it as well.
+    pub fn set_dry_run(&mut self, enabled: bool) {
+        self.dry_run = enabled;
+        self.dry_run_transaction = None;
+    }
+
+    /// Enables or disables the decoding of the calls' results.
+    ///
+    /// When disabled, `sc_call` returns a `CallResult` with a `None` result, the smart contract errors still being returned as errors.
+    /// Deployments are not affected, the deployed address being read from the logs.
+    pub fn set_skip_deserialization(&mut self, enabled: bool) {
+        self.skip_deserialization = enabled;
+    }
+
+    /// Returns the transaction built by the last `sc_call` made in dry-run mode, if any.
+    pub fn get_dry_run_transaction(&self) -> Option<&SignedTransaction> {
+        self.dry_run_transaction.as_ref()
     }
 }
 
@@ -99,60 +807,57 @@ impl<Interactor: BlockchainInteractor> TransactionExecutor for BaseTransactionNe
         where
             OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
     {
-        let function_name = if function.is_empty() {
-            None
-        } else {
-            Some(function)
-        };
+        let (label, span) = self.start_labeled_call();
 
-        let normalized = NormalizationInOut {
-            sender: self.interactor.get_sender_address().to_bech32_string()?,
-            receiver: to.to_bech32_string()?,
-            function_name,
+        let result = self.send_call::<OutputManaged>(
+            to,
+            function,
             arguments,
-            egld_value,
-            esdt_transfers,
-        }.normalize()?;
-
-        let receiver = normalized.receiver.clone();
-        let egld_value = normalized.egld_value.clone();
-        let transaction_data = normalized.get_transaction_data();
-
-        let result = self.interactor.sc_call(
-            receiver,
-            egld_value,
-            transaction_data,
             gas_limit,
+            egld_value,
+            esdt_transfers
         )
-            .await?;
+            .instrument(span)
+            .await;
 
-        let Some(mut sc_result) = find_smart_contract_result(
-            &result.transaction.smart_contract_results,
-            result.transaction.logs.as_ref()
-        )? else {
-            if let Some(logs) = result.transaction.logs.as_ref() {
-                if let Ok(Some(error_log)) = find_sc_error(logs) {
-                    return Err(TransactionError::SmartContractExecutionError { // TODO add tests for this
-                        status: error_log.status,
-                        message: error_log.message
-                    }.into())
-                }
-            }
+        self.interactor.set_call_label(None);
 
-            return Err(TransactionError::NoSmartContractResult.into())
-        };
+        result.map_err(|error| error.with_label(label.as_deref()))
+    }
 
-        let managed_result = OutputManaged::multi_decode(&mut sc_result)
-            .map_err(|_| TransactionError::CannotDecodeSmartContractResult)?;
+    async fn should_skip_deserialization(&self) -> bool {
+        self.skip_deserialization
+    }
 
-        let native_result = managed_result.to_native();
+    async fn get_sender_address(&self) -> Option<Address> {
+        Some(self.interactor.get_sender_address())
+    }
 
-        let call_result = CallResult {
-            response: result,
-            result: Some(native_result),
-        };
+    async fn get_transfer_gas_config(&self) -> TransferGasConfig {
+        self.interactor.get_transfer_gas_config()
+    }
+
+    /// Estimates the gas limit of a call through the interactor, the call going through the same normalization as `sc_call`.
+    ///
+    /// Nothing is broadcast, even outside of the dry-run mode.
+    async fn estimate_gas(
+        &self,
+        to: &Address,
+        function: String,
+        arguments: Vec<Vec<u8>>,
+        egld_value: BigUint,
+        esdt_transfers: Vec<TokenTransfer>
+    ) -> Result<u64, ExecutorError> {
+        let call = self.get_interactor_call(
+            to,
+            function,
+            arguments,
+            0,
+            egld_value,
+            esdt_transfers
+        )?;
 
-        Ok(call_result)
+        self.interactor.estimate_gas(call).await
     }
 }
 
@@ -194,6 +899,10 @@ impl<Interactor: BlockchainInteractor> DeployExecutor for BaseTransactionNetwork
         )
             .await?;
 
+        if self.dry_run {
+            return Ok((Address::default(), deploy_result))
+        }
+
         let Some(logs) = deploy_result.response.transaction.logs.as_ref() else {
             return Err(TransactionError::NoSCDeployLogInTheResponse.into())
         };
@@ -206,4 +915,638 @@ impl<Interactor: BlockchainInteractor> DeployExecutor for BaseTransactionNetwork
 
         Ok((deployed_address, deploy_result))
     }
-}
\ No newline at end of file
+}
+
+/// Implementation of the `UpgradeExecutor` trait for the `BaseTransactionNetworkExecutor` struct.
+/// The upgrade is sent as an `upgradeContract` call to the contract, and its result is decoded as for any other call.
+#[async_trait]
+impl<Interactor: BlockchainInteractor> UpgradeExecutor for BaseTransactionNetworkExecutor<Interactor> {
+    /// Asynchronously upgrades a smart contract deployed on the blockchain.
+    async fn sc_upgrade<
+        OutputManaged
+    >(
+        &mut self,
+        contract_address: &Address,
+        bytes: Vec<u8>,
+        code_metadata: CodeMetadata,
+        egld_value: BigUint,
+        arguments: Vec<Vec<u8>>,
+        gas_limit: u64
+    ) -> Result<CallResult<OutputManaged::Native>, ExecutorError>
+        where
+            OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
+    {
+        let upgrade_call_input = get_upgrade_call_input(
+            contract_address,
+            bytes,
+            code_metadata,
+            egld_value,
+            arguments,
+            gas_limit
+        );
+
+        self.sc_call::<OutputManaged>(
+            &upgrade_call_input.to,
+            upgrade_call_input.function,
+            upgrade_call_input.arguments,
+            upgrade_call_input.gas_limit,
+            upgrade_call_input.egld_value,
+            upgrade_call_input.esdt_transfers
+        )
+            .await
+    }
+}
+
+/// Decodes the smart contract result of an executed transaction.
+///
+/// Some successful calls legitimately have no smart contract result, such as calls to endpoints returning nothing.
+/// In this case, the output is decoded from empty data: it succeeds for types such as `()`.
+/// If the output cannot be decoded from empty data, `TransactionError::NoSmartContractResult` is returned, holding the response
+/// so the logs, the gas used and the fee can still be inspected.
+fn decode_call_result<OutputManaged>(result: TransactionOnNetwork) -> Result<CallResult<OutputManaged::Native>, ExecutorError>
+    where
+        OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
+{
+    check_sc_error(&result)?;
+
+    let Some(sc_result) = find_smart_contract_result(
+        &result.transaction.hash,
+        &result.transaction.sender,
+        &result.transaction.smart_contract_results,
+        result.transaction.logs.as_ref()
+    )? else {
+        let Ok(managed_result) = OutputManaged::multi_decode(&mut Vec::<Vec<u8>>::new()) else {
+            return Err(TransactionError::NoSmartContractResult { response: Box::new(result) }.into())
+        };
+
+        let call_result = CallResult {
+            response: result,
+            result: Some(managed_result.to_native()),
+        };
+
+        return Ok(call_result)
+    };
+
+    let native_result = decode_output::<OutputManaged>(sc_result.return_data)?;
+
+    let call_result = CallResult {
+        response: result,
+        result: Some(native_result),
+    };
+
+    Ok(call_result)
+}
+
+/// Returns the response of an executed transaction without decoding its result, unless the smart contract returned an error.
+fn skip_call_result_decoding<Output>(result: TransactionOnNetwork) -> Result<CallResult<Output>, ExecutorError> {
+    check_sc_error(&result)?;
+
+    let call_result = CallResult {
+        response: result,
+        result: None,
+    };
+
+    Ok(call_result)
+}
+
+fn check_sc_error(result: &TransactionOnNetwork) -> Result<(), ExecutorError> {
+    if let Some(logs) = result.transaction.logs.as_ref() {
+        if let Some(error_log) = find_sc_error(logs)? {
+            return Err(TransactionError::SmartContractExecutionError {
+                status: error_log.status,
+                message: error_log.message
+            }.into())
+        }
+    }
+
+    if result.transaction.status == FAILED_TRANSACTION_STATUS {
+        return Err(TransactionError::TransactionFailed {
+            tx_hash: result.transaction.hash.clone(),
+            return_message: result.transaction.return_message
+                .clone()
+                .filter(|return_message| !return_message.is_empty())
+        }.into())
+    }
+
+    Ok(())
+}
+
+/// Logs the normalized call at the debug level, right before it is broadcast, to see the exact data field sent.
+fn log_call_before_broadcast(call: &InteractorCall) {
+    let (function, arguments) = split_call_data(&call.data);
+
+    tracing::debug!(
+        receiver = %call.to,
+        function,
+        arguments = ?arguments,
+        egld_value = %call.value,
+        gas_limit = call.gas_limit,
+        data = %call.data,
+        "broadcasting call"
+    );
+}
+
+/// Splits a transaction's data field into the function name and the hex-encoded arguments.
+fn split_call_data(data: &str) -> (&str, Vec<&str>) {
+    let mut parts = data.split('@');
+    let function = parts.next().unwrap_or_default();
+
+    (function, parts.collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use num_bigint::BigUint;
+
+    use crate::base::transaction::TransactionExecutor;
+    use crate::error::executor::ExecutorError;
+    use crate::error::transaction::TransactionError;
+    use crate::mocking::interactor::MockInteractor;
+    use crate::network::transaction::executor::{decode_call_result, skip_call_result_decoding, split_call_data, BaseTransactionNetworkExecutor};
+    use crate::network::transaction::interactor::{BlockchainInteractor, InteractorCall};
+    use crate::network::transaction::interactor::tests::get_interactor;
+    use crate::network::transaction::models::transaction_on_network::{TransactionOnNetwork, TransactionOnNetworkTransactionLogs, TransactionOnNetworkTransactionLogsEvents, TransactionOnNetworkTransactionSmartContractResult};
+    use crate::network::utils::wallet::Wallet;
+    use crate::utils::transaction::batch::BatchCall;
+    use novax_data::Address;
+
+    const CONTRACT_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgqtqfhy99su9xzjjrq59kpzpp25udtc9eq0n4sr90ax6";
+
+    /// An interactor answering only the first call of a batch, breaking the one result per call contract.
+    /// It records the label set for each call it receives.
+    #[derive(Default)]
+    struct PartialBatchInteractor {
+        call_label: Option<String>,
+        received_labels: Vec<Option<String>>
+    }
+
+    #[async_trait]
+    impl BlockchainInteractor for PartialBatchInteractor {
+        type Signer = Wallet;
+
+        async fn new(_gateway_url: String, _signer: Wallet) -> Result<Self, ExecutorError> {
+            Ok(PartialBatchInteractor::default())
+        }
+
+        async fn sc_call(&mut self, _to: String, _value: BigUint, _data: String, _gas_limit: u64) -> Result<TransactionOnNetwork, ExecutorError> {
+            self.received_labels.push(self.call_label.clone());
+
+            let mut response = TransactionOnNetwork::default();
+            response.transaction.status = "success".to_string();
+
+            Ok(response)
+        }
+
+        async fn sc_call_batch(&mut self, calls: Vec<InteractorCall>) -> Vec<Result<TransactionOnNetwork, ExecutorError>> {
+            let mut results = vec![];
+
+            if let Some(call) = calls.into_iter().next() {
+                results.push(self.sc_call(call.to, call.value, call.data, call.gas_limit).await);
+            }
+
+            results
+        }
+
+        fn set_call_label(&mut self, label: Option<String>) {
+            self.call_label = label;
+        }
+
+        fn get_sender_address(&self) -> Address {
+            Address::zero()
+        }
+    }
+
+    fn get_batch_call() -> BatchCall {
+        BatchCall {
+            to: Address::from_bech32_string(CONTRACT_ADDRESS).unwrap(),
+            function: "add".to_string(),
+            arguments: vec![vec![10]],
+            gas_limit: 10_000_000,
+            egld_value: BigUint::from(0u8),
+            esdt_transfers: vec![],
+        }
+    }
+
+    fn get_relayer() -> Wallet {
+        Wallet::from_private_key("1648ad209d6b157a289884933e3bb30f161ec7113221ec16f87c3578b05830b0").unwrap()
+    }
+
+    #[test]
+    fn test_set_gas_estimation_multiplier() {
+        let mut executor = BaseTransactionNetworkExecutor::from_interactor(get_interactor(None));
+
+        executor.set_gas_estimation_multiplier(1.0).unwrap();
+
+        assert_eq!(executor.interactor().gas_estimation_multiplier, 1.0);
+    }
+
+    #[test]
+    fn test_set_gas_estimation_multiplier_invalid() {
+        let mut executor = BaseTransactionNetworkExecutor::from_interactor(get_interactor(None));
+
+        let expected: Result<(), ExecutorError> = Err(TransactionError::InvalidGasEstimationMultiplier { multiplier: 0.9 }.into());
+
+        assert_eq!(executor.set_gas_estimation_multiplier(0.9), expected);
+        assert!(executor.set_gas_estimation_multiplier(f64::NAN).is_err());
+        assert!(executor.set_gas_estimation_multiplier(f64::INFINITY).is_err());
+        assert_eq!(executor.interactor().gas_estimation_multiplier, 1.1);
+    }
+
+    #[test]
+    fn test_set_relayer_signer_sets_the_relayer_address() {
+        let relayer = get_relayer();
+        let mut executor = BaseTransactionNetworkExecutor::from_interactor(get_interactor(None));
+        executor.set_relayer_signer(relayer.clone());
+
+        assert_eq!(executor.interactor().relayer, Some(relayer.get_address()));
+    }
+
+    #[test]
+    fn test_set_relayer_signer_replaces_the_relayer_address() {
+        let relayer = get_relayer();
+        let mut executor = BaseTransactionNetworkExecutor::from_interactor(get_interactor(None));
+        executor.set_relayer(Address::zero()).unwrap();
+
+        executor.set_relayer_signer(relayer.clone());
+
+        assert_eq!(executor.interactor().relayer, Some(relayer.get_address()));
+    }
+
+    #[test]
+    fn test_set_relayer_matching_the_relayer_signer() {
+        let relayer = get_relayer();
+        let mut executor = BaseTransactionNetworkExecutor::from_interactor(get_interactor(None));
+        executor.set_relayer_signer(relayer.clone());
+
+        executor.set_relayer(relayer.get_address()).unwrap();
+
+        assert_eq!(executor.interactor().relayer, Some(relayer.get_address()));
+    }
+
+    #[test]
+    fn test_set_relayer_conflicting_with_the_relayer_signer() {
+        let relayer = get_relayer();
+        let mut executor = BaseTransactionNetworkExecutor::from_interactor(get_interactor(None));
+        executor.set_relayer_signer(relayer.clone());
+
+        let result = executor.set_relayer(Address::zero());
+
+        let expected: Result<(), ExecutorError> = Err(TransactionError::ConflictingRelayerAddress {
+            relayer: Address::zero().to_string(),
+            relayer_signer: relayer.get_address().to_string()
+        }.into());
+
+        assert_eq!(result, expected);
+        assert_eq!(executor.interactor().relayer, Some(relayer.get_address()));
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_sc_call_builds_the_transaction_without_sending_it() {
+        let mut interactor = get_interactor(None);
+        interactor.next_nonce = Some(7);
+
+        let mut executor = BaseTransactionNetworkExecutor::from_interactor(interactor);
+        executor.set_dry_run(true);
+
+        // The gateway URL is empty, so the call would fail if it was sent.
+        let result = executor.sc_call::<()>(
+            &Address::from_bech32_string(CONTRACT_ADDRESS).unwrap(),
+            "add".to_string(),
+            vec![vec![10]],
+            10_000_000,
+            BigUint::from(0u8),
+            vec![]
+        ).await.unwrap();
+
+        assert_eq!(result.result, None);
+
+        let transaction = executor.get_dry_run_transaction().unwrap();
+
+        assert_eq!(transaction.receiver, CONTRACT_ADDRESS);
+        assert_eq!(transaction.nonce, 7);
+        assert_eq!(transaction.gas_limit, 10_000_000);
+        assert_eq!(transaction.data, Some("YWRkQDBh".to_string()));
+        assert!(transaction.verify_signature().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_sc_call_not_supported_by_the_interactor() {
+        let mut executor = BaseTransactionNetworkExecutor::from_interactor(MockInteractor::new(get_interactor(None).signer));
+        executor.set_dry_run(true);
+
+        let result = executor.sc_call::<()>(
+            &Address::from_bech32_string(CONTRACT_ADDRESS).unwrap(),
+            "add".to_string(),
+            vec![vec![10]],
+            10_000_000,
+            BigUint::from(0u8),
+            vec![]
+        ).await;
+
+        let expected: ExecutorError = TransactionError::DryRunNotSupported.into();
+
+        assert_eq!(result.err(), Some(expected));
+        assert!(executor.get_dry_run_transaction().is_none());
+        assert!(executor.interactor().get_received_calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_sc_call_no_wait_and_batch_not_supported() {
+        let mut executor = BaseTransactionNetworkExecutor::from_interactor(MockInteractor::new(get_interactor(None).signer));
+        executor.set_dry_run(true);
+
+        let to = Address::from_bech32_string(CONTRACT_ADDRESS).unwrap();
+
+        let no_wait_result = executor.sc_call_no_wait(
+            &to,
+            "add".to_string(),
+            vec![vec![10]],
+            10_000_000,
+            BigUint::from(0u8),
+            vec![]
+        ).await;
+
+        let batch_errors: Vec<Option<ExecutorError>> = executor.sc_call_batch::<()>(vec![get_batch_call(), get_batch_call()])
+            .await
+            .into_iter()
+            .map(|result| result.err())
+            .collect();
+
+        let expected: ExecutorError = TransactionError::DryRunNotSupported.into();
+
+        assert_eq!(no_wait_result, Err(expected.clone()));
+        assert_eq!(batch_errors, vec![Some(expected.clone()), Some(expected)]);
+        assert!(executor.interactor().get_received_calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sc_call_batch_with_missing_results() {
+        let mut executor = BaseTransactionNetworkExecutor::from_interactor(PartialBatchInteractor::default());
+
+        let results = executor.sc_call_batch::<()>(vec![get_batch_call(), get_batch_call()]).await;
+
+        let expected: ExecutorError = TransactionError::MissingBatchResult { index: 1 }.into();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap().result, Some(()));
+        assert_eq!(results[1].as_ref().err(), Some(&expected));
+    }
+
+    #[tokio::test]
+    async fn test_sc_call_batch_skipping_deserialization() {
+        let mut executor = BaseTransactionNetworkExecutor::from_interactor(PartialBatchInteractor::default());
+        executor.set_skip_deserialization(true);
+
+        // Without a smart contract result, decoding a `u8` would fail with `TransactionError::NoSmartContractResult`.
+        let results = executor.sc_call_batch::<u8>(vec![get_batch_call()]).await;
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].as_ref().unwrap().result, None);
+    }
+
+    #[tokio::test]
+    async fn test_call_label_applies_to_a_single_call() {
+        let mut executor = BaseTransactionNetworkExecutor::from_interactor(PartialBatchInteractor::default());
+        executor.set_call_label(Some("swap".to_string()));
+
+        executor.sc_call::<()>(
+            &Address::from_bech32_string(CONTRACT_ADDRESS).unwrap(),
+            "swap".to_string(),
+            vec![],
+            10_000_000,
+            BigUint::from(0u8),
+            vec![]
+        ).await.unwrap();
+
+        executor.sc_call::<()>(
+            &Address::from_bech32_string(CONTRACT_ADDRESS).unwrap(),
+            "stake".to_string(),
+            vec![],
+            10_000_000,
+            BigUint::from(0u8),
+            vec![]
+        ).await.unwrap();
+
+        let interactor = executor.interactor();
+
+        assert_eq!(interactor.received_labels, vec![Some("swap".to_string()), None]);
+        assert_eq!(interactor.call_label, None);
+    }
+
+    #[tokio::test]
+    async fn test_call_label_wraps_the_error_of_a_single_call() {
+        let mut executor = BaseTransactionNetworkExecutor::from_interactor(MockInteractor::new(get_interactor(None).signer));
+        executor.set_call_label(Some("swap".to_string()));
+
+        let to = Address::from_bech32_string(CONTRACT_ADDRESS).unwrap();
+
+        // No expectation is set on the mock, so both calls fail.
+        let labeled_error = executor.sc_call_no_wait(&to, "swap".to_string(), vec![], 10_000_000, BigUint::from(0u8), vec![])
+            .await
+            .unwrap_err();
+
+        let unlabeled_error = executor.sc_call_no_wait(&to, "swap".to_string(), vec![], 10_000_000, BigUint::from(0u8), vec![])
+            .await
+            .unwrap_err();
+
+        let expected = ExecutorError::Labeled {
+            label: "swap".to_string(),
+            error: Box::new(unlabeled_error.clone())
+        };
+
+        assert_eq!(labeled_error, expected);
+        assert!(!matches!(unlabeled_error, ExecutorError::Labeled { .. }));
+    }
+
+    #[test]
+    fn test_set_gas_price() {
+        let mut executor = BaseTransactionNetworkExecutor::from_interactor(get_interactor(None));
+
+        executor.set_gas_price(2000000000).unwrap();
+
+        assert_eq!(executor.interactor().gas_price_override, Some(2000000000));
+    }
+
+    #[test]
+    fn test_set_gas_price_below_network_minimum() {
+        let mut executor = BaseTransactionNetworkExecutor::from_interactor(get_interactor(Some(2000000000)));
+
+        let result = executor.set_gas_price(1);
+
+        let expected: Result<(), ExecutorError> = Err(TransactionError::GasPriceBelowNetworkMinimum {
+            gas_price: 1,
+            min_gas_price: 1000000000,
+        }.into());
+
+        assert_eq!(result, expected);
+        assert_eq!(executor.interactor().gas_price_override, Some(2000000000));
+    }
+
+    #[test]
+    fn test_decode_call_result_without_smart_contract_result() {
+        let mut response = TransactionOnNetwork::default();
+        response.transaction.status = "success".to_string();
+        response.transaction.gas_used = 50000;
+
+        let result = decode_call_result::<u8>(response).err();
+
+        let Some(ExecutorError::Transaction(TransactionError::NoSmartContractResult { response })) = result else {
+            panic!("expected a NoSmartContractResult error, got {result:?}")
+        };
+
+        assert_eq!(response.transaction.status, "success");
+        assert_eq!(response.transaction.gas_used, 50000);
+    }
+
+    #[test]
+    fn test_decode_call_result_without_smart_contract_result_no_output() {
+        let mut response = TransactionOnNetwork::default();
+        response.transaction.status = "success".to_string();
+
+        let call_result = decode_call_result::<()>(response).unwrap();
+
+        assert_eq!(call_result.result, Some(()));
+    }
+
+    #[test]
+    fn test_decode_call_result_with_smart_contract_result() {
+        let mut response = TransactionOnNetwork::default();
+        response.transaction.smart_contract_results = Some(vec![
+            TransactionOnNetworkTransactionSmartContractResult {
+                hash: "".to_string(),
+                nonce: 1,
+                data: "@6f6b@05".to_string(),
+                ..Default::default()
+            }
+        ]);
+
+        let call_result = decode_call_result::<u8>(response).unwrap();
+
+        assert_eq!(call_result.result, Some(5));
+    }
+
+    #[test]
+    fn test_decode_call_result_ok_without_return_data() {
+        let mut response = TransactionOnNetwork::default();
+        response.transaction.smart_contract_results = Some(vec![
+            TransactionOnNetworkTransactionSmartContractResult {
+                hash: "".to_string(),
+                nonce: 1,
+                data: "@6f6b".to_string(),
+                ..Default::default()
+            }
+        ]);
+
+        let call_result = decode_call_result::<()>(response).unwrap();
+
+        assert_eq!(call_result.result, Some(()));
+    }
+
+    #[test]
+    fn test_decode_call_result_with_wrong_output_type() {
+        let mut response = TransactionOnNetwork::default();
+        response.transaction.smart_contract_results = Some(vec![
+            TransactionOnNetworkTransactionSmartContractResult {
+                hash: "".to_string(),
+                nonce: 1,
+                data: "@6f6b@0102".to_string(),
+                ..Default::default()
+            }
+        ]);
+
+        let result = decode_call_result::<u8>(response).err();
+
+        let expected: ExecutorError = TransactionError::CannotDecodeSmartContractResult {
+            raw: vec![vec![1, 2]],
+            reason: "input too long".to_string()
+        }.into();
+
+        assert_eq!(result, Some(expected));
+    }
+
+    #[test]
+    fn test_decode_call_result_malformed_signal_error_event() {
+        let mut response = TransactionOnNetwork::default();
+        response.transaction.status = "fail".to_string();
+        response.transaction.logs = Some(TransactionOnNetworkTransactionLogs {
+            address: "erd1qqqqqqqqqqqqqpgq4nlkk7jwhqgp4r08lal46tqt70jdv0685u7qrr3l2d".to_string(),
+            events: vec![
+                TransactionOnNetworkTransactionLogsEvents {
+                    address: "erd1qqqqqqqqqqqqqpgq4nlkk7jwhqgp4r08lal46tqt70jdv0685u7qrr3l2d".to_string(),
+                    identifier: "signalError".to_string(),
+                    topics: vec!["aW5zdWZmaWNpZW50IGZ1bmRz".to_string()],
+                    data: None,
+                }
+            ],
+        });
+
+        let result = decode_call_result::<()>(response).err();
+
+        let expected: ExecutorError = TransactionError::WrongTopicsCountForSignalErrorEvent.into();
+
+        assert_eq!(result, Some(expected));
+    }
+
+    #[test]
+    fn test_decode_call_result_failed_with_return_message() {
+        let mut response = TransactionOnNetwork::default();
+        response.transaction.hash = "abcdef".to_string();
+        response.transaction.status = "fail".to_string();
+        response.transaction.return_message = Some("out of gas".to_string());
+
+        let result = decode_call_result::<()>(response).err();
+
+        let expected: ExecutorError = TransactionError::TransactionFailed {
+            tx_hash: "abcdef".to_string(),
+            return_message: Some("out of gas".to_string())
+        }.into();
+
+        assert_eq!(result, Some(expected));
+    }
+
+    #[test]
+    fn test_decode_call_result_failed_without_return_message() {
+        let mut response = TransactionOnNetwork::default();
+        response.transaction.hash = "abcdef".to_string();
+        response.transaction.status = "fail".to_string();
+        response.transaction.return_message = Some("".to_string());
+
+        let result = decode_call_result::<()>(response).err();
+
+        let expected: ExecutorError = TransactionError::TransactionFailed {
+            tx_hash: "abcdef".to_string(),
+            return_message: None
+        }.into();
+
+        assert_eq!(result, Some(expected));
+    }
+
+    #[test]
+    fn test_skip_call_result_decoding() {
+        let mut response = TransactionOnNetwork::default();
+        response.transaction.smart_contract_results = Some(vec![
+            TransactionOnNetworkTransactionSmartContractResult {
+                hash: "".to_string(),
+                nonce: 1,
+                data: "@6f6b@05".to_string(),
+                ..Default::default()
+            }
+        ]);
+
+        let call_result = skip_call_result_decoding::<u8>(response).unwrap();
+
+        assert!(call_result.result.is_none());
+        assert!(call_result.response.transaction.smart_contract_results.is_some());
+    }
+
+    #[test]
+    fn test_split_call_data() {
+        assert_eq!(split_call_data("ESDTTransfer@5745474c442d616263646566@64"), ("ESDTTransfer", vec!["5745474c442d616263646566", "64"]));
+    }
+
+    #[test]
+    fn test_split_call_data_without_arguments() {
+        assert_eq!(split_call_data("getSum"), ("getSum", vec![]));
+    }
+}
@@ -1,26 +1,49 @@
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
 use async_trait::async_trait;
 use base64::Engine;
+use futures::future::{join_all, select, Either};
+use futures::Stream;
 use num_bigint::BigUint;
+use novax_request::gateway::reqwest::{get_shared_client, ReqwestGatewayClient};
 
 use novax_data::Address;
 
+use crate::error::signer::SignerError;
 use crate::error::transaction::TransactionError;
 use crate::ExecutorError;
 use crate::network::models::address::info::AddressGatewayInfoAccount;
-use crate::network::models::network::config::NetworkGatewayConfig;
-use crate::network::transaction::models::send_request::TransactionSendRequest;
-use crate::network::transaction::models::transaction_on_network::{FINAL_TRANSACTION_STATUS, TransactionOnNetwork};
-use crate::network::utils::address::get_address_info;
-use crate::network::utils::network::get_network_config;
-use crate::network::utils::transaction::{get_transaction_on_network, send_transaction};
-use crate::network::utils::wallet::{SignableTransaction, Wallet};
+use crate::network::models::network::config::{NetworkGatewayConfig, NetworkGatewayConfigData};
+use crate::network::models::network::status::NetworkStatus;
+use crate::network::models::simulate::request::SimulationGatewayRequestBody;
+use crate::network::transaction::models::process_status::PENDING_PROCESS_STATUS;
+use crate::network::transaction::models::send_request::{SignedTransaction, TransactionSendRequest};
+use crate::network::transaction::models::transaction_on_network::{FINAL_TRANSACTION_STATUS, PENDING_TRANSACTION_STATUS, TransactionOnNetwork};
+use crate::network::transaction::status::{get_status_stream, TransactionStatus};
+use crate::network::transaction::subscriber::SharedTransactionEventsSubscriber;
+use crate::network::models::address::account::{AccountInfo, METACHAIN_SHARD_ID};
+use crate::network::models::token::properties::TokenProperties;
+use crate::network::utils::address::{get_account, get_address_info, get_egld_balance, get_esdt_balance};
+use crate::network::utils::network::{get_network_config, get_network_status};
+use crate::network::utils::retry::RetryPolicy;
+use crate::network::utils::transaction::{estimate_transaction_cost, get_transaction_on_network, get_transaction_process_status, send_multiple_transactions, send_transaction};
+use crate::network::utils::signer::Signer;
+use crate::network::utils::token::get_token_properties;
+use crate::network::utils::tracing::{GatewayRequestCallback, TracedGatewayClient};
+use crate::network::utils::wallet::{SignableTransaction, TRANSACTION_OPTION_GUARDED, TRANSACTION_OPTION_HASH_SIGN, TRANSACTION_VERSION_WITH_OPTIONS, Wallet};
 use crate::utils::date::get_current_timestamp::{get_current_timestamp, get_timestamp_of_next_block};
+use crate::utils::transaction::egld_transfer::TransferGasConfig;
 
 #[async_trait]
 pub trait BlockchainInteractor: Sized + Send + Sync {
-    async fn new(gateway_url: String, wallet: Wallet) -> Result<Self, ExecutorError>;
+    type Signer: Signer;
+
+    async fn new(gateway_url: String, signer: Self::Signer) -> Result<Self, ExecutorError>;
 
     async fn sc_call(
         &mut self,
@@ -30,48 +53,611 @@ pub trait BlockchainInteractor: Sized + Send + Sync {
         gas_limit: u64
     ) -> Result<TransactionOnNetwork, ExecutorError>;
 
+    /// Sends all the calls and returns the result of each of them, in the same order.
+    ///
+    /// The default implementation sends the calls sequentially, waiting for each one to be executed before sending the next one.
+    async fn sc_call_batch(
+        &mut self,
+        calls: Vec<InteractorCall>
+    ) -> Vec<Result<TransactionOnNetwork, ExecutorError>> {
+        let mut results = Vec::with_capacity(calls.len());
+
+        for call in calls {
+            results.push(self.sc_call(call.to, call.value, call.data, call.gas_limit).await);
+        }
+
+        results
+    }
+
+    /// Sends a call and returns the hash of its transaction, without waiting for its execution nor decoding its result.
+    ///
+    /// The default implementation calls `sc_call`, thus waiting for the execution, and returns the hash of the returned transaction.
+    async fn sc_call_no_wait(&mut self, call: InteractorCall) -> Result<String, ExecutorError> {
+        let response = self.sc_call(call.to, call.value, call.data, call.gas_limit).await?;
+
+        Ok(response.transaction.hash)
+    }
+
+    /// Builds and signs a call as `sc_call` would, including the live nonce and gas lookups, without broadcasting it.
+    ///
+    /// The default implementation returns `TransactionError::DryRunNotSupported`.
+    async fn build_transaction(&self, _call: InteractorCall) -> Result<SignedTransaction, ExecutorError> {
+        Err(TransactionError::DryRunNotSupported.into())
+    }
+
+    /// Estimates the gas limit of a call, as `sc_call` would with a gas limit of zero, without broadcasting it.
+    ///
+    /// The default implementation returns `TransactionError::GasEstimationNotSupported`.
+    async fn estimate_gas(&self, _call: InteractorCall) -> Result<u64, ExecutorError> {
+        Err(TransactionError::GasEstimationNotSupported.into())
+    }
+
+    /// Returns the gas parameters used to compute the gas limit of the transactions which don't execute any code, such as EGLD transfers.
+    ///
+    /// The default implementation returns `TransferGasConfig::default()`, holding the mainnet's values.
+    fn get_transfer_gas_config(&self) -> TransferGasConfig {
+        TransferGasConfig::default()
+    }
+
+    /// Sets the label of the call about to be made, such as "swap", or removes it once the call returned.
+    ///
+    /// The default implementation ignores the label.
+    fn set_call_label(&mut self, _label: Option<String>) {}
+
     fn get_sender_address(&self) -> Address;
 }
 
+/// The raw parameters of a call sent by a `BlockchainInteractor`, once normalized.
+#[derive(Clone, Debug)]
+pub struct InteractorCall {
+    pub to: String,
+    pub value: BigUint,
+    pub data: String,
+    pub gas_limit: u64
+}
+
 #[derive(Clone, Debug)]
-pub struct Interactor {
+pub struct Interactor<S: Signer = Wallet> {
     pub gateway_url: String,
-    pub wallet: Wallet,
+    pub http_client: reqwest::Client,
+    pub signer: S,
     pub network_config: NetworkGatewayConfig,
     pub refresh_strategy: TransactionRefreshStrategy,
-    pub timeout: Duration
+    pub timeout: Duration,
+    pub gas_estimation_multiplier: f64,
+    pub retry_policy: RetryPolicy,
+    pub on_transaction_sent: Option<TransactionSentCallback>,
+    pub on_gateway_request: Option<GatewayRequestCallback>,
+    pub request_id: Option<String>,
+    pub call_label: Option<String>,
+    pub cancellation_flag: Option<Arc<AtomicBool>>,
+    pub events_subscriber: Option<SharedTransactionEventsSubscriber>,
+    pub process_status_check: bool,
+    pub pending_transaction_policy: PendingTransactionPolicy,
+    pub nonce_cache_enabled: bool,
+    pub cached_nonce: Option<u64>,
+    pub next_nonce: Option<u64>,
+    pub sender_override: Option<Address>,
+    pub chain_id_override: Option<String>,
+    pub gas_price_override: Option<u64>,
+    pub version_override: Option<u32>,
+    pub options_override: Option<u32>,
+    pub hash_signing: bool,
+    pub balance_precheck: bool,
+    pub guardian: Option<SharedSigner>,
+    pub relayer: Option<Address>,
+    pub relayer_signer: Option<SharedSigner>
+}
+
+/// A signer co-signing every sent transaction, such as the guardian of a guarded account or the relayer of relayed transactions.
+#[derive(Clone)]
+pub struct SharedSigner(pub Arc<dyn Signer>);
+
+impl Debug for SharedSigner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SharedSigner - {}", self.0.get_address().to_bech32_string().unwrap_or_default())
+    }
 }
 
+/// A callback invoked with the hash of a transaction right after it is broadcast, before waiting for its execution.
+#[derive(Clone)]
+pub struct TransactionSentCallback(pub Arc<dyn Fn(&str) + Send + Sync>);
+
+impl Debug for TransactionSentCallback {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TransactionSentCallback")
+    }
+}
+
+/// The strategy used to poll the gateway while waiting for a transaction to be executed.
 #[derive(Clone, Debug)]
 pub enum TransactionRefreshStrategy {
+    /// Polls once per block, right after the expected timestamp of the next block.
     EachBlock,
+    /// Polls at a fixed interval.
     EachDuration(Duration)
 }
 
-impl Interactor {
+/// The behavior when a sent transaction stays in the "pending" status, for example because its gas price is too low during a spike.
+#[derive(Clone, Debug)]
+pub enum PendingTransactionPolicy {
+    /// Keeps polling until the timeout elapses.
+    Wait,
+    /// Fails with `TransactionError::StuckPending` once the transaction has been pending for the given duration.
+    Fail { after: Duration },
+    /// Once the transaction has been pending for `after`, sends it again with the same nonce and its gas price multiplied by `multiplier`,
+    /// so it replaces the pending one. This is done at most `max_attempts` times, then `TransactionError::StuckPending` is returned.
+    ///
+    /// The replacement is only accepted by networks supporting replacing a pending transaction with a higher gas price.
+    BumpGasPrice { after: Duration, multiplier: f64, max_attempts: u32 }
+}
+
+impl PendingTransactionPolicy {
+    /// Returns `TransactionError::InvalidGasPriceMultiplier` if the policy bumps the gas price by a multiplier which is not a finite number above 1,
+    /// since the replacement would not have a higher gas price than the stuck transaction.
+    pub(crate) fn validate(&self) -> Result<(), ExecutorError> {
+        if let PendingTransactionPolicy::BumpGasPrice { multiplier, .. } = self {
+            if *multiplier <= 1.0 || !multiplier.is_finite() {
+                return Err(TransactionError::InvalidGasPriceMultiplier { multiplier: *multiplier }.into())
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns how long a transaction may stay pending before being considered stuck, if ever.
+    fn get_stuck_after(&self) -> Option<Duration> {
+        match self {
+            PendingTransactionPolicy::Wait => None,
+            PendingTransactionPolicy::Fail { after } | PendingTransactionPolicy::BumpGasPrice { after, .. } => Some(*after)
+        }
+    }
+}
+
+impl<S: Signer> Interactor<S> {
+    /// Creates a new `Interactor` sending all its requests through the provided `reqwest::Client`.
+    ///
+    /// This allows configuring a proxy, custom TLS, default headers or timeouts.
+    pub async fn new_with_client(
+        gateway_url: String,
+        signer: S,
+        http_client: reqwest::Client
+    ) -> Result<Self, ExecutorError> {
+        let gateway_client = ReqwestGatewayClient::new(gateway_url.clone(), http_client.clone());
+        let network_config = get_network_config(&gateway_client).await?;
+
+        Ok(
+            Self::new_with_network_config(
+                gateway_url,
+                signer,
+                http_client,
+                network_config.config
+            )
+        )
+    }
+
+    /// Creates a new `Interactor` using the provided network configuration instead of fetching it from the gateway's `/network/config` endpoint.
+    ///
+    /// No request is made, so transactions can be built and signed offline when their nonce is cached and their gas limit is set.
+    pub fn new_with_network_config(
+        gateway_url: String,
+        signer: S,
+        http_client: reqwest::Client,
+        network_config: NetworkGatewayConfigData
+    ) -> Self {
+        Self {
+            gateway_url,
+            http_client,
+            signer,
+            network_config: NetworkGatewayConfig { config: network_config },
+            refresh_strategy: TransactionRefreshStrategy::EachBlock,
+            timeout: Duration::from_secs(10),
+            gas_estimation_multiplier: 1.1,
+            retry_policy: RetryPolicy::default(),
+            on_transaction_sent: None,
+            on_gateway_request: None,
+            request_id: None,
+            call_label: None,
+            cancellation_flag: None,
+            events_subscriber: None,
+            process_status_check: false,
+            pending_transaction_policy: PendingTransactionPolicy::Wait,
+            nonce_cache_enabled: false,
+            cached_nonce: None,
+            next_nonce: None,
+            sender_override: None,
+            chain_id_override: None,
+            gas_price_override: None,
+            version_override: None,
+            options_override: None,
+            hash_signing: false,
+            balance_precheck: false,
+            guardian: None,
+            relayer: None,
+            relayer_signer: None
+        }
+    }
+
+    /// Returns the chain ID used to build transactions: the overridden one if any, otherwise the one fetched from the gateway.
+    fn get_chain_id(&self) -> String {
+        self.chain_id_override
+            .clone()
+            .unwrap_or_else(|| self.network_config.config.erd_chain_id.clone())
+    }
+
+    /// Returns the gas price used to build transactions: the overridden one if any, otherwise the network's minimum gas price.
+    ///
+    /// An overridden gas price below the network's minimum, for example after the network configuration was fetched again, is an error.
+    pub(crate) fn get_gas_price(&self) -> Result<u64, ExecutorError> {
+        let min_gas_price = self.network_config.config.erd_min_gas_price;
+
+        let Some(gas_price) = self.gas_price_override else {
+            return Ok(min_gas_price)
+        };
+
+        if gas_price < min_gas_price {
+            return Err(TransactionError::GasPriceBelowNetworkMinimum { gas_price, min_gas_price }.into())
+        }
+
+        Ok(gas_price)
+    }
+
+    /// Returns the version and the options of the transactions to send: the overridden ones if any,
+    /// otherwise the network's minimum version and no options.
+    ///
+    /// Hash-signed transactions require the hash signing option, and guarded transactions the guarded one.
+    /// Options are only supported from version 2, so the version is raised if any option is set.
+    fn get_version_and_options(&self) -> (u32, u32) {
+        let mut version = self.version_override
+            .unwrap_or(self.network_config.config.erd_min_transaction_version as u32);
+        let mut options = self.options_override.unwrap_or(0);
+
+        if self.hash_signing {
+            options |= TRANSACTION_OPTION_HASH_SIGN;
+        }
+
+        if self.guardian.is_some() {
+            options |= TRANSACTION_OPTION_GUARDED;
+        }
+
+        if options != 0 {
+            version = version.max(TRANSACTION_VERSION_WITH_OPTIONS);
+        }
+
+        (version, options)
+    }
+
+    /// Returns the client sending the requests to the gateway, reporting each of them to the `on_gateway_request` callback if any.
+    fn get_gateway_client(&self) -> TracedGatewayClient {
+        TracedGatewayClient {
+            client: ReqwestGatewayClient::new(self.gateway_url.clone(), self.http_client.clone()),
+            request_id: self.request_id.clone(),
+            label: self.call_label.clone(),
+            on_request: self.on_gateway_request.clone()
+        }
+    }
+
+    /// Fetches the state of `address` from the `/address/{address}` endpoint: its nonce, balance, username and code hash.
+    ///
+    /// The shard is computed from the address and the number of shards of the network.
+    pub async fn get_account(&self, address: &Address) -> Result<AccountInfo, ExecutorError> {
+        let client = self.get_gateway_client();
+        let num_shards = self.network_config.config.erd_num_shards_without_meta;
+
+        self.retry_policy
+            .run(|| get_account(&client, address.clone(), num_shards))
+            .await
+    }
+
+    /// Fetches the EGLD balance of `address` from the `/address/{address}` endpoint.
+    pub async fn get_egld_balance(&self, address: &Address) -> Result<BigUint, ExecutorError> {
+        let client = self.get_gateway_client();
+
+        self.retry_policy
+            .run(|| get_egld_balance(&client, address.clone()))
+            .await
+    }
+
+    /// Fetches the balance of `token_identifier` held by `address` from the `/address/{address}/esdt/{token_identifier}` endpoint.
+    ///
+    /// For non-fungible tokens, the identifier includes the nonce, such as "SFT-abcdef-01".
+    pub async fn get_esdt_balance(&self, address: &Address, token_identifier: &str) -> Result<BigUint, ExecutorError> {
+        let client = self.get_gateway_client();
+
+        self.retry_policy
+            .run(|| get_esdt_balance(&client, address.clone(), token_identifier))
+            .await
+    }
+
+    /// Fetches the properties of `token_identifier`, such as its decimals and owner, by querying `getTokenProperties` on the ESDT system smart contract.
+    pub async fn get_token_properties(&self, token_identifier: &str) -> Result<TokenProperties, ExecutorError> {
+        let client = self.get_gateway_client();
+
+        self.retry_policy
+            .run(|| get_token_properties(&client, token_identifier))
+            .await
+    }
+
+    /// Fetches the current round, epoch, nonce and highest final nonce of `shard` from the `/network/status/{shard}` endpoint.
+    ///
+    /// `None` targets the metachain, whose epoch is the one of the whole network.
+    pub async fn get_network_status(&self, shard: Option<u32>) -> Result<NetworkStatus, ExecutorError> {
+        let client = self.get_gateway_client();
+        let shard = shard.unwrap_or(METACHAIN_SHARD_ID);
+
+        self.retry_policy
+            .run(|| get_network_status(&client, shard))
+            .await
+    }
+
+    /// Fetches the network configuration again and empties the cached nonce.
+    pub async fn reconnect(&mut self) -> Result<(), ExecutorError> {
+        self.network_config = get_network_config(&self.get_gateway_client()).await?;
+        self.cached_nonce = None;
+
+        Ok(())
+    }
+
+    /// Returns the address set as the transactions' sender, if any, otherwise the signer's one.
+    fn get_sender(&self) -> Address {
+        self.sender_override
+            .clone()
+            .unwrap_or_else(|| self.signer.get_address())
+    }
+
     async fn get_account_info(&self) -> Result<AddressGatewayInfoAccount, ExecutorError> {
-        let address = self.wallet.get_address();
+        let address = self.get_sender();
+        let client = self.get_gateway_client();
+
+        let address_info = self.retry_policy
+            .run(|| get_address_info(&client, address.clone()))
+            .await?;
+
+        Ok(address_info.account)
+    }
+
+    /// Returns the sender's bech32 address and the nonce to use for the next transaction.
+    ///
+    /// If a nonce was set with `set_next_nonce`, or if the nonce cache is enabled and filled, no request is made to the gateway.
+    async fn get_sender_address_and_nonce(&self) -> Result<(String, u64), ExecutorError> {
+        if let Some(nonce) = self.next_nonce {
+            return Ok((self.get_sender().to_bech32_string()?, nonce))
+        }
+
+        if self.nonce_cache_enabled {
+            if let Some(nonce) = self.cached_nonce {
+                return Ok((self.get_sender().to_bech32_string()?, nonce))
+            }
+        }
+
+        let sender_info = self.get_account_info().await?;
+
+        Ok((sender_info.address, sender_info.nonce))
+    }
+
+    /// Returns the sender's address and the nonce to use for the transaction about to be sent, as `get_sender_address_and_nonce`,
+    /// then clears the nonce set with `set_next_nonce` so it is used only once.
+    ///
+    /// The returned boolean is whether that explicit nonce is used, in which case the cached nonce must be left untouched.
+    async fn take_sender_address_and_nonce(&mut self) -> Result<(String, u64, bool), ExecutorError> {
+        let is_nonce_explicit = self.next_nonce.is_some();
+        let sender_address_and_nonce = self.get_sender_address_and_nonce().await;
+        self.next_nonce = None;
+
+        let (sender_address, nonce) = sender_address_and_nonce?;
+
+        Ok((sender_address, nonce, is_nonce_explicit))
+    }
+
+    /// Stores the nonce to use for the next transaction, if the nonce cache is enabled.
+    ///
+    /// Passing `None` empties the cache, forcing the nonce to be fetched from the gateway on the next transaction.
+    fn set_cached_nonce(&mut self, next_nonce: Option<u64>) {
+        if self.nonce_cache_enabled {
+            self.cached_nonce = next_nonce;
+        }
+    }
+
+    /// Waits for a transaction sent elsewhere, for example before a restart, and returns it once executed.
+    ///
+    /// The gateway is polled as when waiting for a transaction sent by `sc_call`, with the same refresh strategy, timeout and retry policy.
+    pub async fn wait_for_transaction(&self, tx_hash: &str) -> Result<TransactionOnNetwork, ExecutorError> {
+        self.wait_for_execution(tx_hash).await
+    }
+
+    /// Waits for several transactions concurrently, and returns the result of each of them in the same order as `tx_hashes`.
+    ///
+    /// Each transaction is polled as by `wait_for_transaction`, sharing the same timeout which starts for all of them at once.
+    /// A failing transaction doesn't stop the wait for the others. The gateway has no endpoint returning several transactions,
+    /// so each poll is a request per transaction still awaited.
+    pub async fn wait_for_many(&self, tx_hashes: &[String]) -> Vec<Result<TransactionOnNetwork, ExecutorError>> {
+        let client = self.get_gateway_client();
+
+        self.wait_until_many_executed(
+            tx_hashes,
+            |tx_hash| get_transaction_on_network(&client, tx_hash),
+            |tx_hash| get_transaction_process_status(&client, tx_hash)
+        ).await
+    }
+
+    /// Waits for several transactions as `wait_for_many`, fetching each of them with `fetch` and its process status with `fetch_process_status`.
+    async fn wait_until_many_executed<'a, F, Fut, P, PFut>(
+        &self,
+        tx_hashes: &'a [String],
+        fetch: F,
+        fetch_process_status: P
+    ) -> Vec<Result<TransactionOnNetwork, ExecutorError>>
+        where
+            F: Fn(&'a str) -> Fut,
+            Fut: Future<Output = Result<TransactionOnNetwork, ExecutorError>>,
+            P: Fn(&'a str) -> PFut,
+            PFut: Future<Output = Result<String, ExecutorError>>
+    {
+        let executions = tx_hashes
+            .iter()
+            .map(|tx_hash| self.wait_until_executed(
+                tx_hash,
+                || fetch(tx_hash),
+                || fetch_process_status(tx_hash),
+                &|_| {}
+            ));
 
-        Ok(get_address_info(&self.gateway_url, address).await?.account)
+        join_all(executions).await
     }
 
+    /// Watches a sent transaction, such as one broadcast with `sc_call_no_wait`, and returns a stream of its status transitions,
+    /// to show the progress of a transaction in a user interface.
+    ///
+    /// The stream yields `TransactionStatus::Sent`, then `Pending` and `CrossShard` as they are observed, each status once in a row,
+    /// and ends with `Completed`, holding what `wait_for_transaction` would return.
+    /// The gateway is polled as by `wait_for_transaction`, only while the stream is polled.
+    pub fn watch_transaction<'a>(&'a self, tx_hash: &'a str) -> impl Stream<Item = TransactionStatus> + 'a {
+        get_status_stream(move |report| async move {
+            self.wait_for_execution_reporting(tx_hash, &*report).await
+        })
+    }
+
+    /// Returns whether the cancellation flag, if any, has been raised.
+    fn is_cancelled(&self) -> bool {
+        self.cancellation_flag
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::SeqCst))
+    }
+
+    /// Polls the gateway until the transaction is executed, the timeout elapses or the cancellation flag is raised.
+    ///
+    /// The flag is checked before each poll, so cancelling takes effect after the current sleep, at most one refresh interval later.
+    ///
+    /// If an events subscriber is set, it is awaited while polling, and the gateway is polled again as soon as it notifies the transaction
+    /// instead of after the current sleep. Polling goes on as usual if the subscriber fails.
     async fn wait_for_execution(&self, tx_hash: &str) -> Result<TransactionOnNetwork, ExecutorError> {
-        let end_timestamp = get_current_timestamp()? + self.timeout;
+        self.wait_for_execution_reporting(tx_hash, &|_| {}).await
+    }
 
-        loop {
-            let transaction_on_network = get_transaction_on_network(
-                &self.gateway_url,
-                tx_hash
-            ).await?;
+    /// Waits for the transaction as `wait_for_execution`, calling `report` with its intermediate status after each poll.
+    async fn wait_for_execution_reporting(
+        &self,
+        tx_hash: &str,
+        report: &(dyn Fn(TransactionStatus) + Send + Sync)
+    ) -> Result<TransactionOnNetwork, ExecutorError> {
+        let client = self.get_gateway_client();
 
-            if FINAL_TRANSACTION_STATUS.contains(&transaction_on_network.transaction.status.as_ref()) {
-                return Ok(transaction_on_network)
+        self.wait_until_executed(
+            tx_hash,
+            || get_transaction_on_network(&client, tx_hash),
+            || get_transaction_process_status(&client, tx_hash),
+            report
+        ).await
+    }
+
+    /// Waits for the transaction as `wait_for_execution`, fetching it with `fetch` and its process status with `fetch_process_status`.
+    ///
+    /// The events subscriber, if any, is raced against polling: an already executed transaction, the timeout or the cancellation flag
+    /// are noticed by the polling, while a notification stops the current sleep and polls the gateway right away.
+    async fn wait_until_executed<F, Fut, P, PFut>(
+        &self,
+        tx_hash: &str,
+        fetch: F,
+        fetch_process_status: P,
+        report: &(dyn Fn(TransactionStatus) + Send + Sync)
+    ) -> Result<TransactionOnNetwork, ExecutorError>
+        where
+            F: Fn() -> Fut,
+            Fut: Future<Output = Result<TransactionOnNetwork, ExecutorError>>,
+            P: Fn() -> PFut,
+            PFut: Future<Output = Result<String, ExecutorError>>
+    {
+        // Both polls share the same start, so restarting after a notification keeps the timeout and the pending policy's deadline.
+        let start_timestamp = get_current_timestamp()?;
+
+        if let Some(events_subscriber) = self.events_subscriber.as_ref() {
+            let polling = pin!(self.poll_until_executed(tx_hash, start_timestamp, &fetch, &fetch_process_status, report));
+            let notification = pin!(events_subscriber.0.wait_for_transaction(tx_hash));
+
+            // Whether the transaction is notified or the subscription is lost, the gateway is polled again right after.
+            if let Either::Left((result, _)) = select(polling, notification).await {
+                return result
+            }
+        }
+
+        self.poll_until_executed(
+            tx_hash,
+            start_timestamp,
+            fetch,
+            fetch_process_status,
+            report
+        ).await
+    }
+
+    /// Calls `fetch` following the refresh strategy until the transaction is executed, the timeout elapses or the cancellation flag is raised,
+    /// both the timeout and the pending policy's duration being counted from `start_timestamp`.
+    ///
+    /// A final transaction whose smart contract results are present but empty is not returned right away, since the results of a cross-shard call
+    /// may only appear in a later poll. It is polled again until its results arrive, and returned as is once the timeout elapses.
+    ///
+    /// If the `PendingTransactionPolicy` allows it, `TransactionError::StuckPending` is returned once the transaction has been pending for too long.
+    ///
+    /// If `process_status_check` is enabled, a final transaction is returned only once `fetch_process_status` is no longer "pending",
+    /// meaning the smart contract results of a cross-shard call, including the reply, are executed in every shard.
+    ///
+    /// After each poll of a transaction not returned yet, `report` is called with its status: `Pending` or `CrossShard`.
+    async fn poll_until_executed<F, Fut, P, PFut>(
+        &self,
+        tx_hash: &str,
+        start_timestamp: Duration,
+        fetch: F,
+        fetch_process_status: P,
+        report: &(dyn Fn(TransactionStatus) + Send + Sync)
+    ) -> Result<TransactionOnNetwork, ExecutorError>
+        where
+            F: Fn() -> Fut,
+            Fut: Future<Output = Result<TransactionOnNetwork, ExecutorError>>,
+            P: Fn() -> PFut,
+            PFut: Future<Output = Result<String, ExecutorError>>
+    {
+        let end_timestamp = start_timestamp + self.timeout;
+        let stuck_timestamp = self.pending_transaction_policy
+            .get_stuck_after()
+            .map(|after| start_timestamp + after);
+
+        loop {
+            if self.is_cancelled() {
+                return Err(TransactionError::WaitingForTransactionCancelled { tx_hash: tx_hash.to_string() }.into())
             }
 
+            let transaction_on_network = self.retry_policy
+                .run(&fetch)
+                .await?;
+
             let current_timestamp = get_current_timestamp()?;
 
-            if current_timestamp >= end_timestamp {
-                return Err(TransactionError::TimeoutWhenRetrievingTransactionOnNetwork.into())
+            if FINAL_TRANSACTION_STATUS.contains(&transaction_on_network.transaction.status.as_ref()) {
+                if current_timestamp >= end_timestamp {
+                    return Ok(transaction_on_network)
+                }
+
+                if !transaction_on_network.is_awaiting_smart_contract_results() {
+                    if !self.process_status_check {
+                        return Ok(transaction_on_network)
+                    }
+
+                    let process_status = self.retry_policy
+                        .run(&fetch_process_status)
+                        .await?;
+
+                    if process_status != PENDING_PROCESS_STATUS {
+                        return Ok(transaction_on_network)
+                    }
+                }
+
+                report(TransactionStatus::CrossShard);
+            } else if stuck_timestamp.is_some_and(|stuck_timestamp| current_timestamp >= stuck_timestamp)
+                && transaction_on_network.transaction.status == PENDING_TRANSACTION_STATUS {
+                return Err(TransactionError::StuckPending { tx_hash: tx_hash.to_string() }.into())
+            } else if current_timestamp >= end_timestamp {
+                return Err(TransactionError::TimeoutWhenRetrievingTransactionOnNetwork { tx_hash: tx_hash.to_string() }.into())
+            } else {
+                report(TransactionStatus::Pending);
             }
 
             match self.refresh_strategy {
@@ -86,8 +672,220 @@ impl Interactor {
         }
     }
 
+    async fn estimate_gas_limit(
+        &self,
+        nonce: u64,
+        value: String,
+        receiver: String,
+        sender: String,
+        data: &str
+    ) -> Result<u64, ExecutorError> {
+        let body = SimulationGatewayRequestBody {
+            nonce,
+            value,
+            receiver,
+            sender,
+            gas_price: self.get_gas_price()?,
+            gas_limit: 0,
+            data: base64::engine::general_purpose::STANDARD.encode(data),
+            chain_id: self.get_chain_id(),
+            version: self.network_config.config.erd_min_transaction_version,
+        };
+
+        let client = self.get_gateway_client();
+        let gas_units = self.retry_policy
+            .run(|| estimate_transaction_cost(&client, &body))
+            .await?;
+
+        Ok((gas_units as f64 * self.gas_estimation_multiplier).ceil() as u64)
+    }
+
+    /// Builds and signs a call without broadcasting it, allowing to send it later through any channel.
+    ///
+    /// The nonce is the account's current one, or the cached one if the nonce cache is enabled, in which case it is incremented.
+    /// If the call's `gas_limit` is zero, it is estimated through the gateway.
+    pub async fn build_signed_transaction(&mut self, call: InteractorCall) -> Result<SignedTransaction, ExecutorError> {
+        let (sender_address, nonce, is_nonce_explicit) = self.take_sender_address_and_nonce().await?;
+
+        let signed_transaction = self.sign_call(
+            nonce,
+            sender_address,
+            call,
+            self.get_gas_price()?
+        ).await?;
+
+        if !is_nonce_explicit {
+            self.set_cached_nonce(Some(nonce + 1));
+        }
+
+        Ok(signed_transaction)
+    }
+
+    /// Signs and broadcasts a call using the provided nonce and gas price, then returns the hash of the sent transaction.
+    async fn send_call(
+        &self,
+        nonce: u64,
+        sender_address: String,
+        call: InteractorCall,
+        gas_price: u64
+    ) -> Result<String, ExecutorError> {
+        let transaction_request = self.sign_call(
+            nonce,
+            sender_address,
+            call,
+            gas_price
+        ).await?;
+
+        self.broadcast(transaction_request).await
+    }
+
+    /// Broadcasts a signed transaction, checking the sender's balance first if `balance_precheck` is enabled, and returns its hash.
+    async fn broadcast(&self, transaction_request: TransactionSendRequest) -> Result<String, ExecutorError> {
+        if self.balance_precheck {
+            let available_balance = self.get_egld_balance(&self.get_sender()).await?;
+            get_remaining_balance(&transaction_request, &available_balance)?;
+        }
+
+        let tx_hash = send_transaction(
+            &self.get_gateway_client(),
+            &transaction_request
+        )
+            .await?;
+
+        if let Some(on_transaction_sent) = self.on_transaction_sent.as_ref() {
+            (on_transaction_sent.0)(&tx_hash);
+        }
+
+        Ok(tx_hash)
+    }
+
+    /// Waits for a transaction sent by `sc_call`. With the `PendingTransactionPolicy::BumpGasPrice` policy, each time the transaction
+    /// is stuck in the "pending" status, the call is sent again with the same nonce and a higher gas price, and the replacement is awaited.
+    async fn wait_for_execution_replacing_stuck(
+        &self,
+        tx_hash: String,
+        nonce: u64,
+        sender_address: String,
+        call: InteractorCall,
+        gas_price: u64
+    ) -> Result<TransactionOnNetwork, ExecutorError> {
+        self.wait_replacing_stuck(
+            tx_hash,
+            nonce,
+            sender_address,
+            call,
+            gas_price,
+            |tx_hash| async move { self.wait_for_execution(&tx_hash).await },
+            |transaction_request| self.broadcast(transaction_request)
+        ).await
+    }
+
+    /// Waits for a transaction as `wait_for_execution_replacing_stuck`, waiting with `wait` and broadcasting the replacements with `broadcast`.
+    #[allow(clippy::too_many_arguments)]
+    async fn wait_replacing_stuck<W, WFut, B, BFut>(
+        &self,
+        mut tx_hash: String,
+        nonce: u64,
+        sender_address: String,
+        call: InteractorCall,
+        mut gas_price: u64,
+        wait: W,
+        broadcast: B
+    ) -> Result<TransactionOnNetwork, ExecutorError>
+        where
+            W: Fn(String) -> WFut,
+            WFut: Future<Output = Result<TransactionOnNetwork, ExecutorError>>,
+            B: Fn(TransactionSendRequest) -> BFut,
+            BFut: Future<Output = Result<String, ExecutorError>>
+    {
+        let mut attempts = 0;
+
+        loop {
+            let result = wait(tx_hash.clone()).await;
+
+            let PendingTransactionPolicy::BumpGasPrice { multiplier, max_attempts, .. } = self.pending_transaction_policy else {
+                return result
+            };
+
+            let is_stuck = matches!(result, Err(ExecutorError::Transaction(TransactionError::StuckPending { .. })));
+
+            if !is_stuck || attempts >= max_attempts {
+                return result
+            }
+
+            attempts += 1;
+            gas_price = (gas_price as f64 * multiplier).ceil() as u64;
+
+            let transaction_request = self.sign_call(
+                nonce,
+                sender_address.clone(),
+                call.clone(),
+                gas_price
+            ).await?;
+
+            tx_hash = broadcast(transaction_request).await?;
+        }
+    }
+
+    /// Returns the gas limit of a call: its own one, or the estimated one if it is zero.
+    /// The relayer's move balance cost is added for relayed transactions.
+    async fn get_gas_limit(
+        &self,
+        nonce: u64,
+        sender_address: &str,
+        call: &InteractorCall
+    ) -> Result<u64, ExecutorError> {
+        let mut gas_limit = if call.gas_limit == 0 {
+            self.estimate_gas_limit(
+                nonce,
+                call.value.to_string(),
+                call.to.clone(),
+                sender_address.to_string(),
+                &call.data
+            ).await?
+        } else {
+            call.gas_limit
+        };
+
+        if self.relayer.is_some() || self.relayer_signer.is_some() {
+            gas_limit += self.network_config.config.erd_min_gas_limit;
+        }
+
+        Ok(gas_limit)
+    }
+
+    /// Signs a call using the provided nonce and gas price, estimating its gas limit first if it is zero.
+    async fn sign_call(
+        &self,
+        nonce: u64,
+        sender_address: String,
+        call: InteractorCall,
+        gas_price: u64
+    ) -> Result<TransactionSendRequest, ExecutorError> {
+        let gas_limit = self.get_gas_limit(
+            nonce,
+            &sender_address,
+            &call
+        ).await?;
+
+        let (version, options) = self.get_version_and_options();
+
+        self.get_sendable_transaction(
+            nonce,
+            call.value.to_string(),
+            call.to,
+            sender_address,
+            gas_price,
+            gas_limit,
+            call.data,
+            self.get_chain_id(),
+            version,
+            options
+        ).await
+    }
+
     #[allow(clippy::too_many_arguments)]
-    fn get_sendable_transaction(
+    async fn get_sendable_transaction(
         &self,
         nonce: u64,
         value: String,
@@ -99,7 +897,7 @@ impl Interactor {
         chain_id: String,
         version: u32,
         options: u32
-    ) -> TransactionSendRequest {
+    ) -> Result<TransactionSendRequest, ExecutorError> {
         let base64_encoded_data = base64::engine::general_purpose::STANDARD.encode(data);
 
         let tx_to_sign = SignableTransaction {
@@ -113,66 +911,1213 @@ impl Interactor {
             chain_id,
             version,
             options,
+            guardian: self.guardian.as_ref().map(|guardian| guardian.0.get_address().to_bech32_string()).transpose()?,
+            relayer: self.get_relayer_address()?,
         };
 
-        tx_to_sign.into_sendable_transaction(&self.wallet)
+        let bytes_to_sign = tx_to_sign.get_bytes_to_sign();
+        let mut transaction_request = tx_to_sign.into_sendable_transaction(&self.signer).await?;
+
+        // A transaction built for another sender with `set_sender` is not expected to be signed by its key.
+        if cfg!(debug_assertions) && self.sender_override.is_none() {
+            check_signature(&transaction_request)?;
+        }
+
+        if let Some(guardian) = self.guardian.as_ref() {
+            transaction_request.guardian_signature = Some(hex::encode(guardian.0.sign(&bytes_to_sign).await?));
+        }
+
+        if let Some(relayer_signer) = self.relayer_signer.as_ref() {
+            transaction_request.relayer_signature = Some(hex::encode(relayer_signer.0.sign(&bytes_to_sign).await?));
+        }
+
+        Ok(transaction_request)
     }
-}
 
-#[async_trait]
-impl BlockchainInteractor for Interactor {
-    async fn new(
-        gateway_url: String,
-        wallet: Wallet
-    ) -> Result<Self, ExecutorError> {
-        let network_config = get_network_config(&gateway_url).await?;
+    /// Returns the bech32 address of the relayer, if any: the relayer signer's one, otherwise the one set without a signer.
+    fn get_relayer_address(&self) -> Result<Option<String>, ExecutorError> {
+        if let Some(relayer_signer) = self.relayer_signer.as_ref() {
+            return Ok(Some(relayer_signer.0.get_address().to_bech32_string()?))
+        }
 
-        Ok(
-            Self {
-                gateway_url,
-                wallet,
-                network_config,
-                refresh_strategy: TransactionRefreshStrategy::EachBlock,
-                timeout: Duration::from_secs(10)
-            }
-        )
+        let relayer_address = self.relayer
+            .as_ref()
+            .map(|relayer| relayer.to_bech32_string())
+            .transpose()?;
+
+        Ok(relayer_address)
     }
+}
 
-    async fn sc_call(
+impl<S: Signer> Interactor<S> {
+    /// Sends a call and waits for its execution, see `BlockchainInteractor::sc_call`.
+    async fn send_call_and_wait(
         &mut self,
         to: String,
         value: BigUint,
         data: String,
         gas_limit: u64
     ) -> Result<TransactionOnNetwork, ExecutorError> {
-        let sender_info = self.get_account_info().await?;
-        let sender_address = sender_info.address;
-        let nonce = sender_info.nonce;
+        let (sender_address, nonce, is_nonce_explicit) = self.take_sender_address_and_nonce().await?;
 
-        let transaction_request = self.get_sendable_transaction(
-            nonce,
-            value.to_string(),
+        let call = InteractorCall {
             to,
-            sender_address,
-            self.network_config.config.erd_min_gas_price,
-            gas_limit,
+            value,
             data,
-            self.network_config.config.erd_chain_id.clone(),
-            1,
-            0
-        );
+            gas_limit,
+        };
 
-        let tx_hash = send_transaction(
-            &self.gateway_url,
-            &transaction_request
-        )
-            .await?;
+        let gas_price = self.get_gas_price()?;
+
+        let tx_hash = self.send_call_updating_cached_nonce(
+            nonce,
+            is_nonce_explicit,
+            sender_address.clone(),
+            call.clone(),
+            gas_price
+        ).await?;
+
+        self.wait_for_execution_replacing_stuck(
+            tx_hash,
+            nonce,
+            sender_address,
+            call,
+            gas_price
+        ).await
+    }
+
+    /// Sends a call and returns its hash without waiting for its execution, see `BlockchainInteractor::sc_call_no_wait`.
+    async fn send_call_without_waiting(&mut self, call: InteractorCall) -> Result<String, ExecutorError> {
+        let (sender_address, nonce, is_nonce_explicit) = self.take_sender_address_and_nonce().await?;
+        let gas_price = self.get_gas_price()?;
+
+        self.send_call_updating_cached_nonce(
+            nonce,
+            is_nonce_explicit,
+            sender_address,
+            call,
+            gas_price
+        ).await
+    }
 
+    /// Sends a call, then increments the cached nonce if the gateway accepted it, or empties it otherwise.
+    ///
+    /// The cached nonce is left untouched if `nonce` was set explicitly with `set_next_nonce`, since it may be unrelated to the account's sequence.
+    async fn send_call_updating_cached_nonce(
+        &mut self,
+        nonce: u64,
+        is_nonce_explicit: bool,
+        sender_address: String,
+        call: InteractorCall,
+        gas_price: u64
+    ) -> Result<String, ExecutorError> {
+        let sent_hash = self.send_call(
+            nonce,
+            sender_address,
+            call,
+            gas_price
+        ).await;
+
+        if is_nonce_explicit {
+            return sent_hash
+        }
 
-        self.wait_for_execution(&tx_hash).await
+        match sent_hash {
+            Ok(tx_hash) => {
+                self.set_cached_nonce(Some(nonce + 1));
+                Ok(tx_hash)
+            },
+            Err(error) => {
+                self.set_cached_nonce(None);
+                Err(error)
+            }
+        }
+    }
+
+    /// Sends all the calls and waits for their executions, see `BlockchainInteractor::sc_call_batch`.
+    async fn send_calls_and_wait(
+        &mut self,
+        calls: Vec<InteractorCall>
+    ) -> Vec<Result<TransactionOnNetwork, ExecutorError>> {
+        let (sender_address, mut nonce, is_nonce_explicit) = match self.take_sender_address_and_nonce().await {
+            Ok(sender_address_and_nonce) => sender_address_and_nonce,
+            Err(error) => return calls.iter().map(|_| Err(error.clone())).collect()
+        };
+
+        let gas_price = match self.get_gas_price() {
+            Ok(gas_price) => gas_price,
+            Err(error) => return calls.iter().map(|_| Err(error.clone())).collect()
+        };
+
+        // The balance is fetched once, then decreased by each call, so the calls which cannot be covered by what remains are not sent.
+        let mut available_balance = if self.balance_precheck {
+            match self.get_egld_balance(&self.get_sender()).await {
+                Ok(balance) => Some(balance),
+                Err(error) => return calls.iter().map(|_| Err(error.clone())).collect()
+            }
+        } else {
+            None
+        };
+
+        let mut sent_hashes: Vec<Result<String, ExecutorError>> = Vec::with_capacity(calls.len());
+        let mut signed_transactions = Vec::with_capacity(calls.len());
+        let mut signed_indexes = Vec::with_capacity(calls.len());
+
+        for (index, call) in calls.into_iter().enumerate() {
+            let signed_transaction = self.sign_call(nonce, sender_address.clone(), call, gas_price)
+                .await
+                .and_then(|signed_transaction| {
+                    if let Some(available_balance) = available_balance.as_mut() {
+                        *available_balance = get_remaining_balance(&signed_transaction, available_balance)?;
+                    }
+
+                    Ok(signed_transaction)
+                });
+
+            match signed_transaction {
+                Ok(signed_transaction) => {
+                    nonce += 1;
+                    signed_transactions.push(signed_transaction);
+                    signed_indexes.push(index);
+                    // Replaced by the hash once the signed transactions are broadcast.
+                    sent_hashes.push(Err(TransactionError::ErrorWhileSendingTheTransaction.into()));
+                },
+                Err(error) => sent_hashes.push(Err(error))
+            }
+        }
+
+        if !signed_transactions.is_empty() {
+            match send_multiple_transactions(&self.get_gateway_client(), &signed_transactions).await {
+                Ok(hashes) => {
+                    let mut is_any_rejected = false;
+
+                    for (index, hash) in signed_indexes.into_iter().zip(hashes) {
+                        sent_hashes[index] = match hash {
+                            Some(tx_hash) => {
+                                if let Some(on_transaction_sent) = self.on_transaction_sent.as_ref() {
+                                    (on_transaction_sent.0)(&tx_hash);
+                                }
+
+                                Ok(tx_hash)
+                            },
+                            None => {
+                                is_any_rejected = true;
+                                Err(TransactionError::TransactionRejectedInBatch { index }.into())
+                            }
+                        };
+                    }
+
+                    if !is_nonce_explicit {
+                        self.set_cached_nonce((!is_any_rejected).then_some(nonce));
+                    }
+                },
+                Err(error) => {
+                    for index in signed_indexes {
+                        sent_hashes[index] = Err(error.clone());
+                    }
+
+                    if !is_nonce_explicit {
+                        self.set_cached_nonce(None);
+                    }
+                }
+            }
+        } else if !is_nonce_explicit {
+            self.set_cached_nonce(Some(nonce));
+        }
+
+        let interactor = &*self;
+        let executions = sent_hashes
+            .iter()
+            .map(|sent_hash| async move {
+                match sent_hash {
+                    Ok(tx_hash) => interactor.wait_for_execution(tx_hash).await,
+                    Err(error) => Err(error.clone())
+                }
+            });
+
+        join_all(executions).await
+    }
+}
+
+#[async_trait]
+impl<S: Signer> BlockchainInteractor for Interactor<S> {
+    type Signer = S;
+
+    async fn new(
+        gateway_url: String,
+        signer: S
+    ) -> Result<Self, ExecutorError> {
+        Self::new_with_client(
+            gateway_url,
+            signer,
+            get_shared_client().clone()
+        ).await
+    }
+
+    async fn sc_call(
+        &mut self,
+        to: String,
+        value: BigUint,
+        data: String,
+        gas_limit: u64
+    ) -> Result<TransactionOnNetwork, ExecutorError> {
+        self.send_call_and_wait(to, value, data, gas_limit).await
+    }
+
+    /// Broadcasts the call and returns its hash as soon as the gateway accepts it, without polling for its execution.
+    ///
+    /// The cached nonce, if enabled, is incremented, so following calls can be sent right away.
+    async fn sc_call_no_wait(&mut self, call: InteractorCall) -> Result<String, ExecutorError> {
+        self.send_call_without_waiting(call).await
+    }
+
+    /// Signs all the calls using nonces incremented locally from the account's current nonce, then broadcasts them in a single
+    /// request through the gateway's `/transaction/send-multiple` endpoint.
+    ///
+    /// Once all the calls are broadcast, their executions are awaited concurrently.
+    /// A call that fails to be signed, for example because its gas cannot be estimated, doesn't consume a nonce, so the following calls are still sent.
+    /// A call rejected by the gateway leads to a `TransactionError::TransactionRejectedInBatch` error, and the cached nonce is emptied
+    /// since the following calls may then wait for the missing nonce.
+    async fn sc_call_batch(
+        &mut self,
+        calls: Vec<InteractorCall>
+    ) -> Vec<Result<TransactionOnNetwork, ExecutorError>> {
+        self.send_calls_and_wait(calls).await
+    }
+
+    /// Builds and signs a call with the account's current nonce, without broadcasting it.
+    ///
+    /// The cached nonce, if any, is used but not incremented, since nothing is sent.
+    async fn build_transaction(&self, call: InteractorCall) -> Result<SignedTransaction, ExecutorError> {
+        let (sender_address, nonce) = self.get_sender_address_and_nonce().await?;
+
+        self.sign_call(
+            nonce,
+            sender_address,
+            call,
+            self.get_gas_price()?
+        ).await
+    }
+
+    /// Estimates the gas limit of a call through the gateway's `/transaction/cost` endpoint, applying the gas estimation multiplier.
+    ///
+    /// The call's own gas limit is ignored. For relayed transactions, the relayer's move balance cost is included.
+    async fn estimate_gas(&self, call: InteractorCall) -> Result<u64, ExecutorError> {
+        let (sender_address, nonce) = self.get_sender_address_and_nonce().await?;
+
+        let call = InteractorCall {
+            gas_limit: 0,
+            ..call
+        };
+
+        self.get_gas_limit(
+            nonce,
+            &sender_address,
+            &call
+        ).await
+    }
+
+    /// Returns the gas parameters of the network, including the extra gas of guarded transactions if a guardian is set.
+    fn get_transfer_gas_config(&self) -> TransferGasConfig {
+        TransferGasConfig::from_network_config(&self.network_config.config, self.guardian.is_some())
+    }
+
+    /// Sets the label reported with each gateway request of the call about to be made.
+    fn set_call_label(&mut self, label: Option<String>) {
+        self.call_label = label;
     }
 
     fn get_sender_address(&self) -> Address {
-        self.wallet.get_address()
+        self.get_sender()
+    }
+}
+
+/// Verifies the sender's signature of a freshly signed transaction, catching a signer using a wrong key or serialization before the network rejects the transaction.
+///
+/// Returns `SignerError::SignatureMismatch` if the signature doesn't validate against the sender's public key.
+fn check_signature(transaction: &TransactionSendRequest) -> Result<(), ExecutorError> {
+    if !transaction.verify_signature()? {
+        return Err(SignerError::SignatureMismatch { sender: transaction.sender.clone() }.into())
+    }
+
+    Ok(())
+}
+
+/// Returns the balance left once the transaction's value and maximum fee, its gas limit times its gas price, are paid from `available_balance`,
+/// or `TransactionError::InsufficientBalance` if the balance cannot cover them.
+///
+/// The fee of a relayed transaction is not counted, since it is paid by the relayer.
+fn get_remaining_balance(transaction: &TransactionSendRequest, available_balance: &BigUint) -> Result<BigUint, ExecutorError> {
+    let value = transaction.value.parse::<BigUint>().unwrap_or_default();
+    let fee = if transaction.relayer.is_some() {
+        BigUint::ZERO
+    } else {
+        BigUint::from(transaction.gas_limit) * transaction.gas_price
+    };
+
+    let required = value + fee;
+
+    if required > *available_balance {
+        return Err(TransactionError::InsufficientBalance {
+            required: required.to_string(),
+            available: available_balance.to_string()
+        }.into())
+    }
+
+    Ok(available_balance - required)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use futures::StreamExt;
+    use num_bigint::BigUint;
+    use novax_data::Address;
+
+    use crate::error::gateway::GatewayError;
+    use crate::error::network::NetworkQueryError;
+    use crate::error::signer::SignerError;
+    use crate::error::transaction::TransactionError;
+    use crate::{ExecutorError, TransactionOnNetwork, TransactionOnNetworkTransactionSmartContractResult};
+    use crate::network::models::network::config::{NetworkGatewayConfig, NetworkGatewayConfigData};
+    use crate::network::transaction::interactor::{check_signature, get_remaining_balance, BlockchainInteractor, Interactor, InteractorCall, PendingTransactionPolicy, SharedSigner, TransactionRefreshStrategy};
+    use crate::network::transaction::models::send_request::TransactionSendRequest;
+    use crate::network::transaction::status::{get_status_stream, TransactionStatus};
+    use crate::network::transaction::subscriber::{SharedTransactionEventsSubscriber, TransactionEventsSubscriber};
+    use crate::network::utils::retry::RetryPolicy;
+    use crate::network::utils::wallet::Wallet;
+    use crate::utils::transaction::egld_transfer::TransferGasConfig;
+
+    pub(crate) fn get_interactor(gas_price_override: Option<u64>) -> Interactor {
+        Interactor {
+            gateway_url: "".to_string(),
+            http_client: reqwest::Client::new(),
+            signer: Wallet::from_private_key("69417ce717e43d0d3a598f68b5e562d7d2a532a5a3ac1e8b3342515e0b2d950f").unwrap(),
+            network_config: NetworkGatewayConfig {
+                config: NetworkGatewayConfigData {
+                    erd_chain_id: "D".to_string(),
+                    erd_min_gas_price: 1000000000,
+                    erd_min_gas_limit: 50000,
+                    erd_gas_per_data_byte: 1500,
+                    erd_min_transaction_version: 1,
+                    erd_num_shards_without_meta: 3,
+                    erd_extra_gas_limit_guarded_tx: 50000,
+                },
+            },
+            refresh_strategy: TransactionRefreshStrategy::EachBlock,
+            timeout: Duration::from_secs(10),
+            gas_estimation_multiplier: 1.1,
+            retry_policy: RetryPolicy::default(),
+            on_transaction_sent: None,
+            on_gateway_request: None,
+            request_id: None,
+            call_label: None,
+            cancellation_flag: None,
+            events_subscriber: None,
+            process_status_check: false,
+            pending_transaction_policy: PendingTransactionPolicy::Wait,
+            nonce_cache_enabled: false,
+            cached_nonce: None,
+            next_nonce: None,
+            sender_override: None,
+            chain_id_override: None,
+            gas_price_override,
+            version_override: None,
+            options_override: None,
+            hash_signing: false,
+            balance_precheck: false,
+            guardian: None,
+            relayer: None,
+            relayer_signer: None,
+        }
+    }
+
+    #[test]
+    fn test_get_gas_price_default() {
+        let interactor = get_interactor(None);
+
+        assert_eq!(interactor.get_gas_price(), Ok(1000000000));
+    }
+
+    #[test]
+    fn test_get_gas_price_overridden() {
+        let interactor = get_interactor(Some(2000000000));
+
+        assert_eq!(interactor.get_gas_price(), Ok(2000000000));
+    }
+
+    #[test]
+    fn test_get_gas_price_below_network_minimum() {
+        let interactor = get_interactor(Some(999999999));
+
+        let expected: Result<u64, ExecutorError> = Err(TransactionError::GasPriceBelowNetworkMinimum {
+            gas_price: 999999999,
+            min_gas_price: 1000000000,
+        }.into());
+
+        assert_eq!(interactor.get_gas_price(), expected);
+    }
+
+    fn get_polled_transaction(status: &str, smart_contract_results: Option<usize>) -> TransactionOnNetwork {
+        let mut tx_on_network = TransactionOnNetwork::default();
+        tx_on_network.transaction.status = status.to_string();
+        tx_on_network.transaction.smart_contract_results = smart_contract_results
+            .map(|count| vec![TransactionOnNetworkTransactionSmartContractResult::default(); count]);
+
+        tx_on_network
+    }
+
+    async fn poll_scripted(interactor: &Interactor<Wallet>, polls: Vec<TransactionOnNetwork>) -> (Result<TransactionOnNetwork, ExecutorError>, usize) {
+        let (result, poll_count, _) = poll_scripted_with_process_status(interactor, polls, vec!["success"]).await;
+
+        (result, poll_count)
+    }
+
+    async fn poll_scripted_with_process_status(
+        interactor: &Interactor<Wallet>,
+        polls: Vec<TransactionOnNetwork>,
+        process_statuses: Vec<&str>
+    ) -> (Result<TransactionOnNetwork, ExecutorError>, usize, usize) {
+        let poll_count = AtomicUsize::new(0);
+        let process_status_count = AtomicUsize::new(0);
+
+        let result = interactor.poll_until_executed(
+            "",
+            Duration::ZERO,
+            || {
+                let index = poll_count.fetch_add(1, Ordering::SeqCst).min(polls.len() - 1);
+                let tx_on_network = polls[index].clone();
+                async move { Ok(tx_on_network) }
+            },
+            || {
+                let index = process_status_count.fetch_add(1, Ordering::SeqCst).min(process_statuses.len() - 1);
+                let process_status = process_statuses[index].to_string();
+                async move { Ok(process_status) }
+            },
+            &|_| {}
+        ).await;
+
+        (result, poll_count.load(Ordering::SeqCst), process_status_count.load(Ordering::SeqCst))
+    }
+
+    /// An events subscriber notifying each transaction after `delay`, or never if it is `None`, and failing with `error` if it is set.
+    struct ScriptedEventsSubscriber {
+        delay: Option<Duration>,
+        error: Option<ExecutorError>
+    }
+
+    #[async_trait]
+    impl TransactionEventsSubscriber for ScriptedEventsSubscriber {
+        async fn wait_for_transaction(&self, _tx_hash: &str) -> Result<(), ExecutorError> {
+            let Some(delay) = self.delay else {
+                return std::future::pending().await
+            };
+
+            tokio::time::sleep(delay).await;
+
+            match self.error.clone() {
+                Some(error) => Err(error),
+                None => Ok(())
+            }
+        }
+    }
+
+    fn get_events_subscriber(delay: Option<Duration>, error: Option<ExecutorError>) -> SharedTransactionEventsSubscriber {
+        SharedTransactionEventsSubscriber(Arc::new(ScriptedEventsSubscriber { delay, error }))
+    }
+
+    /// Waits for a transaction as `wait_for_transaction`, including the events subscriber, polling `polls` in order.
+    async fn wait_scripted(interactor: &Interactor<Wallet>, polls: Vec<TransactionOnNetwork>) -> (Result<TransactionOnNetwork, ExecutorError>, usize) {
+        let poll_count = AtomicUsize::new(0);
+
+        let result = interactor.wait_until_executed(
+            "",
+            || {
+                let index = poll_count.fetch_add(1, Ordering::SeqCst).min(polls.len() - 1);
+                let tx_on_network = polls[index].clone();
+                async move { Ok(tx_on_network) }
+            },
+            || async { Ok("success".to_string()) },
+            &|_| {}
+        ).await;
+
+        (result, poll_count.load(Ordering::SeqCst))
+    }
+
+    /// Returns the statuses yielded while polling `polls` in order, the completion being replaced by the status of the executed transaction.
+    async fn watch_scripted(interactor: &Interactor<Wallet>, polls: Vec<TransactionOnNetwork>) -> Vec<String> {
+        let poll_count = AtomicUsize::new(0);
+
+        let stream = get_status_stream(|report| async move {
+            interactor.poll_until_executed(
+                "",
+                Duration::ZERO,
+                || {
+                    let index = poll_count.fetch_add(1, Ordering::SeqCst).min(polls.len() - 1);
+                    let tx_on_network = polls[index].clone();
+                    async move { Ok(tx_on_network) }
+                },
+                || async { Ok("success".to_string()) },
+                &*report
+            ).await
+        });
+
+        stream
+            .map(|status| match status {
+                TransactionStatus::Sent => "sent".to_string(),
+                TransactionStatus::Pending => "pending".to_string(),
+                TransactionStatus::CrossShard => "cross-shard".to_string(),
+                TransactionStatus::Completed(result) => result.map(|tx_on_network| tx_on_network.transaction.status).unwrap_or_else(|error| format!("{error:?}")),
+            })
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_wait_cancelled_while_polling() {
+        let mut interactor = get_interactor(None);
+        interactor.refresh_strategy = TransactionRefreshStrategy::EachDuration(Duration::from_millis(1));
+
+        let cancellation_flag = Arc::new(AtomicBool::new(false));
+        interactor.cancellation_flag = Some(cancellation_flag.clone());
+
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            cancellation_flag.store(true, Ordering::SeqCst);
+        });
+
+        let (result, poll_count) = poll_scripted(&interactor, vec![get_polled_transaction("pending", None)]).await;
+
+        let expected: Result<String, ExecutorError> = Err(TransactionError::WaitingForTransactionCancelled { tx_hash: "".to_string() }.into());
+
+        assert_eq!(result.map(|tx_on_network| tx_on_network.transaction.status), expected);
+        assert!(poll_count > 1);
+    }
+
+    #[tokio::test]
+    async fn test_wait_already_executed_transaction_with_events_subscriber() {
+        let mut interactor = get_interactor(None);
+        interactor.events_subscriber = Some(get_events_subscriber(None, None));
+
+        let (result, poll_count) = wait_scripted(&interactor, vec![get_polled_transaction("success", None)]).await;
+
+        assert_eq!(result.map(|tx_on_network| tx_on_network.transaction.status), Ok("success".to_string()));
+        assert_eq!(poll_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_wait_polls_right_after_the_events_subscriber_notification() {
+        let mut interactor = get_interactor(None);
+        interactor.refresh_strategy = TransactionRefreshStrategy::EachDuration(Duration::from_secs(3600));
+        interactor.events_subscriber = Some(get_events_subscriber(Some(Duration::from_millis(10)), None));
+
+        let polls = vec![
+            get_polled_transaction("pending", None),
+            get_polled_transaction("success", None),
+        ];
+
+        let (result, poll_count) = tokio::time::timeout(Duration::from_secs(5), wait_scripted(&interactor, polls))
+            .await
+            .unwrap();
+
+        assert_eq!(result.map(|tx_on_network| tx_on_network.transaction.status), Ok("success".to_string()));
+        assert_eq!(poll_count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_wait_times_out_without_events_subscriber_notification() {
+        let mut interactor = get_interactor(None);
+        interactor.timeout = Duration::ZERO;
+        interactor.events_subscriber = Some(get_events_subscriber(None, None));
+
+        let (result, poll_count) = wait_scripted(&interactor, vec![get_polled_transaction("pending", None)]).await;
+
+        let expected: Result<String, ExecutorError> = Err(TransactionError::TimeoutWhenRetrievingTransactionOnNetwork { tx_hash: "".to_string() }.into());
+
+        assert_eq!(result.map(|tx_on_network| tx_on_network.transaction.status), expected);
+        assert_eq!(poll_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_wait_falls_back_to_polling_when_the_events_subscriber_fails() {
+        let mut interactor = get_interactor(None);
+        interactor.refresh_strategy = TransactionRefreshStrategy::EachDuration(Duration::from_millis(1));
+        interactor.events_subscriber = Some(get_events_subscriber(
+            Some(Duration::ZERO),
+            Some(NetworkQueryError::ErrorWhileSendingRequest { message: "socket closed".to_string() }.into())
+        ));
+
+        let polls = vec![
+            get_polled_transaction("pending", None),
+            get_polled_transaction("pending", None),
+            get_polled_transaction("success", None),
+        ];
+
+        let (result, poll_count) = wait_scripted(&interactor, polls).await;
+
+        assert_eq!(result.map(|tx_on_network| tx_on_network.transaction.status), Ok("success".to_string()));
+        assert_eq!(poll_count, 3);
+    }
+
+    #[tokio::test]
+    async fn test_wait_cancelled_with_events_subscriber() {
+        let mut interactor = get_interactor(None);
+        interactor.refresh_strategy = TransactionRefreshStrategy::EachDuration(Duration::from_millis(1));
+        interactor.events_subscriber = Some(get_events_subscriber(None, None));
+        interactor.cancellation_flag = Some(Arc::new(AtomicBool::new(true)));
+
+        let (result, poll_count) = wait_scripted(&interactor, vec![get_polled_transaction("pending", None)]).await;
+
+        let expected: Result<String, ExecutorError> = Err(TransactionError::WaitingForTransactionCancelled { tx_hash: "".to_string() }.into());
+
+        assert_eq!(result.map(|tx_on_network| tx_on_network.transaction.status), expected);
+        assert_eq!(poll_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_watch_transaction_statuses() {
+        let mut interactor = get_interactor(None);
+        interactor.refresh_strategy = TransactionRefreshStrategy::EachDuration(Duration::from_millis(1));
+
+        let polls = vec![
+            get_polled_transaction("pending", None),
+            get_polled_transaction("pending", None),
+            get_polled_transaction("success", Some(0)),
+            get_polled_transaction("success", Some(0)),
+            get_polled_transaction("success", Some(2)),
+        ];
+
+        let statuses = watch_scripted(&interactor, polls).await;
+
+        assert_eq!(statuses, vec!["sent", "pending", "cross-shard", "success"]);
+    }
+
+    #[tokio::test]
+    async fn test_watch_transaction_executed_at_first_poll() {
+        let mut interactor = get_interactor(None);
+        interactor.refresh_strategy = TransactionRefreshStrategy::EachDuration(Duration::from_millis(1));
+
+        let statuses = watch_scripted(&interactor, vec![get_polled_transaction("fail", None)]).await;
+
+        assert_eq!(statuses, vec!["sent", "fail"]);
+    }
+
+    #[tokio::test]
+    async fn test_poll_until_executed_waits_for_delayed_smart_contract_results() {
+        let mut interactor = get_interactor(None);
+        interactor.refresh_strategy = TransactionRefreshStrategy::EachDuration(Duration::from_millis(1));
+
+        let polls = vec![
+            get_polled_transaction("pending", None),
+            get_polled_transaction("success", Some(0)),
+            get_polled_transaction("success", Some(0)),
+            get_polled_transaction("success", Some(2)),
+        ];
+
+        let (result, poll_count) = poll_scripted(&interactor, polls).await;
+
+        assert_eq!(poll_count, 4);
+        assert_eq!(result.unwrap().transaction.smart_contract_results.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_poll_until_executed_without_smart_contract_results() {
+        let mut interactor = get_interactor(None);
+        interactor.refresh_strategy = TransactionRefreshStrategy::EachDuration(Duration::from_millis(1));
+
+        let polls = vec![
+            get_polled_transaction("success", None),
+        ];
+
+        let (result, poll_count) = poll_scripted(&interactor, polls).await;
+
+        assert_eq!(poll_count, 1);
+        assert!(result.unwrap().transaction.smart_contract_results.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_poll_until_executed_returns_empty_smart_contract_results_at_deadline() {
+        let mut interactor = get_interactor(None);
+        interactor.timeout = Duration::ZERO;
+
+        let polls = vec![
+            get_polled_transaction("success", Some(0)),
+        ];
+
+        let (result, poll_count) = poll_scripted(&interactor, polls).await;
+
+        assert_eq!(poll_count, 1);
+        assert!(result.unwrap().transaction.smart_contract_results.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_poll_until_executed_pending_at_deadline() {
+        let mut interactor = get_interactor(None);
+        interactor.timeout = Duration::ZERO;
+
+        let polls = vec![
+            get_polled_transaction("pending", None),
+        ];
+
+        let (result, _) = poll_scripted(&interactor, polls).await;
+
+        let expected: ExecutorError = TransactionError::TimeoutWhenRetrievingTransactionOnNetwork { tx_hash: "".to_string() }.into();
+
+        assert_eq!(result.err(), Some(expected));
+    }
+
+    #[tokio::test]
+    async fn test_poll_until_executed_waits_for_completed_process_status() {
+        let mut interactor = get_interactor(None);
+        interactor.refresh_strategy = TransactionRefreshStrategy::EachDuration(Duration::from_millis(1));
+        interactor.process_status_check = true;
+
+        let polls = vec![
+            get_polled_transaction("success", Some(1)),
+            get_polled_transaction("success", Some(1)),
+            get_polled_transaction("success", Some(2)),
+        ];
+
+        let (result, poll_count, process_status_count) = poll_scripted_with_process_status(&interactor, polls, vec!["pending", "pending", "success"]).await;
+
+        assert_eq!(poll_count, 3);
+        assert_eq!(process_status_count, 3);
+        assert_eq!(result.unwrap().transaction.smart_contract_results.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_poll_until_executed_process_status_not_fetched_by_default() {
+        let mut interactor = get_interactor(None);
+        interactor.refresh_strategy = TransactionRefreshStrategy::EachDuration(Duration::from_millis(1));
+
+        let polls = vec![
+            get_polled_transaction("success", Some(1)),
+        ];
+
+        let (result, poll_count, process_status_count) = poll_scripted_with_process_status(&interactor, polls, vec!["pending"]).await;
+
+        assert!(result.is_ok());
+        assert_eq!(poll_count, 1);
+        assert_eq!(process_status_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_poll_until_executed_pending_process_status_at_deadline() {
+        let mut interactor = get_interactor(None);
+        interactor.timeout = Duration::ZERO;
+        interactor.process_status_check = true;
+
+        let polls = vec![
+            get_polled_transaction("success", Some(1)),
+        ];
+
+        let (result, poll_count, process_status_count) = poll_scripted_with_process_status(&interactor, polls, vec!["pending"]).await;
+
+        assert_eq!(result.unwrap().transaction.smart_contract_results.unwrap().len(), 1);
+        assert_eq!(poll_count, 1);
+        assert_eq!(process_status_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_poll_until_executed_stuck_pending() {
+        let mut interactor = get_interactor(None);
+        interactor.pending_transaction_policy = PendingTransactionPolicy::Fail { after: Duration::ZERO };
+
+        let polls = vec![
+            get_polled_transaction("pending", None),
+        ];
+
+        let (result, poll_count) = poll_scripted(&interactor, polls).await;
+
+        let expected: ExecutorError = TransactionError::StuckPending { tx_hash: "".to_string() }.into();
+
+        assert_eq!(poll_count, 1);
+        assert_eq!(result.err(), Some(expected));
+    }
+
+    #[tokio::test]
+    async fn test_poll_until_executed_stuck_policy_ignores_other_statuses() {
+        let mut interactor = get_interactor(None);
+        interactor.refresh_strategy = TransactionRefreshStrategy::EachDuration(Duration::from_millis(1));
+        interactor.pending_transaction_policy = PendingTransactionPolicy::BumpGasPrice { after: Duration::ZERO, multiplier: 1.5, max_attempts: 1 };
+
+        let polls = vec![
+            get_polled_transaction("received", None),
+            get_polled_transaction("success", None),
+        ];
+
+        let (result, poll_count) = poll_scripted(&interactor, polls).await;
+
+        assert_eq!(poll_count, 2);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_pending_transaction_policy_validate() {
+        let get_policy = |multiplier| PendingTransactionPolicy::BumpGasPrice { after: Duration::ZERO, multiplier, max_attempts: 1 };
+
+        assert_eq!(PendingTransactionPolicy::Wait.validate(), Ok(()));
+        assert_eq!(get_policy(1.5).validate(), Ok(()));
+
+        for multiplier in [1.0, 0.5, -2.0, f64::INFINITY] {
+            let expected: Result<(), ExecutorError> = Err(TransactionError::InvalidGasPriceMultiplier { multiplier }.into());
+            assert_eq!(get_policy(multiplier).validate(), expected);
+        }
+
+        assert!(get_policy(f64::NAN).validate().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_wait_replacing_stuck_resends_with_same_nonce_and_higher_gas_price() {
+        let mut interactor = get_interactor(None);
+        interactor.pending_transaction_policy = PendingTransactionPolicy::BumpGasPrice { after: Duration::ZERO, multiplier: 1.5, max_attempts: 2 };
+
+        let call = InteractorCall {
+            to: "erd1qqqqqqqqqqqqqpgqr7een4m5z44frr3k35yjdjcrfe6703cwdl3s3wkddz".to_string(),
+            value: BigUint::from(0u8),
+            data: "claim".to_string(),
+            gas_limit: 10_000_000
+        };
+
+        let sender_address = interactor.get_sender().to_bech32_string().unwrap();
+        let broadcast_requests: Mutex<Vec<TransactionSendRequest>> = Mutex::new(vec![]);
+
+        let result = interactor.wait_replacing_stuck(
+            "hash0".to_string(),
+            5,
+            sender_address,
+            call,
+            1000000000,
+            |tx_hash| async move {
+                match tx_hash.as_str() {
+                    "hash2" => Ok(get_polled_transaction("success", None)),
+                    _ => Err(TransactionError::StuckPending { tx_hash }.into())
+                }
+            },
+            |transaction_request| {
+                let mut broadcast_requests = broadcast_requests.lock().unwrap();
+                broadcast_requests.push(transaction_request);
+                let tx_hash = format!("hash{}", broadcast_requests.len());
+
+                async move { Ok(tx_hash) }
+            }
+        ).await;
+
+        let broadcast_requests = broadcast_requests.into_inner().unwrap();
+        let nonces_and_gas_prices: Vec<(u64, u64)> = broadcast_requests
+            .iter()
+            .map(|transaction_request| (transaction_request.nonce, transaction_request.gas_price))
+            .collect();
+
+        assert_eq!(result.map(|tx_on_network| tx_on_network.transaction.status), Ok("success".to_string()));
+        assert_eq!(nonces_and_gas_prices, vec![(5, 1500000000), (5, 2250000000)]);
+    }
+
+    #[tokio::test]
+    async fn test_wait_stuck_pending_with_events_subscriber() {
+        let mut interactor = get_interactor(None);
+        interactor.refresh_strategy = TransactionRefreshStrategy::EachDuration(Duration::from_millis(1));
+        interactor.pending_transaction_policy = PendingTransactionPolicy::Fail { after: Duration::ZERO };
+        interactor.events_subscriber = Some(get_events_subscriber(Some(Duration::ZERO), None));
+
+        let (result, _) = wait_scripted(&interactor, vec![get_polled_transaction("pending", None)]).await;
+
+        let expected: Result<String, ExecutorError> = Err(TransactionError::StuckPending { tx_hash: "".to_string() }.into());
+
+        assert_eq!(result.map(|tx_on_network| tx_on_network.transaction.status), expected);
+    }
+
+    #[tokio::test]
+    async fn test_poll_until_executed_pending_waits_by_default() {
+        let mut interactor = get_interactor(None);
+        interactor.timeout = Duration::ZERO;
+
+        let polls = vec![
+            get_polled_transaction("pending", None),
+        ];
+
+        let (result, _) = poll_scripted(&interactor, polls).await;
+
+        let expected: ExecutorError = TransactionError::TimeoutWhenRetrievingTransactionOnNetwork { tx_hash: "".to_string() }.into();
+
+        assert_eq!(result.err(), Some(expected));
+    }
+
+    fn get_transaction_request(value: &str, relayer: Option<String>) -> TransactionSendRequest {
+        TransactionSendRequest {
+            nonce: 0,
+            value: value.to_string(),
+            receiver: "".to_string(),
+            sender: "".to_string(),
+            gas_price: 1000000000,
+            gas_limit: 50000,
+            data: None,
+            signature: "".to_string(),
+            chain_id: "D".to_string(),
+            version: 1,
+            options: 0,
+            guardian: None,
+            guardian_signature: None,
+            relayer,
+            relayer_signature: None,
+        }
+    }
+
+    #[test]
+    fn test_get_remaining_balance() {
+        let transaction = get_transaction_request("1000000000000000", None);
+
+        let result = get_remaining_balance(&transaction, &BigUint::from(2000000000000000u64));
+
+        assert_eq!(result, Ok(BigUint::from(950000000000000u64)));
+    }
+
+    #[test]
+    fn test_get_remaining_balance_exact() {
+        let transaction = get_transaction_request("1000000000000000", None);
+
+        let result = get_remaining_balance(&transaction, &BigUint::from(1050000000000000u64));
+
+        assert_eq!(result, Ok(BigUint::ZERO));
+    }
+
+    #[test]
+    fn test_get_remaining_balance_insufficient() {
+        let transaction = get_transaction_request("1000000000000000", None);
+
+        let result = get_remaining_balance(&transaction, &BigUint::from(1000000000000000u64));
+
+        let expected: ExecutorError = TransactionError::InsufficientBalance {
+            required: "1050000000000000".to_string(),
+            available: "1000000000000000".to_string()
+        }.into();
+
+        assert_eq!(result, Err(expected));
+    }
+
+    #[test]
+    fn test_get_remaining_balance_relayed_fee_not_counted() {
+        let transaction = get_transaction_request("1000000000000000", Some("relayer".to_string()));
+
+        let result = get_remaining_balance(&transaction, &BigUint::from(1000000000000000u64));
+
+        assert_eq!(result, Ok(BigUint::ZERO));
+    }
+
+    #[tokio::test]
+    async fn test_take_sender_address_and_nonce_explicit() {
+        let mut interactor = get_interactor(None);
+        interactor.next_nonce = Some(5);
+
+        let (_, nonce, is_nonce_explicit) = interactor.take_sender_address_and_nonce().await.unwrap();
+
+        assert_eq!(nonce, 5);
+        assert!(is_nonce_explicit);
+        assert_eq!(interactor.next_nonce, None);
+    }
+
+    #[tokio::test]
+    async fn test_build_signed_transaction_with_sender_override() {
+        let sender = Address::from_bech32_string("erd1qqqqqqqqqqqqqpgqtqfhy99su9xzjjrq59kpzpp25udtc9eq0n4sr90ax6").unwrap();
+        let mut interactor = get_interactor(None);
+        interactor.sender_override = Some(sender.clone());
+        interactor.next_nonce = Some(7);
+
+        let call = InteractorCall {
+            to: sender.to_bech32_string().unwrap(),
+            value: BigUint::ZERO,
+            data: "proposeAction".to_string(),
+            gas_limit: 10_000_000,
+        };
+
+        let transaction = interactor.build_signed_transaction(call).await.unwrap();
+
+        assert_eq!(transaction.sender, sender.to_bech32_string().unwrap());
+        assert_eq!(transaction.nonce, 7);
+        assert_eq!(interactor.get_sender_address(), sender);
+    }
+
+    #[tokio::test]
+    async fn test_build_signed_transaction_relayed() {
+        let relayer = Wallet::from_private_key("1648ad209d6b157a289884933e3bb30f161ec7113221ec16f87c3578b05830b0").unwrap();
+        let mut interactor = get_interactor(None);
+        interactor.relayer = Some(relayer.get_address());
+        interactor.relayer_signer = Some(SharedSigner(Arc::new(relayer.clone())));
+        interactor.next_nonce = Some(7);
+
+        let call = InteractorCall {
+            to: "erd1qqqqqqqqqqqqqpgqtqfhy99su9xzjjrq59kpzpp25udtc9eq0n4sr90ax6".to_string(),
+            value: BigUint::ZERO,
+            data: "proposeAction".to_string(),
+            gas_limit: 10_000_000,
+        };
+
+        let transaction = interactor.build_signed_transaction(call).await.unwrap();
+
+        assert_eq!(transaction.relayer, Some(relayer.get_address().to_bech32_string().unwrap()));
+        assert_eq!(transaction.gas_limit, 10_050_000);
+
+        let relayer_signature = transaction.relayer_signature.clone().unwrap();
+        assert_eq!(relayer_signature.len(), 128);
+        assert_ne!(relayer_signature, transaction.signature);
+    }
+
+    #[test]
+    fn test_get_transfer_gas_config_from_network_config() {
+        let mut interactor = get_interactor(None);
+        interactor.network_config.config.erd_min_gas_limit = 70_000;
+        interactor.network_config.config.erd_gas_per_data_byte = 2_000;
+
+        let expected = TransferGasConfig {
+            min_gas_limit: 70_000,
+            gas_per_data_byte: 2_000,
+            extra_gas_limit: 0,
+        };
+
+        assert_eq!(interactor.get_transfer_gas_config(), expected);
+    }
+
+    #[test]
+    fn test_get_transfer_gas_config_guarded() {
+        let mut interactor = get_interactor(None);
+        interactor.guardian = Some(SharedSigner(Arc::new(interactor.signer.clone())));
+
+        assert_eq!(interactor.get_transfer_gas_config().extra_gas_limit, 50_000);
+    }
+
+    #[tokio::test]
+    async fn test_balance_precheck_with_sender_override() {
+        let sender = Address::from_bech32_string("erd1qqqqqqqqqqqqqpgqtqfhy99su9xzjjrq59kpzpp25udtc9eq0n4sr90ax6").unwrap();
+        let mut interactor = get_interactor(None);
+        interactor.sender_override = Some(sender.clone());
+        interactor.balance_precheck = true;
+        interactor.next_nonce = Some(7);
+
+        let call = InteractorCall {
+            to: sender.to_bech32_string().unwrap(),
+            value: BigUint::ZERO,
+            data: "proposeAction".to_string(),
+            gas_limit: 10_000_000,
+        };
+
+        // The gateway URL is empty, so fetching the balance fails with the address it was fetched for.
+        let expected: ExecutorError = GatewayError::CannotFetchAddressInfo { address: sender.to_bech32_string().unwrap() }.into();
+
+        let result = interactor.sc_call_no_wait(call.clone()).await;
+        assert_eq!(result, Err(expected.clone()));
+
+        interactor.next_nonce = Some(7);
+        let errors: Vec<Option<ExecutorError>> = interactor.sc_call_batch(vec![call])
+            .await
+            .into_iter()
+            .map(|result| result.err())
+            .collect();
+        assert_eq!(errors, vec![Some(expected)]);
+    }
+
+    #[tokio::test]
+    async fn test_check_signature() {
+        let mut interactor = get_interactor(None);
+        interactor.next_nonce = Some(7);
+
+        let call = InteractorCall {
+            to: "erd1qqqqqqqqqqqqqpgqtqfhy99su9xzjjrq59kpzpp25udtc9eq0n4sr90ax6".to_string(),
+            value: BigUint::ZERO,
+            data: "getSum".to_string(),
+            gas_limit: 10_000_000,
+        };
+
+        let mut transaction = interactor.build_signed_transaction(call).await.unwrap();
+
+        assert_eq!(check_signature(&transaction), Ok(()));
+
+        transaction.gas_limit = 20_000_000;
+
+        let expected: ExecutorError = SignerError::SignatureMismatch { sender: transaction.sender.clone() }.into();
+
+        assert_eq!(check_signature(&transaction), Err(expected));
+    }
+
+    #[tokio::test]
+    async fn test_take_sender_address_and_nonce_explicit_before_cache() {
+        let mut interactor = get_interactor(None);
+        interactor.nonce_cache_enabled = true;
+        interactor.cached_nonce = Some(3);
+        interactor.next_nonce = Some(5);
+
+        let (_, nonce, _) = interactor.take_sender_address_and_nonce().await.unwrap();
+        let (_, next_nonce, is_next_nonce_explicit) = interactor.take_sender_address_and_nonce().await.unwrap();
+
+        assert_eq!(nonce, 5);
+        assert_eq!(next_nonce, 3);
+        assert!(!is_next_nonce_explicit);
+        assert_eq!(interactor.cached_nonce, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_many_returns_a_result_per_hash() {
+        // The gateway URL is empty, so every request fails.
+        let interactor = get_interactor(None);
+
+        let errors: Vec<Option<ExecutorError>> = interactor.wait_for_many(&["hash1".to_string(), "hash2".to_string()])
+            .await
+            .into_iter()
+            .map(|result| result.err())
+            .collect();
+
+        let expected: Vec<Option<ExecutorError>> = vec![
+            Some(TransactionError::ErrorWhileGettingTransactionOnNetwork { tx_hash: "hash1".to_string() }.into()),
+            Some(TransactionError::ErrorWhileGettingTransactionOnNetwork { tx_hash: "hash2".to_string() }.into())
+        ];
+
+        assert_eq!(errors, expected);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_many_with_mixed_results() {
+        let mut interactor = get_interactor(None);
+        interactor.refresh_strategy = TransactionRefreshStrategy::EachDuration(Duration::from_millis(1));
+
+        let first_hash_poll_count = AtomicUsize::new(0);
+        let tx_hashes = vec!["hash1".to_string(), "hash2".to_string(), "hash3".to_string()];
+
+        let statuses: Vec<Result<String, ExecutorError>> = interactor.wait_until_many_executed(
+            &tx_hashes,
+            |tx_hash| {
+                let result = match tx_hash {
+                    "hash1" if first_hash_poll_count.fetch_add(1, Ordering::SeqCst) == 0 => Ok(get_polled_transaction("pending", None)),
+                    "hash1" => Ok(get_polled_transaction("success", None)),
+                    "hash3" => Ok(get_polled_transaction("fail", None)),
+                    _ => Err(TransactionError::ErrorWhileGettingTransactionOnNetwork { tx_hash: tx_hash.to_string() }.into())
+                };
+
+                async move { result }
+            },
+            |_| async { Ok("success".to_string()) }
+        )
+            .await
+            .into_iter()
+            .map(|result| result.map(|tx_on_network| tx_on_network.transaction.status))
+            .collect();
+
+        let expected: Vec<Result<String, ExecutorError>> = vec![
+            Ok("success".to_string()),
+            Err(TransactionError::ErrorWhileGettingTransactionOnNetwork { tx_hash: "hash2".to_string() }.into()),
+            Ok("fail".to_string())
+        ];
+
+        assert_eq!(statuses, expected);
+        assert_eq!(first_hash_poll_count.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_many_without_hashes() {
+        let interactor = get_interactor(None);
+
+        assert!(interactor.wait_for_many(&[]).await.is_empty());
     }
 }
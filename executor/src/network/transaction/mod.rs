@@ -1,3 +1,13 @@
+#[cfg(feature = "network")]
+pub mod builder;
+#[cfg(feature = "network")]
 pub mod executor;
+#[cfg(feature = "network")]
 pub mod interactor;
-pub mod models;
\ No newline at end of file
+pub mod models;
+#[cfg(feature = "network")]
+pub mod relayed;
+#[cfg(feature = "network")]
+pub mod status;
+#[cfg(feature = "network")]
+pub mod subscriber;
\ No newline at end of file
@@ -1,3 +1,6 @@
 pub mod transaction_on_network;
 pub mod send_request;
-pub mod send_response;
\ No newline at end of file
+#[cfg(feature = "network")]
+pub mod send_response;
+#[cfg(feature = "network")]
+pub mod process_status;
\ No newline at end of file
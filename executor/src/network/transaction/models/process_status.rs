@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+/// The process status of a transaction whose smart contract results are not all executed yet, such as a cross-shard call.
+pub(crate) const PENDING_PROCESS_STATUS: &str = "pending";
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionProcessStatusResponse {
+    pub data: Option<TransactionProcessStatusResponseData>,
+    pub error: String
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionProcessStatusResponseData {
+    pub status: String
+}
@@ -1,6 +1,14 @@
-use serde::Serialize;
+use ed25519_dalek::{PublicKey, Signature, Verifier};
+use serde::{Deserialize, Serialize};
 
-#[derive(Serialize)]
+use novax_data::Address;
+
+use crate::error::signer::SignerError;
+use crate::ExecutorError;
+use crate::network::utils::wallet::SignableTransaction;
+
+/// A signed transaction, serialized as expected by the gateway's `/transaction/send` endpoint.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionSendRequest {
     pub nonce: u64,
@@ -9,17 +17,189 @@ pub struct TransactionSendRequest {
     pub sender: String,
     pub gas_price: u64,
     pub gas_limit: u64,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data: Option<String>,
     pub signature: String,
     #[serde(rename = "chainID")]
     pub chain_id: String,
     pub version: u32,
-    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default, skip_serializing_if = "is_zero")]
     pub options: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guardian: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guardian_signature: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relayer: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relayer_signature: Option<String>,
+}
+
+/// A fully signed transaction, ready to be broadcast through any channel.
+///
+/// Serializing it to JSON gives the body expected by the gateway's `/transaction/send` endpoint.
+pub type SignedTransaction = TransactionSendRequest;
+
+impl TransactionSendRequest {
+    /// Returns the exact bytes signed by the sender, as defined by the protocol: the canonical JSON serialization of the transaction
+    /// without its signatures, or its Keccak256 hash if the transaction is signed on its hash.
+    ///
+    /// The guardian and the relayer, if any, sign the same bytes. This allows verifying the signatures independently,
+    /// or submitting the transaction through a channel expecting the signed payload and the signature separately.
+    pub fn get_signed_bytes(&self) -> Vec<u8> {
+        let signable_transaction = SignableTransaction {
+            nonce: self.nonce,
+            value: self.value.clone(),
+            receiver: self.receiver.clone(),
+            sender: self.sender.clone(),
+            gas_price: self.gas_price,
+            gas_limit: self.gas_limit,
+            data: self.data.clone(),
+            chain_id: self.chain_id.clone(),
+            version: self.version,
+            options: self.options,
+            guardian: self.guardian.clone(),
+            relayer: self.relayer.clone(),
+        };
+
+        signable_transaction.get_bytes_to_sign()
+    }
+
+    /// Returns the raw bytes of the sender's ed25519 signature.
+    ///
+    /// Fails with `SignerError::InvalidSignature` if the signature is not valid hex.
+    pub fn get_signature_bytes(&self) -> Result<Vec<u8>, ExecutorError> {
+        hex::decode(&self.signature)
+            .map_err(|_| SignerError::InvalidSignature { signature: self.signature.clone() }.into())
+    }
+
+    /// Returns whether the sender's signature is valid for the bytes returned by `get_signed_bytes`.
+    ///
+    /// Fails if the sender is not a valid bech32 address or if the signature is not a well-formed ed25519 signature.
+    pub fn verify_signature(&self) -> Result<bool, ExecutorError> {
+        let invalid_signature = || -> ExecutorError {
+            SignerError::InvalidSignature { signature: self.signature.clone() }.into()
+        };
+
+        let sender = Address::from_bech32_string(&self.sender)?;
+        let public_key = PublicKey::from_bytes(&sender.to_bytes()).map_err(|_| invalid_signature())?;
+        let signature = Signature::from_bytes(&self.get_signature_bytes()?).map_err(|_| invalid_signature())?;
+
+        Ok(public_key.verify(&self.get_signed_bytes(), &signature).is_ok())
+    }
 }
 
 #[allow(clippy::trivially_copy_pass_by_ref)]
 fn is_zero(num: &u32) -> bool {
     *num == 0
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::signer::SignerError;
+    use crate::ExecutorError;
+    use crate::network::transaction::models::send_request::SignedTransaction;
+    use crate::network::utils::wallet::{SignableTransaction, TRANSACTION_OPTION_HASH_SIGN, TRANSACTION_VERSION_WITH_OPTIONS, Wallet};
+
+    #[test]
+    fn test_serialize_signed_transaction() {
+        let transaction = SignedTransaction {
+            nonce: 5,
+            value: "1000".to_string(),
+            receiver: "erd1qqqqqqqqqqqqqpgqr7een4m5z44frr3k35yjdjcrfe6703cwdl3s3wkddz".to_string(),
+            sender: "erd1h4uhy73dev6qrfj7wxsguapzs8632mfwqjswjpsj6kzm2jfrnslqsuduqu".to_string(),
+            gas_price: 1000000000,
+            gas_limit: 600000000,
+            data: Some("cGluZw==".to_string()),
+            signature: "abcd".to_string(),
+            chain_id: "D".to_string(),
+            version: 1,
+            options: 0,
+            guardian: None,
+            guardian_signature: None,
+            relayer: None,
+            relayer_signature: None,
+        };
+
+        let result = serde_json::to_string(&transaction).unwrap();
+        let expected = r#"{"nonce":5,"value":"1000","receiver":"erd1qqqqqqqqqqqqqpgqr7een4m5z44frr3k35yjdjcrfe6703cwdl3s3wkddz","sender":"erd1h4uhy73dev6qrfj7wxsguapzs8632mfwqjswjpsj6kzm2jfrnslqsuduqu","gasPrice":1000000000,"gasLimit":600000000,"data":"cGluZw==","signature":"abcd","chainID":"D","version":1}"#;
+
+        assert_eq!(result, expected);
+
+        let deserialized: SignedTransaction = serde_json::from_str(&result).unwrap();
+
+        assert_eq!(deserialized, transaction);
+    }
+
+    fn get_signable_transaction(wallet: &Wallet) -> SignableTransaction {
+        let sender = wallet.get_address().to_bech32_string().unwrap();
+
+        SignableTransaction {
+            nonce: 5,
+            value: "1000".to_string(),
+            receiver: "erd1qqqqqqqqqqqqqpgqr7een4m5z44frr3k35yjdjcrfe6703cwdl3s3wkddz".to_string(),
+            sender,
+            gas_price: 1000000000,
+            gas_limit: 600000000,
+            data: Some("cGluZw==".to_string()),
+            chain_id: "D".to_string(),
+            version: 1,
+            options: 0,
+            guardian: None,
+            relayer: None,
+        }
+    }
+
+    fn get_wallet() -> Wallet {
+        Wallet::from_private_key("69417ce717e43d0d3a598f68b5e562d7d2a532a5a3ac1e8b3342515e0b2d950f").unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_get_signed_bytes_and_verify_signature() {
+        let wallet = get_wallet();
+        let signable_transaction = get_signable_transaction(&wallet);
+        let expected_signed_bytes = signable_transaction.get_bytes_to_sign();
+
+        let transaction = signable_transaction.into_sendable_transaction(&wallet).await.unwrap();
+
+        assert_eq!(transaction.get_signed_bytes(), expected_signed_bytes);
+        assert_eq!(transaction.get_signature_bytes().unwrap().len(), 64);
+        assert_eq!(transaction.verify_signature(), Ok(true));
+    }
+
+    #[tokio::test]
+    async fn test_verify_signature_hash_signing() {
+        let wallet = get_wallet();
+        let mut signable_transaction = get_signable_transaction(&wallet);
+        signable_transaction.version = TRANSACTION_VERSION_WITH_OPTIONS;
+        signable_transaction.options = TRANSACTION_OPTION_HASH_SIGN;
+
+        let transaction = signable_transaction.into_sendable_transaction(&wallet).await.unwrap();
+
+        assert_eq!(transaction.get_signed_bytes().len(), 32);
+        assert_eq!(transaction.verify_signature(), Ok(true));
+    }
+
+    #[tokio::test]
+    async fn test_verify_signature_tampered_transaction() {
+        let wallet = get_wallet();
+
+        let mut transaction = get_signable_transaction(&wallet).into_sendable_transaction(&wallet).await.unwrap();
+        transaction.value = "1000000".to_string();
+
+        assert_eq!(transaction.verify_signature(), Ok(false));
+    }
+
+    #[tokio::test]
+    async fn test_get_signature_bytes_invalid_hex() {
+        let wallet = get_wallet();
+
+        let mut transaction = get_signable_transaction(&wallet).into_sendable_transaction(&wallet).await.unwrap();
+        transaction.signature = "not hex".to_string();
+
+        let expected: ExecutorError = SignerError::InvalidSignature { signature: "not hex".to_string() }.into();
+
+        assert_eq!(transaction.get_signature_bytes(), Err(expected.clone()));
+        assert_eq!(transaction.verify_signature(), Err(expected));
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -11,4 +13,19 @@ pub struct TransactionSendResponse {
 #[serde(rename_all = "camelCase")]
 pub struct TransactionSendResponseData {
     pub tx_hash: String
-}
\ No newline at end of file
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionSendMultipleResponse {
+    pub data: Option<TransactionSendMultipleResponseData>,
+    pub error: String
+}
+
+/// The hashes of the transactions accepted by `/transaction/send-multiple`, keyed by their position in the sent array.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionSendMultipleResponseData {
+    #[serde(default)]
+    pub txs_hashes: HashMap<usize, String>
+}
@@ -1,48 +1,68 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::utils::transaction::results::find_sc_error;
 
 pub(crate) const SUCCESS_TRANSACTION_STATUS: [&str; 2] = ["success", "successful"];
+#[cfg(feature = "network")]
 pub(crate) const FINAL_TRANSACTION_STATUS: [&str; 3] = ["success", "successful", "fail"];
+#[cfg(feature = "network")]
+pub(crate) const PENDING_TRANSACTION_STATUS: &str = "pending";
+#[cfg(feature = "network")]
+pub(crate) const FAILED_TRANSACTION_STATUS: &str = "fail";
 
-#[derive(Deserialize, Clone, Default)]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Default, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionOnNetworkResponse {
     pub data: Option<TransactionOnNetwork>,
     pub error: String
 }
 
-#[derive(Deserialize, Clone, Default)]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Default, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionOnNetwork {
     pub transaction: TransactionOnNetworkTransaction
 }
 
-#[derive(Deserialize, Clone, Default)]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Default, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionOnNetworkTransaction {
+    #[serde(default)]
+    pub hash: String,
+    #[serde(default)]
+    pub sender: String,
     pub gas_used: u64,
+    #[serde(default)]
+    pub fee: Option<String>,
     pub smart_contract_results: Option<Vec<TransactionOnNetworkTransactionSmartContractResult>>,
     pub status: String,
-    pub logs: Option<TransactionOnNetworkTransactionLogs>
+    pub logs: Option<TransactionOnNetworkTransactionLogs>,
+    /// The explanation of a failure given by the proxy at the transaction level, such as "out of gas", if any.
+    #[serde(default)]
+    pub return_message: Option<String>
 }
 
-#[derive(Deserialize, Clone, Default)]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Default, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionOnNetworkTransactionSmartContractResult {
     pub hash: String,
     pub nonce: u64,
     pub data: String,
+    #[serde(default)]
+    pub sender: String,
+    #[serde(default)]
+    pub receiver: String,
+    #[serde(default)]
+    pub prev_tx_hash: String,
 }
 
-#[derive(Deserialize, Clone, Default)]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Default, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionOnNetworkTransactionLogs {
     pub address: String,
     pub events: Vec<TransactionOnNetworkTransactionLogsEvents>
 }
 
-#[derive(Deserialize, Clone, Default)]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Default, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionOnNetworkTransactionLogsEvents {
     pub address: String,
@@ -63,11 +83,23 @@ impl TransactionOnNetwork {
 
         matches!(find_sc_error(logs), Ok(None))
     }
+
+    /// Returns whether the transaction succeeded with smart contract results present but empty.
+    ///
+    /// The gateway omits the results of a transaction that has none, an empty list means they are not available yet,
+    /// as for a cross-shard call whose destination shard hasn't executed it.
+    #[cfg(feature = "network")]
+    pub(crate) fn is_awaiting_smart_contract_results(&self) -> bool {
+        SUCCESS_TRANSACTION_STATUS.contains(&self.transaction.status.as_ref())
+            && self.transaction.smart_contract_results.as_ref().is_some_and(|results| results.is_empty())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::TransactionOnNetworkResponse;
+    #[cfg(feature = "network")]
+    use crate::{TransactionOnNetwork, TransactionOnNetworkTransactionSmartContractResult};
 
     #[test]
     fn test_deserialize_successful_swap() {
@@ -947,4 +979,52 @@ mod tests {
         let tx_on_network = serde_json::from_str::<TransactionOnNetworkResponse>(data).unwrap();
         assert!(!tx_on_network.data.unwrap().is_success())
     }
-}
\ No newline at end of file
+
+    #[cfg(feature = "network")]
+    #[test]
+    fn test_is_awaiting_smart_contract_results_empty_results() {
+        let mut tx_on_network = TransactionOnNetwork::default();
+        tx_on_network.transaction.status = "success".to_string();
+        tx_on_network.transaction.smart_contract_results = Some(vec![]);
+
+        assert!(tx_on_network.is_awaiting_smart_contract_results())
+    }
+
+    #[cfg(feature = "network")]
+    #[test]
+    fn test_is_awaiting_smart_contract_results_no_results() {
+        let mut tx_on_network = TransactionOnNetwork::default();
+        tx_on_network.transaction.status = "success".to_string();
+
+        assert!(!tx_on_network.is_awaiting_smart_contract_results())
+    }
+
+    #[cfg(feature = "network")]
+    #[test]
+    fn test_is_awaiting_smart_contract_results_with_results() {
+        let mut tx_on_network = TransactionOnNetwork::default();
+        tx_on_network.transaction.status = "success".to_string();
+        tx_on_network.transaction.smart_contract_results = Some(vec![TransactionOnNetworkTransactionSmartContractResult::default()]);
+
+        assert!(!tx_on_network.is_awaiting_smart_contract_results())
+    }
+
+    #[cfg(feature = "network")]
+    #[test]
+    fn test_is_awaiting_smart_contract_results_failed_transaction() {
+        let mut tx_on_network = TransactionOnNetwork::default();
+        tx_on_network.transaction.status = "fail".to_string();
+        tx_on_network.transaction.smart_contract_results = Some(vec![]);
+
+        assert!(!tx_on_network.is_awaiting_smart_contract_results())
+    }
+
+    #[test]
+    fn test_deserialize_return_message() {
+        let data = r#"{"data":{"transaction":{"hash":"abcdef","gasUsed":500000,"status":"fail","returnMessage":"out of gas"}},"error":""}"#;
+
+        let tx_on_network = serde_json::from_str::<TransactionOnNetworkResponse>(data).unwrap().data.unwrap();
+
+        assert_eq!(tx_on_network.transaction.return_message, Some("out of gas".to_string()));
+    }
+}
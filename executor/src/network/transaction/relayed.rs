@@ -0,0 +1,157 @@
+use async_trait::async_trait;
+use multiversx_sc::codec::TopDecodeMulti;
+use multiversx_sc::imports::CodeMetadata;
+use num_bigint::BigUint;
+
+use novax_data::{Address, NativeConvertible};
+
+use crate::base::deploy::DeployExecutor;
+use crate::base::transaction::TransactionExecutor;
+use crate::base::upgrade::UpgradeExecutor;
+use crate::call_result::CallResult;
+use crate::error::executor::ExecutorError;
+use crate::network::transaction::executor::BaseTransactionNetworkExecutor;
+use crate::network::transaction::interactor::Interactor;
+use crate::network::utils::signer::Signer;
+use crate::network::utils::wallet::Wallet;
+use crate::utils::transaction::egld_transfer::TransferGasConfig;
+use crate::utils::transaction::token_transfer::TokenTransfer;
+
+/// An executor sending relayed v3 transactions: the user signs the transaction, and the relayer co-signs it and pays its gas.
+///
+/// Each transaction is sent from the user's account, with the `relayer` and `relayerSignature` fields filled.
+/// The network's minimum gas limit is added to the gas limit of each call, to account for the relayer's move balance cost.
+/// Results are decoded as for any other call, the contract's reply being sent back to the user.
+#[derive(Clone, Debug)]
+pub struct RelayedExecutor<S: Signer = Wallet> {
+    executor: BaseTransactionNetworkExecutor<Interactor<S>>
+}
+
+impl<S: Signer> RelayedExecutor<S> {
+    /// Creates a new `RelayedExecutor`, signing the transactions with `signer` and relaying them with `relayer`.
+    pub async fn new<R: Signer + 'static>(gateway_url: String, signer: S, relayer: R) -> Result<Self, ExecutorError> {
+        let executor = BaseTransactionNetworkExecutor::new(gateway_url, signer).await?;
+
+        Ok(Self::from_executor(executor, relayer))
+    }
+
+    /// Creates a new `RelayedExecutor` from an existing executor, keeping its configuration.
+    pub fn from_executor<R: Signer + 'static>(mut executor: BaseTransactionNetworkExecutor<Interactor<S>>, relayer: R) -> Self {
+        executor.set_relayer_signer(relayer);
+
+        Self {
+            executor
+        }
+    }
+
+    /// Returns the underlying executor, allowing to configure it or to build signed transactions without broadcasting them.
+    pub fn executor_mut(&mut self) -> &mut BaseTransactionNetworkExecutor<Interactor<S>> {
+        &mut self.executor
+    }
+}
+
+#[async_trait]
+impl<S: Signer> TransactionExecutor for RelayedExecutor<S> {
+    async fn sc_call<OutputManaged>(
+        &mut self,
+        to: &Address,
+        function: String,
+        arguments: Vec<Vec<u8>>,
+        gas_limit: u64,
+        egld_value: BigUint,
+        esdt_transfers: Vec<TokenTransfer>
+    ) -> Result<CallResult<OutputManaged::Native>, ExecutorError>
+        where
+            OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
+    {
+        self.executor.sc_call::<OutputManaged>(
+            to,
+            function,
+            arguments,
+            gas_limit,
+            egld_value,
+            esdt_transfers
+        ).await
+    }
+
+    async fn should_skip_deserialization(&self) -> bool {
+        self.executor.should_skip_deserialization().await
+    }
+
+    async fn get_sender_address(&self) -> Option<Address> {
+        self.executor.get_sender_address().await
+    }
+
+    async fn get_transfer_gas_config(&self) -> TransferGasConfig {
+        self.executor.get_transfer_gas_config().await
+    }
+
+    async fn estimate_gas(
+        &self,
+        to: &Address,
+        function: String,
+        arguments: Vec<Vec<u8>>,
+        egld_value: BigUint,
+        esdt_transfers: Vec<TokenTransfer>
+    ) -> Result<u64, ExecutorError> {
+        self.executor.estimate_gas(
+            to,
+            function,
+            arguments,
+            egld_value,
+            esdt_transfers
+        ).await
+    }
+}
+
+#[async_trait]
+impl<S: Signer> DeployExecutor for RelayedExecutor<S> {
+    async fn sc_deploy<
+        OutputManaged
+    >(
+        &mut self,
+        bytes: Vec<u8>,
+        code_metadata: CodeMetadata,
+        egld_value: BigUint,
+        arguments: Vec<Vec<u8>>,
+        gas_limit: u64
+    ) -> Result<(Address, CallResult<OutputManaged::Native>), ExecutorError>
+        where
+            OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
+    {
+        self.executor.sc_deploy::<OutputManaged>(
+            bytes,
+            code_metadata,
+            egld_value,
+            arguments,
+            gas_limit
+        ).await
+    }
+}
+
+#[async_trait]
+impl<S: Signer> UpgradeExecutor for RelayedExecutor<S> {
+    async fn sc_upgrade<
+        OutputManaged
+    >(
+        &mut self,
+        contract_address: &Address,
+        bytes: Vec<u8>,
+        code_metadata: CodeMetadata,
+        egld_value: BigUint,
+        arguments: Vec<Vec<u8>>,
+        gas_limit: u64
+    ) -> Result<CallResult<OutputManaged::Native>, ExecutorError>
+        where
+            OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
+    {
+        self.executor.sc_upgrade::<OutputManaged>(
+            contract_address,
+            bytes,
+            code_metadata,
+            egld_value,
+            arguments,
+            gas_limit
+        ).await
+    }
+}
@@ -0,0 +1,56 @@
+use std::future::Future;
+use std::mem::{discriminant, Discriminant};
+use std::sync::Mutex;
+
+use futures::{FutureExt, Stream, StreamExt};
+use futures::channel::mpsc;
+use futures::stream;
+
+use crate::ExecutorError;
+use crate::network::transaction::models::transaction_on_network::TransactionOnNetwork;
+
+/// A step of a transaction's execution, as observed while polling the gateway, yielded by `Interactor::watch_transaction`.
+#[derive(Clone)]
+pub enum TransactionStatus {
+    /// The transaction is broadcast and is about to be polled.
+    Sent,
+    /// The gateway knows the transaction, which is not executed yet.
+    Pending,
+    /// The transaction is executed in its sender's shard, but its smart contract results, such as the ones of a cross-shard call, are not all executed yet.
+    CrossShard,
+    /// The wait is over: the executed transaction, which may have failed, or the error `sc_call` would return, such as a timeout.
+    Completed(Result<TransactionOnNetwork, ExecutorError>)
+}
+
+/// Returns a stream yielding `TransactionStatus::Sent`, then each intermediate status reported by `wait` when it differs from the previous one,
+/// and finally `TransactionStatus::Completed` with the output of `wait`.
+pub(crate) fn get_status_stream<'a, W, Fut>(wait: W) -> impl Stream<Item = TransactionStatus> + 'a
+    where
+        W: FnOnce(Box<dyn Fn(TransactionStatus) + Send + Sync + 'a>) -> Fut,
+        Fut: Future<Output = Result<TransactionOnNetwork, ExecutorError>> + 'a
+{
+    let (sender, receiver) = mpsc::unbounded();
+    _ = sender.unbounded_send(TransactionStatus::Sent);
+
+    let reporter_sender = sender.clone();
+    let last_status: Mutex<Discriminant<TransactionStatus>> = Mutex::new(discriminant(&TransactionStatus::Sent));
+    let report = move |status: TransactionStatus| {
+        let mut last_status = last_status.lock().unwrap();
+
+        if *last_status != discriminant(&status) {
+            *last_status = discriminant(&status);
+            _ = reporter_sender.unbounded_send(status);
+        }
+    };
+
+    // The completion is sent through the same channel as the intermediate statuses, so it is always yielded last.
+    // The channel closes once both senders are dropped, ending the stream.
+    let completion = wait(Box::new(report))
+        .map(move |result| {
+            _ = sender.unbounded_send(TransactionStatus::Completed(result));
+        });
+
+    let completion = stream::once(completion).filter_map(|_| async { None });
+
+    stream::select(receiver, completion)
+}
@@ -0,0 +1,31 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::error::executor::ExecutorError;
+
+/// A trait abstracting a push-based source of transaction events, such as the MultiversX notifier's WebSocket service.
+///
+/// When set on an executor, the gateway is still polled following the refresh strategy while the subscriber is awaited,
+/// so an already executed transaction, the timeout or a cancellation are noticed without a notification.
+/// Once the subscriber notifies the transaction, the gateway is polled right away instead of after the current sleep.
+/// If the subscriber returns an error, for example because the socket dropped, the executor goes on polling.
+///
+/// `wait_for_transaction` is called right after the transaction is broadcast: implementations should keep listening between calls
+/// and buffer the recent events, so an event received before the call is not missed.
+#[async_trait]
+pub trait TransactionEventsSubscriber: Send + Sync {
+    /// Resolves once the transaction `tx_hash` is notified as executed, or returns an error if the subscription is lost.
+    async fn wait_for_transaction(&self, tx_hash: &str) -> Result<(), ExecutorError>;
+}
+
+/// A shared `TransactionEventsSubscriber`, allowing the interactor to stay `Clone` and `Debug`.
+#[derive(Clone)]
+pub struct SharedTransactionEventsSubscriber(pub Arc<dyn TransactionEventsSubscriber>);
+
+impl Debug for SharedTransactionEventsSubscriber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SharedTransactionEventsSubscriber")
+    }
+}
@@ -1,8 +1,13 @@
+use std::str::FromStr;
+
+use num_bigint::BigUint;
 use novax_data::Address;
 use novax_request::gateway::client::GatewayClient;
 use crate::error::gateway::GatewayError;
 use crate::ExecutorError;
-use crate::network::models::address::info::{AddressGatewayInfo, AddressGatewayResponse};
+use crate::network::models::address::account::{AccountInfo, compute_shard};
+use crate::network::models::address::esdt::AddressGatewayEsdtResponse;
+use crate::network::models::address::info::{AddressGatewayInfo, AddressGatewayInfoAccount, AddressGatewayResponse};
 
 pub async fn get_address_info<Client: GatewayClient>(client: &Client, address: Address) -> Result<AddressGatewayInfo, ExecutorError> {
     let address_bech32 = address.to_bech32_string()?;
@@ -20,4 +25,122 @@ pub async fn get_address_info<Client: GatewayClient>(client: &Client, address: A
     };
 
     Ok(data)
+}
+
+pub async fn get_egld_balance<Client: GatewayClient>(client: &Client, address: Address) -> Result<BigUint, ExecutorError> {
+    let info = get_address_info(client, address).await?;
+
+    parse_balance(&info.account.balance)
+}
+
+pub async fn get_account<Client: GatewayClient>(client: &Client, address: Address, num_shards: u32) -> Result<AccountInfo, ExecutorError> {
+    let info = get_address_info(client, address.clone()).await?;
+
+    to_account_info(address, info.account, num_shards)
+}
+
+fn to_account_info(address: Address, account: AddressGatewayInfoAccount, num_shards: u32) -> Result<AccountInfo, ExecutorError> {
+    let shard = compute_shard(&address, num_shards);
+
+    let account_info = AccountInfo {
+        address,
+        nonce: account.nonce,
+        balance: parse_balance(&account.balance)?,
+        username: Some(account.username).filter(|username| !username.is_empty()),
+        code_hash: account.code_hash.filter(|code_hash| !code_hash.is_empty()),
+        shard,
+    };
+
+    Ok(account_info)
+}
+
+pub async fn get_esdt_balance<Client: GatewayClient>(client: &Client, address: Address, token_identifier: &str) -> Result<BigUint, ExecutorError> {
+    let address_bech32 = address.to_bech32_string()?;
+
+    let Ok((_, Some(text))) = client.with_appended_url(&format!("/address/{address_bech32}/esdt/{token_identifier}")).get().await else {
+        return Err(GatewayError::CannotFetchEsdtBalance { address: address_bech32, token_identifier: token_identifier.to_string() }.into())
+    };
+
+    let Ok(response) = serde_json::from_str::<AddressGatewayEsdtResponse>(&text) else {
+        return Err(GatewayError::CannotParseEsdtBalance { address: address_bech32, token_identifier: token_identifier.to_string() }.into())
+    };
+
+    let Some(data) = response.data else {
+        return Err(GatewayError::CannotParseEsdtBalance { address: address_bech32, token_identifier: token_identifier.to_string() }.into())
+    };
+
+    parse_balance(&data.token_data.balance)
+}
+
+fn parse_balance(balance: &str) -> Result<BigUint, ExecutorError> {
+    BigUint::from_str(balance)
+        .map_err(|_| GatewayError::CannotParseBalance { balance: balance.to_string() }.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use crate::error::gateway::GatewayError;
+    use crate::ExecutorError;
+    use novax_data::Address;
+
+    use crate::network::models::address::esdt::AddressGatewayEsdtResponse;
+    use crate::network::models::address::info::AddressGatewayResponse;
+    use crate::network::utils::address::{parse_balance, to_account_info};
+
+    #[test]
+    fn test_parse_balance() {
+        let result = parse_balance("1000000000000000000000").unwrap();
+
+        assert_eq!(result, BigUint::from(10u128.pow(21)));
+    }
+
+    #[test]
+    fn test_parse_balance_invalid() {
+        let result = parse_balance("-1");
+
+        let expected = Err(ExecutorError::from(GatewayError::CannotParseBalance { balance: "-1".to_string() }));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_deserialize_esdt_response() {
+        let data = r#"{"data":{"tokenData":{"balance":"1500","properties":"","tokenIdentifier":"WEGLD-a28c59"},"blockInfo":{"nonce":1}},"error":"","code":"successful"}"#;
+
+        let response = serde_json::from_str::<AddressGatewayEsdtResponse>(data).unwrap();
+        let token_data = response.data.unwrap().token_data;
+
+        assert_eq!(token_data.token_identifier, "WEGLD-a28c59");
+        assert_eq!(parse_balance(&token_data.balance).unwrap(), BigUint::from(1500u16));
+    }
+
+    #[test]
+    fn test_to_account_info() {
+        let address = "erd1qqqqqqqqqqqqqpgqtqfhy99su9xzjjrq59kpzpp25udtc9eq0n4sr90ax6";
+        let data = format!(r#"{{"data":{{"account":{{"address":"{address}","nonce":0,"balance":"1500","username":"","code":"0061736d","codeHash":"5bTVUfyUBlaRmO0B0PnXbeRnZkUMfhv7u6O2wMpuaSI=","rootHash":"","codeMetadata":"BQY=","developerReward":"0","ownerAddress":"erd1x39tc3q3nn72ecjnmcz7x0qp09kp97t080x99dgyhx7zh95j0n4szskhlv"}},"blockInfo":{{"nonce":1}}}},"error":"","code":"successful"}}"#);
+
+        let response = serde_json::from_str::<AddressGatewayResponse>(&data).unwrap();
+        let account = to_account_info(Address::from_bech32_string(address).unwrap(), response.data.unwrap().account, 3).unwrap();
+
+        assert_eq!(account.nonce, 0);
+        assert_eq!(account.balance, BigUint::from(1500u16));
+        assert_eq!(account.username, None);
+        assert_eq!(account.code_hash, Some("5bTVUfyUBlaRmO0B0PnXbeRnZkUMfhv7u6O2wMpuaSI=".to_string()));
+        assert_eq!(account.shard, 1);
+    }
+
+    #[test]
+    fn test_to_account_info_with_username() {
+        let address = "erd1x39tc3q3nn72ecjnmcz7x0qp09kp97t080x99dgyhx7zh95j0n4szskhlv";
+        let data = format!(r#"{{"data":{{"account":{{"address":"{address}","nonce":12,"balance":"0","username":"alice.elrond"}}}},"error":"","code":"successful"}}"#);
+
+        let response = serde_json::from_str::<AddressGatewayResponse>(&data).unwrap();
+        let account = to_account_info(Address::from_bech32_string(address).unwrap(), response.data.unwrap().account, 3).unwrap();
+
+        assert_eq!(account.nonce, 12);
+        assert_eq!(account.username, Some("alice.elrond".to_string()));
+        assert_eq!(account.code_hash, None);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,99 @@
+use aes::Aes128;
+use ctr::cipher::{KeyIvInit, StreamCipher};
+use ctr::Ctr128BE;
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+use crate::error::wallet::WalletError;
+use crate::ExecutorError;
+
+type Aes128Ctr = Ctr128BE<Aes128>;
+type HmacSha256 = Hmac<Sha256>;
+
+const KEYSTORE_CIPHER: &str = "aes-128-ctr";
+const KEYSTORE_KDF: &str = "scrypt";
+const DERIVED_KEY_LENGTH: usize = 32;
+
+#[derive(Deserialize)]
+struct Keystore {
+    crypto: KeystoreCrypto
+}
+
+#[derive(Deserialize)]
+struct KeystoreCrypto {
+    ciphertext: String,
+    cipherparams: KeystoreCipherParams,
+    cipher: String,
+    kdf: String,
+    kdfparams: KeystoreKdfParams,
+    mac: String
+}
+
+#[derive(Deserialize)]
+struct KeystoreCipherParams {
+    iv: String
+}
+
+#[derive(Deserialize)]
+struct KeystoreKdfParams {
+    dklen: usize,
+    salt: String,
+    n: u64,
+    r: u32,
+    p: u32
+}
+
+/// Decrypts the secret key held by a keystore JSON, as generated by the MultiversX web wallet.
+///
+/// The password is checked against the keystore's MAC before decrypting anything.
+pub(crate) fn decrypt_keystore(contents: &str, password: &str) -> Result<Vec<u8>, ExecutorError> {
+    let keystore: Keystore = serde_json::from_str(contents)
+        .map_err(|_| WalletError::InvalidKeystoreFile)?;
+    let crypto = keystore.crypto;
+
+    if crypto.cipher != KEYSTORE_CIPHER || crypto.kdf != KEYSTORE_KDF || crypto.kdfparams.dklen != DERIVED_KEY_LENGTH {
+        return Err(WalletError::InvalidKeystoreFile.into())
+    }
+
+    if !crypto.kdfparams.n.is_power_of_two() {
+        return Err(WalletError::InvalidKeystoreFile.into())
+    }
+
+    let salt = decode_hex_field(&crypto.kdfparams.salt)?;
+    let iv = decode_hex_field(&crypto.cipherparams.iv)?;
+    let mut ciphertext = decode_hex_field(&crypto.ciphertext)?;
+    let mac = decode_hex_field(&crypto.mac)?;
+
+    let scrypt_params = scrypt::Params::new(
+        crypto.kdfparams.n.trailing_zeros() as u8,
+        crypto.kdfparams.r,
+        crypto.kdfparams.p,
+        DERIVED_KEY_LENGTH
+    )
+        .map_err(|_| WalletError::InvalidKeystoreFile)?;
+
+    let mut derived_key = Zeroizing::new([0u8; DERIVED_KEY_LENGTH]);
+    scrypt::scrypt(password.as_bytes(), &salt, &scrypt_params, derived_key.as_mut())
+        .map_err(|_| WalletError::InvalidKeystoreFile)?;
+
+    let (encryption_key, mac_key) = derived_key.split_at(DERIVED_KEY_LENGTH / 2);
+
+    let mut hmac = HmacSha256::new_from_slice(mac_key)
+        .map_err(|_| WalletError::InvalidKeystoreFile)?;
+    hmac.update(&ciphertext);
+    hmac.verify_slice(&mac)
+        .map_err(|_| WalletError::InvalidPassword)?;
+
+    let mut cipher = Aes128Ctr::new_from_slices(encryption_key, &iv)
+        .map_err(|_| WalletError::InvalidKeystoreFile)?;
+    cipher.apply_keystream(&mut ciphertext);
+
+    Ok(ciphertext)
+}
+
+fn decode_hex_field(field: &str) -> Result<Vec<u8>, ExecutorError> {
+    hex::decode(field)
+        .map_err(|_| WalletError::InvalidKeystoreFile.into())
+}
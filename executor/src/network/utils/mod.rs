@@ -1,4 +1,15 @@
+#[cfg(feature = "network")]
 pub mod address;
+#[cfg(feature = "network")]
 pub mod network;
 pub mod wallet;
-pub mod transaction;
\ No newline at end of file
+#[cfg(feature = "network")]
+pub mod transaction;
+#[cfg(feature = "network")]
+pub mod retry;
+pub mod keystore;
+pub mod signer;
+#[cfg(feature = "network")]
+pub mod tracing;
+#[cfg(feature = "network")]
+pub mod token;
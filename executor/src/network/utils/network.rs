@@ -2,6 +2,7 @@ use novax_request::gateway::client::GatewayClient;
 use crate::error::gateway::GatewayError;
 use crate::ExecutorError;
 use crate::network::models::network::config::{NetworkGatewayConfig, NetworkGatewayConfigResponse};
+use crate::network::models::network::status::{NetworkGatewayStatusResponse, NetworkStatus};
 
 pub async fn get_network_config<Client: GatewayClient>(client: &Client) -> Result<NetworkGatewayConfig, ExecutorError> {
     let Ok((_, Some(text)))= client.with_appended_url("/network/config").get().await else {
@@ -17,4 +18,57 @@ pub async fn get_network_config<Client: GatewayClient>(client: &Client) -> Resul
     };
 
     Ok(data)
-}
\ No newline at end of file
+}
+
+pub async fn get_network_status<Client: GatewayClient>(client: &Client, shard: u32) -> Result<NetworkStatus, ExecutorError> {
+    let Ok((_, Some(text))) = client.with_appended_url(&format!("/network/status/{shard}")).get().await else {
+        return Err(GatewayError::CannotFetchNetworkStatus { shard }.into())
+    };
+
+    parse_network_status(&text, shard)
+}
+
+fn parse_network_status(text: &str, shard: u32) -> Result<NetworkStatus, ExecutorError> {
+    let Ok(response) = serde_json::from_str::<NetworkGatewayStatusResponse>(text) else {
+        return Err(GatewayError::CannotParseNetworkStatus { shard }.into())
+    };
+
+    let Some(data) = response.data else {
+        return Err(GatewayError::CannotParseNetworkStatus { shard }.into())
+    };
+
+    Ok(data.status)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::gateway::GatewayError;
+    use crate::ExecutorError;
+    use crate::network::models::network::status::NetworkStatus;
+    use crate::network::utils::network::parse_network_status;
+
+    #[test]
+    fn test_parse_network_status() {
+        let data = r#"{"data":{"status":{"erd_current_round":21041862,"erd_epoch_number":1460,"erd_highest_final_nonce":21018405,"erd_nonce":21018406,"erd_nonce_at_epoch_start":21004225,"erd_nonces_passed_in_current_epoch":14181,"erd_round_at_epoch_start":21027680,"erd_rounds_passed_in_current_epoch":14182,"erd_rounds_per_epoch":14400}},"error":"","code":"successful"}"#;
+
+        let result = parse_network_status(data, 1).unwrap();
+
+        let expected = NetworkStatus {
+            erd_current_round: 21041862,
+            erd_epoch_number: 1460,
+            erd_nonce: 21018406,
+            erd_highest_final_nonce: 21018405,
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_network_status_error_response() {
+        let data = r#"{"data":null,"error":"invalid shard ID","code":"bad_request"}"#;
+
+        let result = parse_network_status(data, 7);
+
+        assert_eq!(result, Err(ExecutorError::from(GatewayError::CannotParseNetworkStatus { shard: 7 })));
+    }
+}
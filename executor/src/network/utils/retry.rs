@@ -0,0 +1,106 @@
+use std::future::Future;
+use std::time::Duration;
+
+use crate::error::gateway::GatewayError;
+use crate::error::transaction::TransactionError;
+use crate::ExecutorError;
+
+/// Describes how idempotent gateway requests are retried when they fail.
+///
+/// Between two attempts, the delay doubles starting from `base_delay`: `base_delay`, `2 * base_delay`, `4 * base_delay`, etc.
+/// Only requests that failed before returning a readable response are retried, parsing errors are returned immediately.
+///
+/// Sending a transaction is never retried, since a failed request doesn't guarantee the transaction didn't reach the mempool.
+#[derive(Clone, Copy, Debug)]
+pub struct RetryPolicy {
+    /// The maximum number of retries after the first attempt. Zero disables retrying.
+    pub max_retries: u32,
+    /// The delay before the first retry.
+    pub base_delay: Duration
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            base_delay: Duration::from_millis(500)
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a new `RetryPolicy`.
+    pub fn new(max_retries: u32, base_delay: Duration) -> Self {
+        Self {
+            max_retries,
+            base_delay
+        }
+    }
+
+    pub(crate) async fn run<T, F, Fut>(&self, mut operation: F) -> Result<T, ExecutorError>
+        where
+            F: FnMut() -> Fut,
+            Fut: Future<Output = Result<T, ExecutorError>>
+    {
+        let mut attempt = 0;
+
+        loop {
+            match operation().await {
+                Err(error) if attempt < self.max_retries && is_transient_error(&error) => {
+                    tokio::time::sleep(self.get_delay(attempt)).await;
+                    attempt += 1;
+                },
+                result => return result
+            }
+        }
+    }
+
+    fn get_delay(&self, attempt: u32) -> Duration {
+        self.base_delay.saturating_mul(2u32.saturating_pow(attempt))
+    }
+}
+
+fn is_transient_error(error: &ExecutorError) -> bool {
+    matches!(
+        error.unlabeled(),
+        ExecutorError::Gateway(GatewayError::CannotFetchAddressInfo { .. })
+            | ExecutorError::Gateway(GatewayError::CannotFetchEsdtBalance { .. })
+            | ExecutorError::Gateway(GatewayError::CannotFetchTokenProperties { .. })
+            | ExecutorError::Gateway(GatewayError::CannotFetchNetworkConfig)
+            | ExecutorError::Gateway(GatewayError::CannotSimulateTransaction)
+            | ExecutorError::Transaction(TransactionError::ErrorWhileGettingTransactionOnNetwork { .. })
+            | ExecutorError::Transaction(TransactionError::ErrorWhileGettingTransactionProcessStatus { .. })
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::error::gateway::GatewayError;
+    use crate::ExecutorError;
+    use crate::network::utils::retry::{is_transient_error, RetryPolicy};
+
+    #[test]
+    fn test_get_delay_doubles() {
+        let policy = RetryPolicy::new(3, Duration::from_millis(100));
+
+        assert_eq!(policy.get_delay(0), Duration::from_millis(100));
+        assert_eq!(policy.get_delay(1), Duration::from_millis(200));
+        assert_eq!(policy.get_delay(2), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn test_is_transient_error_fetch_failure() {
+        let error: ExecutorError = GatewayError::CannotFetchNetworkConfig.into();
+
+        assert!(is_transient_error(&error));
+    }
+
+    #[test]
+    fn test_is_transient_error_parsing_failure() {
+        let error: ExecutorError = GatewayError::CannotParseNetworkConfig.into();
+
+        assert!(!is_transient_error(&error));
+    }
+}
@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+
+use novax_data::Address;
+
+use crate::error::signer::SignerError;
+
+/// A trait abstracting the signing of transactions, allowing keys to live outside of the process memory.
+///
+/// `Wallet` implements this trait for in-memory keys. Other implementations can rely on hardware wallets,
+/// remote KMS/HSM services or multisig flows.
+#[async_trait]
+pub trait Signer: Send + Sync {
+    /// Signs the given message and returns the resulting ed25519 signature.
+    async fn sign(&self, message: &[u8]) -> Result<[u8; 64], SignerError>;
+
+    /// Returns the address of the account whose key is used for signing.
+    fn get_address(&self) -> Address;
+}
+
+/// A `Signer` holding only an address, giving a deterministic sender to tests without generating or storing any key.
+///
+/// It suits the executors which never sign, such as the `DummyExecutor` or a `BaseTransactionNetworkExecutor` built on a `MockInteractor`,
+/// keeping snapshots of the sent calls stable across runs and machines. It cannot sign: `sign` always returns `SignerError::SigningFailed`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AddressSigner {
+    address: Address
+}
+
+impl AddressSigner {
+    /// Creates a signer whose address is `address`.
+    pub fn new(address: Address) -> Self {
+        AddressSigner { address }
+    }
+}
+
+#[async_trait]
+impl Signer for AddressSigner {
+    async fn sign(&self, _message: &[u8]) -> Result<[u8; 64], SignerError> {
+        Err(SignerError::SigningFailed { message: "an AddressSigner holds no key and cannot sign".to_string() })
+    }
+
+    fn get_address(&self) -> Address {
+        self.address.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use novax_data::Address;
+
+    use crate::error::signer::SignerError;
+    use crate::network::utils::signer::{AddressSigner, Signer};
+
+    #[test]
+    fn test_address_signer_get_address() {
+        let address = Address::from_bytes([1; 32]);
+
+        assert_eq!(AddressSigner::new(address.clone()).get_address(), address);
+    }
+
+    #[tokio::test]
+    async fn test_address_signer_cannot_sign() {
+        let signer = AddressSigner::new(Address::from_bytes([1; 32]));
+
+        let expected = SignerError::SigningFailed { message: "an AddressSigner holds no key and cannot sign".to_string() };
+
+        assert_eq!(signer.sign(b"message").await, Err(expected));
+    }
+}
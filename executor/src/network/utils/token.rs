@@ -0,0 +1,214 @@
+use std::str::FromStr;
+
+use base64::Engine;
+use num_bigint::BigUint;
+use novax_data::Address;
+use novax_request::gateway::client::GatewayClient;
+
+use crate::error::gateway::GatewayError;
+use crate::ExecutorError;
+use crate::network::models::token::properties::TokenProperties;
+use crate::network::query::models::request::VmValuesQueryRequest;
+use crate::network::query::models::response::VmValuesQueryResponse;
+
+/// The return message of `getTokenProperties` when the token doesn't exist.
+const TOKEN_NOT_FOUND_MESSAGE: &str = "no ticker with given name";
+
+/// The number of values returned by `getTokenProperties` before the "Key-value" flags: name, type, owner, minted value and burnt value.
+const POSITIONAL_VALUES_COUNT: usize = 5;
+
+pub async fn get_token_properties<Client: GatewayClient>(client: &Client, token_identifier: &str) -> Result<TokenProperties, ExecutorError> {
+    let body = VmValuesQueryRequest {
+        sc_address: Address::esdt_system_sc().to_bech32_string()?,
+        func_name: "getTokenProperties".to_string(),
+        args: vec![hex::encode(token_identifier)],
+        caller: None,
+        value: None,
+        at_block: None,
+    };
+
+    let Ok((_, Some(text))) = client.with_appended_url("/vm-values/query").post(&body).await else {
+        return Err(GatewayError::CannotFetchTokenProperties { token_identifier: token_identifier.to_string() }.into())
+    };
+
+    let Ok(response) = serde_json::from_str::<VmValuesQueryResponse>(&text) else {
+        return Err(GatewayError::CannotParseTokenProperties { token_identifier: token_identifier.to_string() }.into())
+    };
+
+    let Some(data) = response.data else {
+        return Err(GatewayError::CannotParseTokenProperties { token_identifier: token_identifier.to_string() }.into())
+    };
+
+    let Some(return_data) = data.data.return_data else {
+        if data.data.return_message == TOKEN_NOT_FOUND_MESSAGE {
+            return Err(GatewayError::TokenNotFound { token_identifier: token_identifier.to_string() }.into())
+        }
+
+        return Err(GatewayError::CannotParseTokenProperties { token_identifier: token_identifier.to_string() }.into())
+    };
+
+    to_token_properties(token_identifier, &return_data)
+}
+
+/// Decodes the base64 values returned by `getTokenProperties`.
+///
+/// The first values are positional, the following ones are flags such as "NumDecimals-18" or "IsPaused-false",
+/// which are decoded by their key rather than their position.
+fn to_token_properties(token_identifier: &str, return_data: &[String]) -> Result<TokenProperties, ExecutorError> {
+    let parse_error = || -> ExecutorError {
+        GatewayError::CannotParseTokenProperties { token_identifier: token_identifier.to_string() }.into()
+    };
+
+    let values = return_data
+        .iter()
+        .map(|value| base64::engine::general_purpose::STANDARD.decode(value))
+        .collect::<Result<Vec<Vec<u8>>, _>>()
+        .map_err(|_| parse_error())?;
+
+    if values.len() < POSITIONAL_VALUES_COUNT {
+        return Err(parse_error())
+    }
+
+    let to_string = |bytes: &[u8]| String::from_utf8(bytes.to_vec()).map_err(|_| parse_error());
+    let to_biguint = |bytes: &[u8]| BigUint::from_str(&to_string(bytes)?).map_err(|_| parse_error());
+
+    let owner_bytes: [u8; 32] = values[2].clone().try_into().map_err(|_| parse_error())?;
+
+    let mut properties = TokenProperties {
+        identifier: token_identifier.to_string(),
+        name: to_string(&values[0])?,
+        token_type: to_string(&values[1])?,
+        owner: Address::from_bytes(owner_bytes),
+        minted_value: to_biguint(&values[3])?,
+        burnt_value: to_biguint(&values[4])?,
+        decimals: 0,
+        is_paused: false,
+        can_upgrade: false,
+        can_mint: false,
+        can_burn: false,
+        can_change_owner: false,
+        can_pause: false,
+        can_freeze: false,
+        can_wipe: false,
+        can_add_special_roles: false,
+        can_transfer_nft_create_role: false,
+        nft_create_stopped: false,
+        wiped_amount: BigUint::default(),
+    };
+
+    for value in &values[POSITIONAL_VALUES_COUNT..] {
+        let flag = to_string(value)?;
+        let Some((key, value)) = flag.split_once('-') else {
+            return Err(parse_error())
+        };
+
+        let is_true = value == "true";
+
+        match key {
+            "NumDecimals" => properties.decimals = u8::from_str(value).map_err(|_| parse_error())?,
+            "IsPaused" => properties.is_paused = is_true,
+            "CanUpgrade" => properties.can_upgrade = is_true,
+            "CanMint" => properties.can_mint = is_true,
+            "CanBurn" => properties.can_burn = is_true,
+            "CanChangeOwner" => properties.can_change_owner = is_true,
+            "CanPause" => properties.can_pause = is_true,
+            "CanFreeze" => properties.can_freeze = is_true,
+            "CanWipe" => properties.can_wipe = is_true,
+            "CanAddSpecialRoles" => properties.can_add_special_roles = is_true,
+            "CanTransferNFTCreateRole" => properties.can_transfer_nft_create_role = is_true,
+            "NFTCreateStopped" => properties.nft_create_stopped = is_true,
+            "NumWiped" => properties.wiped_amount = BigUint::from_str(value).map_err(|_| parse_error())?,
+            _ => {}
+        }
+    }
+
+    Ok(properties)
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use novax_data::Address;
+
+    use crate::error::gateway::GatewayError;
+    use crate::ExecutorError;
+    use crate::network::models::token::properties::TokenProperties;
+    use crate::network::utils::token::to_token_properties;
+
+    fn get_wegld_return_data() -> Vec<String> {
+        [
+            "V3JhcHBlZEVHTEQ=",
+            "RnVuZ2libGVFU0RU",
+            "NEq8RBGc/KziU94F4zwBeWwS+W87zFK1BLm8K5aSfOs=",
+            "MTAwMA==",
+            "MjAw",
+            "TnVtRGVjaW1hbHMtMTg=",
+            "SXNQYXVzZWQtZmFsc2U=",
+            "Q2FuVXBncmFkZS10cnVl",
+            "Q2FuTWludC10cnVl",
+            "Q2FuQnVybi10cnVl",
+            "Q2FuQ2hhbmdlT3duZXItdHJ1ZQ==",
+            "Q2FuUGF1c2UtdHJ1ZQ==",
+            "Q2FuRnJlZXplLXRydWU=",
+            "Q2FuV2lwZS10cnVl",
+            "Q2FuQWRkU3BlY2lhbFJvbGVzLXRydWU=",
+            "Q2FuVHJhbnNmZXJORlRDcmVhdGVSb2xlLWZhbHNl",
+            "TkZUQ3JlYXRlU3RvcHBlZC1mYWxzZQ==",
+            "TnVtV2lwZWQtMA=="
+        ]
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn test_to_token_properties() {
+        let result = to_token_properties("WEGLD-d7c6bb", &get_wegld_return_data()).unwrap();
+
+        let expected = TokenProperties {
+            identifier: "WEGLD-d7c6bb".to_string(),
+            name: "WrappedEGLD".to_string(),
+            token_type: "FungibleESDT".to_string(),
+            owner: Address::from_bech32_string("erd1x39tc3q3nn72ecjnmcz7x0qp09kp97t080x99dgyhx7zh95j0n4szskhlv").unwrap(),
+            minted_value: BigUint::from(1000u16),
+            burnt_value: BigUint::from(200u8),
+            decimals: 18,
+            is_paused: false,
+            can_upgrade: true,
+            can_mint: true,
+            can_burn: true,
+            can_change_owner: true,
+            can_pause: true,
+            can_freeze: true,
+            can_wipe: true,
+            can_add_special_roles: true,
+            can_transfer_nft_create_role: false,
+            nft_create_stopped: false,
+            wiped_amount: BigUint::default(),
+        };
+
+        assert_eq!(result, expected);
+        assert_eq!(result.get_supply(), BigUint::from(800u16));
+    }
+
+    #[test]
+    fn test_to_token_properties_missing_values() {
+        let result = to_token_properties("WEGLD-d7c6bb", &get_wegld_return_data()[..3]);
+
+        let expected: Result<TokenProperties, ExecutorError> = Err(GatewayError::CannotParseTokenProperties { token_identifier: "WEGLD-d7c6bb".to_string() }.into());
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_to_token_properties_invalid_decimals() {
+        let mut return_data = get_wegld_return_data();
+        return_data[5] = "TnVtRGVjaW1hbHMtYWJj".to_string(); // NumDecimals-abc
+
+        let result = to_token_properties("WEGLD-d7c6bb", &return_data);
+
+        let expected: Result<TokenProperties, ExecutorError> = Err(GatewayError::CannotParseTokenProperties { token_identifier: "WEGLD-d7c6bb".to_string() }.into());
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,134 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use novax_request::error::request::RequestError;
+use novax_request::gateway::client::GatewayClient;
+use novax_request::gateway::reqwest::ReqwestGatewayClient;
+use reqwest::StatusCode;
+use serde::Serialize;
+
+/// The details of a request sent to the gateway, passed to the callback set with `set_on_gateway_request`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct GatewayRequestInfo {
+    /// The correlation ID set with `set_request_id`, if any.
+    pub request_id: Option<String>,
+    /// The label of the call being made, such as "swap", set with `set_call_label` before the call, if any.
+    pub label: Option<String>,
+    /// The HTTP method, "GET" or "POST".
+    pub method: &'static str,
+    /// The full URL of the request, such as "https://gateway.multiversx.com/transaction/{hash}".
+    pub url: String,
+    /// The HTTP status of the response, or `None` if no response was received.
+    pub status: Option<u16>,
+    /// The time elapsed between sending the request and receiving the whole response.
+    pub duration: Duration
+}
+
+/// A callback invoked after each request sent to the gateway.
+#[derive(Clone)]
+pub struct GatewayRequestCallback(pub Arc<dyn Fn(&GatewayRequestInfo) + Send + Sync>);
+
+impl Debug for GatewayRequestCallback {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GatewayRequestCallback")
+    }
+}
+
+/// A `ReqwestGatewayClient` reporting each of its requests to a `GatewayRequestCallback`, if any.
+#[derive(Clone, Debug)]
+pub(crate) struct TracedGatewayClient {
+    pub client: ReqwestGatewayClient,
+    pub request_id: Option<String>,
+    pub label: Option<String>,
+    pub on_request: Option<GatewayRequestCallback>
+}
+
+impl TracedGatewayClient {
+    fn report(&self, method: &'static str, started_at: Instant, result: &Result<(StatusCode, Option<String>), RequestError>) {
+        let Some(on_request) = self.on_request.as_ref() else {
+            return
+        };
+
+        let info = GatewayRequestInfo {
+            request_id: self.request_id.clone(),
+            label: self.label.clone(),
+            method,
+            url: self.client.get_gateway_url().to_string(),
+            status: result.as_ref().ok().map(|(status, _)| status.as_u16()),
+            duration: started_at.elapsed(),
+        };
+
+        (on_request.0)(&info);
+    }
+}
+
+#[async_trait]
+impl GatewayClient for TracedGatewayClient {
+    type Owned = Self;
+
+    fn get_gateway_url(&self) -> &str {
+        self.client.get_gateway_url()
+    }
+
+    fn with_appended_url(&self, url: &str) -> Self::Owned {
+        TracedGatewayClient {
+            client: self.client.with_appended_url(url),
+            request_id: self.request_id.clone(),
+            label: self.label.clone(),
+            on_request: self.on_request.clone()
+        }
+    }
+
+    async fn get(&self) -> Result<(StatusCode, Option<String>), RequestError> {
+        let started_at = Instant::now();
+        let result = self.client.get().await;
+        self.report("GET", started_at, &result);
+
+        result
+    }
+
+    async fn post<Body>(&self, body: &Body) -> Result<(StatusCode, Option<String>), RequestError> where Body: Serialize + Send + Sync {
+        let started_at = Instant::now();
+        let result = self.client.post(body).await;
+        self.report("POST", started_at, &result);
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use novax_request::gateway::client::GatewayClient;
+    use novax_request::gateway::reqwest::ReqwestGatewayClient;
+
+    use crate::network::utils::tracing::{GatewayRequestCallback, GatewayRequestInfo, TracedGatewayClient};
+
+    #[tokio::test]
+    async fn test_traced_client_reports_failed_request() {
+        let reported: Arc<Mutex<Vec<GatewayRequestInfo>>> = Arc::new(Mutex::new(vec![]));
+        let reported_clone = reported.clone();
+
+        let client = TracedGatewayClient {
+            client: ReqwestGatewayClient::new("http://127.0.0.1:1", reqwest::Client::new()),
+            request_id: Some("request-1".to_string()),
+            label: Some("swap".to_string()),
+            on_request: Some(GatewayRequestCallback(Arc::new(move |info| reported_clone.lock().unwrap().push(info.clone())))),
+        };
+
+        let result = client.with_appended_url("/network/config").get().await;
+
+        let reported = reported.lock().unwrap();
+
+        assert!(result.is_err());
+        assert_eq!(reported.len(), 1);
+        assert_eq!(reported[0].request_id, Some("request-1".to_string()));
+        assert_eq!(reported[0].label, Some("swap".to_string()));
+        assert_eq!(reported[0].method, "GET");
+        assert_eq!(reported[0].url, "http://127.0.0.1:1/network/config");
+        assert_eq!(reported[0].status, None);
+    }
+}
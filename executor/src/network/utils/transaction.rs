@@ -1,8 +1,10 @@
 use novax_request::gateway::client::GatewayClient;
 use crate::error::transaction::TransactionError;
-use crate::ExecutorError;
+use crate::{ExecutorError, GatewayError, SimulationError, SimulationGatewayResponse};
+use crate::network::models::simulate::request::SimulationGatewayRequestBody;
+use crate::network::transaction::models::process_status::TransactionProcessStatusResponse;
 use crate::network::transaction::models::send_request::TransactionSendRequest;
-use crate::network::transaction::models::send_response::TransactionSendResponse;
+use crate::network::transaction::models::send_response::{TransactionSendMultipleResponse, TransactionSendResponse};
 use crate::network::transaction::models::transaction_on_network::{TransactionOnNetworkResponse, TransactionOnNetwork};
 
 pub async fn send_transaction<Client: GatewayClient>(client: &Client, transaction_request: &TransactionSendRequest) -> Result<String, ExecutorError> {
@@ -23,6 +25,36 @@ pub async fn send_transaction<Client: GatewayClient>(client: &Client, transactio
     Ok(sent_transaction_data.tx_hash)
 }
 
+/// Broadcasts all the transactions in a single request through `/transaction/send-multiple`.
+///
+/// The returned vector has one entry per transaction, in the same order: its hash, or `None` if the gateway rejected it.
+pub async fn send_multiple_transactions<Client: GatewayClient>(client: &Client, transaction_requests: &[TransactionSendRequest]) -> Result<Vec<Option<String>>, ExecutorError> {
+    let Ok((_, Some(text))) = client
+        .with_appended_url("/transaction/send-multiple")
+        .post(&transaction_requests)
+        .await else {
+        return Err(TransactionError::ErrorWhileSendingTheTransaction.into())
+    };
+
+    to_sent_hashes(text, transaction_requests.len())
+}
+
+/// Parses the response of `/transaction/send-multiple` into the hash of each of the `sent_count` transactions, `None` for the rejected ones.
+fn to_sent_hashes(text: String, sent_count: usize) -> Result<Vec<Option<String>>, ExecutorError> {
+    let send_multiple_response: TransactionSendMultipleResponse = serde_json::from_str(&text)
+        .map_err(|_| TransactionError::CannotDeserializeTransactionSendingResponse { response: text })?;
+
+    let Some(mut send_multiple_data) = send_multiple_response.data else {
+        return Err(TransactionError::FailedToSendTheTransaction { message: send_multiple_response.error }.into())
+    };
+
+    let sent_hashes = (0..sent_count)
+        .map(|index| send_multiple_data.txs_hashes.remove(&index))
+        .collect();
+
+    Ok(sent_hashes)
+}
+
 pub async fn get_transaction_on_network<Client: GatewayClient>(client: &Client, tx_hash: &str) -> Result<TransactionOnNetwork, ExecutorError> {
     let url_to_append = format!("/transaction/{tx_hash}?withResults=true");
     let Ok((_, Some(text))) = client
@@ -42,4 +74,85 @@ pub async fn get_transaction_on_network<Client: GatewayClient>(client: &Client,
     };
 
     Ok(transaction_on_network_data)
-}
\ No newline at end of file
+}
+
+/// Returns the process status of a transaction, which stays "pending" until all its smart contract results are executed, including in other shards.
+pub async fn get_transaction_process_status<Client: GatewayClient>(client: &Client, tx_hash: &str) -> Result<String, ExecutorError> {
+    let url_to_append = format!("/transaction/{tx_hash}/process-status");
+    let Ok((_, Some(text))) = client
+        .with_appended_url(&url_to_append)
+        .get()
+        .await else {
+        return Err(TransactionError::ErrorWhileGettingTransactionProcessStatus { tx_hash: tx_hash.to_string() }.into())
+    };
+
+    let process_status_response: TransactionProcessStatusResponse = serde_json::from_str(&text)
+        .map_err(|_| {
+            TransactionError::CannotDeserializeTransactionProcessStatusResponse { response: text }
+        })?;
+
+    let Some(process_status_data) = process_status_response.data else {
+        return Err(TransactionError::CannotDeserializeTransactionProcessStatusResponse { response: process_status_response.error }.into())
+    };
+
+    Ok(process_status_data.status)
+}
+
+pub async fn estimate_transaction_cost<Client: GatewayClient>(client: &Client, body: &SimulationGatewayRequestBody) -> Result<u64, ExecutorError> {
+    let Ok((_, Some(text))) = client
+        .with_appended_url("/transaction/cost")
+        .post(body)
+        .await else {
+        return Err(GatewayError::CannotSimulateTransaction.into())
+    };
+
+    let Ok(response) = serde_json::from_str::<SimulationGatewayResponse>(&text) else {
+        return Err(GatewayError::CannotParseSimulationResponse.into())
+    };
+
+    let Some(data) = response.data else {
+        return Err(SimulationError::ErrorInTx { code: response.code, error: response.error }.into())
+    };
+
+    if !data.return_message.is_empty() {
+        return Err(SimulationError::ErrorInTx { code: response.code, error: data.return_message }.into())
+    }
+
+    Ok(data.tx_gas_units)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::transaction::TransactionError;
+    use crate::ExecutorError;
+    use crate::network::utils::transaction::to_sent_hashes;
+
+    #[test]
+    fn test_to_sent_hashes() {
+        let text = r#"{"data":{"numOfSentTxs":2,"txsHashes":{"0":"hash0","1":"hash1"}},"error":"","code":"successful"}"#;
+
+        let result = to_sent_hashes(text.to_string(), 2).unwrap();
+
+        assert_eq!(result, vec![Some("hash0".to_string()), Some("hash1".to_string())]);
+    }
+
+    #[test]
+    fn test_to_sent_hashes_rejected_transaction() {
+        let text = r#"{"data":{"numOfSentTxs":2,"txsHashes":{"0":"hash0","2":"hash2"}},"error":"","code":"successful"}"#;
+
+        let result = to_sent_hashes(text.to_string(), 3).unwrap();
+
+        assert_eq!(result, vec![Some("hash0".to_string()), None, Some("hash2".to_string())]);
+    }
+
+    #[test]
+    fn test_to_sent_hashes_all_rejected() {
+        let text = r#"{"data":null,"error":"transaction generation failed","code":"bad_request"}"#;
+
+        let result = to_sent_hashes(text.to_string(), 1);
+
+        let expected: ExecutorError = TransactionError::FailedToSendTheTransaction { message: "transaction generation failed".to_string() }.into();
+
+        assert_eq!(result.err(), Some(expected));
+    }
+}
@@ -1,3 +1,4 @@
 /// The `transaction` module contains essential structs and types for handling blockchain transactions.
 pub mod transaction;
+#[cfg(feature = "network")]
 pub mod date; // TODO: this mod is a duplicate with the caching crate
\ No newline at end of file
@@ -0,0 +1,29 @@
+use num_bigint::BigUint;
+
+use novax_data::Address;
+
+use crate::TokenTransfer;
+
+/// A structure describing a single contract call to be sent as part of a batch.
+///
+/// Its fields mirror the parameters of `TransactionExecutor::sc_call`.
+#[derive(PartialEq, Clone, Debug)]
+pub struct BatchCall {
+    /// The address of the contract to call.
+    pub to: Address,
+
+    /// The name of the endpoint to call.
+    pub function: String,
+
+    /// The encoded arguments of the call.
+    pub arguments: Vec<Vec<u8>>,
+
+    /// The gas limit of the call. If zero, the gas limit is estimated.
+    pub gas_limit: u64,
+
+    /// The amount of EGLD sent along with the call.
+    pub egld_value: BigUint,
+
+    /// The ESDTs sent along with the call.
+    pub esdt_transfers: Vec<TokenTransfer>
+}
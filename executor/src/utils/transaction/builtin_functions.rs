@@ -0,0 +1,44 @@
+use crate::utils::transaction::egld_transfer::TransferGasConfig;
+
+/// The name of the built-in function sending the developer rewards accumulated by a contract to its owner.
+pub const CLAIM_DEVELOPER_REWARDS_FUNCTION: &str = "ClaimDeveloperRewards";
+
+/// The name of the built-in function transferring the ownership of a contract.
+pub const CHANGE_OWNER_ADDRESS_FUNCTION: &str = "ChangeOwnerAddress";
+
+/// The gas consumed by the `ClaimDeveloperRewards` built-in function, as set by the protocol's gas schedule.
+pub const CLAIM_DEVELOPER_REWARDS_GAS_COST: u64 = 5_000_000;
+
+/// The gas consumed by the `ChangeOwnerAddress` built-in function, as set by the protocol's gas schedule.
+pub const CHANGE_OWNER_ADDRESS_GAS_COST: u64 = 5_000_000;
+
+/// Returns the gas limit of a call to a built-in function consuming `cost`, with the transaction's `data` field:
+/// the cost of the function added to the cost of a transfer carrying `data` on the network described by `gas_config`.
+pub fn get_builtin_function_gas_limit(gas_config: &TransferGasConfig, data: &str, cost: u64) -> u64 {
+    gas_config.get_egld_transfer_gas_limit(data) + cost
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::transaction::builtin_functions::{CLAIM_DEVELOPER_REWARDS_FUNCTION, CLAIM_DEVELOPER_REWARDS_GAS_COST, get_builtin_function_gas_limit};
+    use crate::utils::transaction::egld_transfer::TransferGasConfig;
+
+    #[test]
+    fn test_get_builtin_function_gas_limit() {
+        let gas_limit = get_builtin_function_gas_limit(&TransferGasConfig::default(), CLAIM_DEVELOPER_REWARDS_FUNCTION, CLAIM_DEVELOPER_REWARDS_GAS_COST);
+
+        assert_eq!(gas_limit, 5_081_500);
+    }
+
+    #[test]
+    fn test_get_builtin_function_gas_limit_guarded() {
+        let gas_config = TransferGasConfig {
+            extra_gas_limit: 50_000,
+            ..TransferGasConfig::default()
+        };
+
+        let gas_limit = get_builtin_function_gas_limit(&gas_config, CLAIM_DEVELOPER_REWARDS_FUNCTION, CLAIM_DEVELOPER_REWARDS_GAS_COST);
+
+        assert_eq!(gas_limit, 5_131_500);
+    }
+}
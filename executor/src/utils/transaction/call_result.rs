@@ -1,4 +1,31 @@
-use crate::TransactionOnNetwork;
+use std::str::FromStr;
+
+use base64::Engine;
+use multiversx_sc::codec::TopDecodeMulti;
+use num_bigint::BigUint;
+use novax_data::{Address, NativeConvertible};
+
+use crate::{ExecutorError, TransactionOnNetwork, TransactionOnNetworkTransactionLogsEvents};
+use crate::error::transaction::TransactionError;
+use crate::utils::transaction::results::{decode_all_smart_contract_results, decode_output, find_smart_contract_result};
+
+/// The identifiers of the events logged for each ESDT transfer.
+const TRANSFER_EVENT_IDENTIFIERS: [&str; 3] = ["ESDTTransfer", "ESDTNFTTransfer", "MultiESDTNFTTransfer"];
+
+/// A token transfer that actually happened during a transaction, as read from the transfer events of its logs.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct ResolvedTransfer {
+    /// The identifier of the transferred token, such as "WEGLD-bd4d79" or "SFT-abcdef".
+    pub token: String,
+    /// The nonce of the transferred token, 0 for fungible tokens.
+    pub nonce: u64,
+    /// The transferred amount, in the smallest denomination.
+    pub amount: BigUint,
+    /// The sender of the tokens.
+    pub from: Address,
+    /// The receiver of the tokens.
+    pub to: Address
+}
 
 /// A structure encapsulating the result of a contract call.
 ///
@@ -19,5 +46,461 @@ pub struct CallResult<T> {
     /// and the contract returned some data. The type `T` is a placeholder for any type that can
     /// be deserialized from the contract's response. It's an `Option<T>` since a contract call
     /// may not always return data.
+    ///
+    /// When executed on a network, a transaction without any smart contract result is decoded from empty data,
+    /// and `TransactionError::NoSmartContractResult` is returned if the output cannot be decoded this way.
+    /// This error holds the executed transaction, so its logs, gas used and fee can still be inspected.
     pub result: Option<T>
-}
\ No newline at end of file
+}
+
+impl<T> CallResult<T> {
+    /// Returns the gas consumed by the transaction, as reported by the gateway.
+    ///
+    /// `None` is returned if the transaction was not executed, such as in dry-run mode, the gas used being zero.
+    pub fn gas_used(&self) -> Option<u64> {
+        let gas_used = self.response.transaction.gas_used;
+
+        (gas_used != 0).then_some(gas_used)
+    }
+
+    /// Returns the fee paid for the transaction, in the smallest EGLD denomination.
+    ///
+    /// `None` is returned if the response has no fee or if it cannot be parsed.
+    pub fn fee(&self) -> Option<BigUint> {
+        let fee = self.response.transaction.fee.as_ref()?;
+
+        BigUint::from_str(fee).ok()
+    }
+
+    /// Decodes the result of the call from the stored response as `OutputManaged`, whatever the type requested when calling.
+    ///
+    /// The result is found as when the call was executed, so a call made with `()` or with the deserialization skipped can be decoded afterwards,
+    /// possibly trying several candidate types. A transaction without any result is decoded from no value at all.
+    pub fn decode_as<OutputManaged>(&self) -> Result<OutputManaged::Native, ExecutorError>
+        where
+            OutputManaged: TopDecodeMulti + NativeConvertible
+    {
+        let raw_result = find_smart_contract_result(
+            &self.response.transaction.hash,
+            &self.response.transaction.sender,
+            &self.response.transaction.smart_contract_results,
+            self.response.transaction.logs.as_ref()
+        )?
+            .map(|sc_result| sc_result.return_data)
+            .unwrap_or_default();
+
+        decode_output::<OutputManaged>(raw_result)
+    }
+
+    /// Returns the decoded arguments of every smart contract result carrying return data, for the calls producing several meaningful results.
+    ///
+    /// Each entry holds the `@`-separated arguments of one result, starting with its result code, such as "ok", which is not checked.
+    /// An argument which is not valid hex is returned as its raw bytes.
+    pub fn raw_results(&self) -> Vec<Vec<Vec<u8>>> {
+        let Some(sc_results) = self.response.transaction.smart_contract_results.as_ref() else {
+            return vec![]
+        };
+
+        decode_all_smart_contract_results(sc_results)
+    }
+
+    /// Returns the first event of the transaction's logs having the given identifier, such as "ESDTTransfer" or a custom contract event.
+    pub fn find_event(&self, identifier: &str) -> Option<&TransactionOnNetworkTransactionLogsEvents> {
+        self.response.transaction.logs
+            .as_ref()?
+            .events
+            .iter()
+            .find(|event| event.identifier == identifier)
+    }
+
+    /// Returns the base64-decoded topics of the first event having the given identifier.
+    ///
+    /// An empty `Vec` is returned if there is no such event.
+    pub fn topics_of(&self, identifier: &str) -> Result<Vec<Vec<u8>>, ExecutorError> {
+        let Some(event) = self.find_event(identifier) else {
+            return Ok(vec![])
+        };
+
+        event.topics
+            .iter()
+            .map(|topic| {
+                base64::engine::general_purpose::STANDARD.decode(topic)
+                    .map_err(|_| TransactionError::CannotDecodeTopic.into())
+            })
+            .collect()
+    }
+
+    /// Returns the ESDT transfers performed during the transaction, read from its "ESDTTransfer", "ESDTNFTTransfer" and "MultiESDTNFTTransfer" events, in order.
+    ///
+    /// This allows reconciling the tokens that actually moved, including the ones sent by the called contract.
+    /// EGLD transfers are not included.
+    pub fn token_transfers(&self) -> Result<Vec<ResolvedTransfer>, ExecutorError> {
+        let Some(logs) = self.response.transaction.logs.as_ref() else {
+            return Ok(vec![])
+        };
+
+        let mut transfers = vec![];
+
+        for event in logs.events.iter().filter(|event| TRANSFER_EVENT_IDENTIFIERS.contains(&event.identifier.as_str())) {
+            transfers.extend(decode_transfer_event(event)?);
+        }
+
+        Ok(transfers)
+    }
+}
+
+/// Decodes a transfer event, whose topics are the identifier, nonce and amount of each transferred token followed by the receiver.
+fn decode_transfer_event(event: &TransactionOnNetworkTransactionLogsEvents) -> Result<Vec<ResolvedTransfer>, ExecutorError> {
+    let topics = event.topics
+        .iter()
+        .map(|topic| {
+            base64::engine::general_purpose::STANDARD.decode(topic)
+                .map_err(|_| ExecutorError::from(TransactionError::CannotDecodeTopic))
+        })
+        .collect::<Result<Vec<Vec<u8>>, ExecutorError>>()?;
+
+    let Some((receiver, token_topics)) = topics.split_last() else {
+        return Err(TransactionError::WrongTopicsCountForTransferEvent { identifier: event.identifier.clone() }.into())
+    };
+
+    if token_topics.is_empty() || token_topics.len() % 3 != 0 {
+        return Err(TransactionError::WrongTopicsCountForTransferEvent { identifier: event.identifier.clone() }.into())
+    }
+
+    let from = Address::from_bech32_string(&event.address)?;
+    let to = Address::from_bytes(
+        receiver.clone()
+            .try_into()
+            .map_err(|_| TransactionError::CannotDecodeTopic)?
+    );
+
+    token_topics
+        .chunks(3)
+        .map(|token_topics| {
+            let token = String::from_utf8(token_topics[0].clone())
+                .map_err(|_| TransactionError::CannotDecodeTopic)?;
+
+            let resolved_transfer = ResolvedTransfer {
+                token,
+                nonce: decode_nonce(&token_topics[1])?,
+                amount: BigUint::from_bytes_be(&token_topics[2]),
+                from: from.clone(),
+                to: to.clone(),
+            };
+
+            Ok(resolved_transfer)
+        })
+        .collect()
+}
+
+fn decode_nonce(bytes: &[u8]) -> Result<u64, ExecutorError> {
+    if bytes.len() > 8 {
+        return Err(TransactionError::CannotDecodeTopic.into())
+    }
+
+    Ok(bytes.iter().fold(0u64, |nonce, byte| (nonce << 8) | *byte as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use multiversx_sc::codec::multi_types::MultiValue2;
+    use multiversx_sc::types::{EsdtTokenPayment, ManagedBuffer, MultiValueEncoded};
+    use multiversx_sc_scenario::api::StaticApi;
+    use num_bigint::BigUint;
+    use novax_data::{Address, Payment};
+
+    use crate::{TransactionOnNetwork, TransactionOnNetworkTransactionLogs, TransactionOnNetworkTransactionLogsEvents, TransactionOnNetworkTransactionSmartContractResult};
+    use crate::call_result::{CallResult, ResolvedTransfer};
+    use crate::error::transaction::TransactionError;
+    use crate::ExecutorError;
+
+    fn get_call_result(events: Vec<TransactionOnNetworkTransactionLogsEvents>) -> CallResult<()> {
+        let mut response = TransactionOnNetwork::default();
+        response.transaction.logs = Some(TransactionOnNetworkTransactionLogs {
+            address: "".to_string(),
+            events,
+        });
+
+        CallResult {
+            response,
+            result: None,
+        }
+    }
+
+    fn get_event(identifier: &str, topics: Vec<&str>) -> TransactionOnNetworkTransactionLogsEvents {
+        TransactionOnNetworkTransactionLogsEvents {
+            address: "".to_string(),
+            identifier: identifier.to_string(),
+            topics: topics.into_iter().map(|topic| topic.to_string()).collect(),
+            data: None,
+        }
+    }
+
+    #[test]
+    fn test_gas_used_and_fee() {
+        let mut response = TransactionOnNetwork::default();
+        response.transaction.gas_used = 318500;
+        response.transaction.fee = Some("318500000000000".to_string());
+
+        let call_result: CallResult<()> = CallResult {
+            response,
+            result: None,
+        };
+
+        assert_eq!(call_result.gas_used(), Some(318500));
+        assert_eq!(call_result.fee(), Some(BigUint::from(318500000000000u64)));
+    }
+
+    #[test]
+    fn test_gas_used_and_fee_not_executed() {
+        let call_result: CallResult<()> = CallResult {
+            response: TransactionOnNetwork::default(),
+            result: None,
+        };
+
+        assert_eq!(call_result.gas_used(), None);
+        assert_eq!(call_result.fee(), None);
+    }
+
+    #[test]
+    fn test_find_event() {
+        let call_result = get_call_result(vec![
+            get_event("writeLog", vec![]),
+            get_event("ESDTTransfer", vec!["V0VHTEQtYWJjZGVm"])
+        ]);
+
+        let event = call_result.find_event("ESDTTransfer").unwrap();
+
+        assert_eq!(event.identifier, "ESDTTransfer");
+        assert!(call_result.find_event("unknown").is_none());
+    }
+
+    #[test]
+    fn test_find_event_no_logs() {
+        let call_result: CallResult<()> = CallResult {
+            response: TransactionOnNetwork::default(),
+            result: None,
+        };
+
+        assert!(call_result.find_event("ESDTTransfer").is_none());
+    }
+
+    #[test]
+    fn test_topics_of() {
+        let call_result = get_call_result(vec![
+            get_event("ESDTTransfer", vec!["V0VHTEQtYWJjZGVm", "", "ZA=="])
+        ]);
+
+        let topics = call_result.topics_of("ESDTTransfer").unwrap();
+
+        let expected = vec![
+            b"WEGLD-abcdef".to_vec(),
+            vec![],
+            vec![100]
+        ];
+
+        assert_eq!(topics, expected);
+        assert_eq!(call_result.topics_of("unknown").unwrap(), Vec::<Vec<u8>>::new());
+    }
+
+    #[test]
+    fn test_topics_of_invalid_base64() {
+        let call_result = get_call_result(vec![
+            get_event("ESDTTransfer", vec!["not base64!"])
+        ]);
+
+        let result = call_result.topics_of("ESDTTransfer");
+
+        let expected: Result<Vec<Vec<u8>>, ExecutorError> = Err(TransactionError::CannotDecodeTopic.into());
+
+        assert_eq!(result, expected);
+    }
+
+    const SENDER: &str = "erd1x39tc3q3nn72ecjnmcz7x0qp09kp97t080x99dgyhx7zh95j0n4szskhlv";
+    const RECEIVER: &str = "erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p";
+    const RECEIVER_TOPIC: &str = "AAAAAAAAAAAFACu3Z9clem18cFODwoduMYNWYWY10wM=";
+
+    fn get_transfer_event(identifier: &str, topics: Vec<&str>) -> TransactionOnNetworkTransactionLogsEvents {
+        TransactionOnNetworkTransactionLogsEvents {
+            address: SENDER.to_string(),
+            ..get_event(identifier, topics)
+        }
+    }
+
+    fn get_resolved_transfer(token: &str, nonce: u64, amount: u64) -> ResolvedTransfer {
+        ResolvedTransfer {
+            token: token.to_string(),
+            nonce,
+            amount: BigUint::from(amount),
+            from: Address::from_bech32_string(SENDER).unwrap(),
+            to: Address::from_bech32_string(RECEIVER).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_token_transfers() {
+        let call_result = get_call_result(vec![
+            get_transfer_event("ESDTTransfer", vec!["V0VHTEQtYWJjZGVm", "", "A+g=", RECEIVER_TOPIC]),
+            get_event("writeLog", vec![]),
+            get_transfer_event("ESDTNFTTransfer", vec!["U0ZULWFiY2RlZg==", "AQ==", "ZA==", RECEIVER_TOPIC])
+        ]);
+
+        let expected = vec![
+            get_resolved_transfer("WEGLD-abcdef", 0, 1000),
+            get_resolved_transfer("SFT-abcdef", 1, 100)
+        ];
+
+        assert_eq!(call_result.token_transfers().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_token_transfers_multi_transfer() {
+        let call_result = get_call_result(vec![
+            get_transfer_event("MultiESDTNFTTransfer", vec!["U0ZULWFiY2RlZg==", "AQ==", "ZA==", "VVNEQy0xMjM0NTY=", "", "TEtA", RECEIVER_TOPIC])
+        ]);
+
+        let expected = vec![
+            get_resolved_transfer("SFT-abcdef", 1, 100),
+            get_resolved_transfer("USDC-123456", 0, 5000000)
+        ];
+
+        assert_eq!(call_result.token_transfers().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_token_transfers_no_logs() {
+        let call_result: CallResult<()> = CallResult {
+            response: TransactionOnNetwork::default(),
+            result: None,
+        };
+
+        assert_eq!(call_result.token_transfers().unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_token_transfers_wrong_topics_count() {
+        let call_result = get_call_result(vec![
+            get_transfer_event("ESDTTransfer", vec!["V0VHTEQtYWJjZGVm", "", RECEIVER_TOPIC])
+        ]);
+
+        let expected: Result<Vec<ResolvedTransfer>, ExecutorError> = Err(TransactionError::WrongTopicsCountForTransferEvent { identifier: "ESDTTransfer".to_string() }.into());
+
+        assert_eq!(call_result.token_transfers(), expected);
+    }
+
+    #[test]
+    fn test_token_transfers_invalid_receiver() {
+        let call_result = get_call_result(vec![
+            get_transfer_event("ESDTTransfer", vec!["V0VHTEQtYWJjZGVm", "", "A+g=", "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=="])
+        ]);
+
+        let expected: Result<Vec<ResolvedTransfer>, ExecutorError> = Err(TransactionError::CannotDecodeTopic.into());
+
+        assert_eq!(call_result.token_transfers(), expected);
+    }
+
+    fn get_call_result_with_sc_result(data: &str) -> CallResult<()> {
+        let mut response = TransactionOnNetwork::default();
+        response.transaction.hash = "tx_hash".to_string();
+        response.transaction.sender = "sender".to_string();
+        response.transaction.smart_contract_results = Some(vec![
+            TransactionOnNetworkTransactionSmartContractResult {
+                hash: "sc_result_hash".to_string(),
+                nonce: 1,
+                data: data.to_string(),
+                sender: "contract".to_string(),
+                receiver: "sender".to_string(),
+                prev_tx_hash: "tx_hash".to_string(),
+            }
+        ]);
+
+        CallResult {
+            response,
+            result: None,
+        }
+    }
+
+    #[test]
+    fn test_decode_as() {
+        let call_result = get_call_result_with_sc_result("@6f6b@05");
+
+        assert_eq!(call_result.decode_as::<u64>(), Ok(5));
+        assert_eq!(call_result.decode_as::<multiversx_sc::types::BigUint<StaticApi>>(), Ok(BigUint::from(5u8)));
+    }
+
+    #[test]
+    fn test_decode_as_managed_buffer() {
+        let call_result = get_call_result_with_sc_result("@6f6b@74657374");
+
+        assert_eq!(call_result.decode_as::<ManagedBuffer<StaticApi>>(), Ok("test".to_string()));
+    }
+
+    #[test]
+    fn test_decode_as_no_result() {
+        let call_result: CallResult<()> = CallResult {
+            response: TransactionOnNetwork::default(),
+            result: None,
+        };
+
+        assert_eq!(call_result.decode_as::<()>(), Ok(()));
+    }
+
+    #[test]
+    fn test_decode_as_wrong_type() {
+        let call_result = get_call_result_with_sc_result("@6f6b@0102");
+
+        let result = call_result.decode_as::<u8>();
+
+        assert!(matches!(
+            result,
+            Err(ExecutorError::Transaction(TransactionError::CannotDecodeSmartContractResult { raw, .. })) if raw == vec![vec![1, 2]]
+        ));
+    }
+
+    #[test]
+    fn test_decode_as_multi_value_encoded() {
+        let call_result = get_call_result_with_sc_result("@6f6b@0000000c5745474c442d61626364656600000000000000000000000164@0000000b555344432d31323334353600000000000000000000000203e8");
+
+        let expected = vec![
+            Payment {
+                token_identifier: "WEGLD-abcdef".to_string(),
+                token_nonce: 0,
+                amount: BigUint::from(100u8)
+            },
+            Payment {
+                token_identifier: "USDC-123456".to_string(),
+                token_nonce: 0,
+                amount: BigUint::from(1000u16)
+            }
+        ];
+
+        assert_eq!(call_result.decode_as::<MultiValueEncoded<StaticApi, EsdtTokenPayment<StaticApi>>>(), Ok(expected));
+    }
+
+    #[test]
+    fn test_decode_as_multi_value_encoded_of_multi_values() {
+        let call_result = get_call_result_with_sc_result("@6f6b@01@0a@02@14");
+
+        assert_eq!(call_result.decode_as::<MultiValueEncoded<StaticApi, MultiValue2<u8, u64>>>(), Ok(vec![(1, 10), (2, 20)]));
+    }
+
+    #[test]
+    fn test_decode_as_multi_value_encoded_empty() {
+        let call_result = get_call_result_with_sc_result("@6f6b");
+
+        assert_eq!(call_result.decode_as::<MultiValueEncoded<StaticApi, u64>>(), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_decode_as_multi_value_encoded_invalid_item() {
+        let call_result = get_call_result_with_sc_result("@6f6b@01@000000000000000000");
+
+        let result = call_result.decode_as::<MultiValueEncoded<StaticApi, u64>>();
+
+        assert!(matches!(
+            result,
+            Err(ExecutorError::Transaction(TransactionError::CannotDecodeSmartContractResult { raw, reason }))
+                if raw == vec![vec![1], vec![0; 9]] && reason.contains("input too long")
+        ));
+    }
+}
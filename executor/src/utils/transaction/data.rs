@@ -9,15 +9,21 @@ use serde::{de, Deserialize, Serialize, Serializer};
 ///
 /// This structure contains the necessary information for the frontend to send a transaction
 /// once the user has their wallet connected.
-#[derive(Deserialize, Serialize, PartialEq, Clone, Debug)]
+///
+/// It serializes using the field names of the MultiversX transaction JSON schema: `receiver`, `value`, `gasLimit` and `data`.
+/// The other fields of the schema, such as the nonce, the sender or the chain ID, are left to the wallet sending the transaction.
+/// The former `egld_value` and `gas_limit` field names are still accepted when deserializing.
+#[derive(Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Debug)]
 pub struct SendableTransaction {
     /// The receiver's address as a string.
     pub receiver: String,
     /// The amount of EGLD to be sent along with the transaction.
+    #[serde(rename = "value", alias = "egld_value")]
     #[serde(serialize_with = "biguint_serialize")]
     #[serde(deserialize_with = "biguint_deserialize")]
     pub egld_value: BigUint,
     /// The gas limit for the transaction.
+    #[serde(rename = "gasLimit", alias = "gas_limit")]
     pub gas_limit: u64,
     /// The data payload for the transaction as a string.
     pub data: String
@@ -84,6 +90,8 @@ where
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+
     use num_bigint::BigUint;
 
     use crate::SendableTransaction;
@@ -98,7 +106,7 @@ mod tests {
         };
 
         let result = serde_json::to_string(&tx).unwrap();
-        let expected = r#"{"receiver":"erd1qqqqqqqqqqqqqpgq74myhunu4sfdpmskm6s6ul8k4cetjvhhlfpsaa20la","egld_value":"1000000000000000000","gas_limit":600000000,"data":"@6f6b@000000080de0b6b3a7640000000000081bc16d674ec80000"}"#;
+        let expected = r#"{"receiver":"erd1qqqqqqqqqqqqqpgq74myhunu4sfdpmskm6s6ul8k4cetjvhhlfpsaa20la","value":"1000000000000000000","gasLimit":600000000,"data":"@6f6b@000000080de0b6b3a7640000000000081bc16d674ec80000"}"#;
 
         assert_eq!(result, expected);
     }
@@ -117,4 +125,35 @@ mod tests {
 
         assert_eq!(result, expected);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_deserialize_sendable_transaction_multiversx_field_names() {
+        let json = r#"{"receiver":"erd1qqqqqqqqqqqqqpgq74myhunu4sfdpmskm6s6ul8k4cetjvhhlfpsaa20la","value":"1000000000000000000","gasLimit":600000000,"data":"getSum"}"#;
+
+        let result: SendableTransaction = serde_json::from_str(json).unwrap();
+        let expected = SendableTransaction {
+            receiver: "erd1qqqqqqqqqqqqqpgq74myhunu4sfdpmskm6s6ul8k4cetjvhhlfpsaa20la".to_string(),
+            egld_value: BigUint::from(10u8).pow(18),
+            gas_limit: 600000000,
+            data: "getSum".to_string(),
+        };
+
+        assert_eq!(result, expected);
+        assert_eq!(serde_json::to_string(&result).unwrap(), json);
+    }
+
+    #[test]
+    fn test_dedupe_sendable_transactions() {
+        let get_transaction = |gas_limit: u64| SendableTransaction {
+            receiver: "erd1qqqqqqqqqqqqqpgq74myhunu4sfdpmskm6s6ul8k4cetjvhhlfpsaa20la".to_string(),
+            egld_value: BigUint::from(10u8).pow(18),
+            gas_limit,
+            data: "getSum".to_string(),
+        };
+
+        let transactions = vec![get_transaction(600000000), get_transaction(600000000), get_transaction(5000000)];
+        let unique_transactions: HashSet<SendableTransaction> = transactions.into_iter().collect();
+
+        assert_eq!(unique_transactions.len(), 2);
+    }
+}
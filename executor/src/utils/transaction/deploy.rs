@@ -21,13 +21,10 @@ pub fn get_deploy_call_input(
     mut arguments: Vec<Vec<u8>>,
     gas_limit: u64
 ) -> DeployCallInput {
-    let mut encoded_metadata: ManagedBuffer<StaticApi> = ManagedBuffer::new();
-    code_metadata.top_encode(&mut encoded_metadata).unwrap();
-
     let built_in_arguments: Vec<Vec<u8>> = vec![
         bytes,
         vec![5, 0], // VM type: WASM
-        encoded_metadata.to_boxed_bytes().into_vec()
+        encode_code_metadata(code_metadata)
     ];
 
     let mut all_arguments = built_in_arguments;
@@ -41,4 +38,37 @@ pub fn get_deploy_call_input(
         egld_value,
         esdt_transfers: vec![],
     }
-}
\ No newline at end of file
+}
+
+pub fn get_upgrade_call_input(
+    contract_address: &Address,
+    bytes: Vec<u8>,
+    code_metadata: CodeMetadata,
+    egld_value: BigUint,
+    mut arguments: Vec<Vec<u8>>,
+    gas_limit: u64
+) -> DeployCallInput {
+    let built_in_arguments: Vec<Vec<u8>> = vec![
+        bytes,
+        encode_code_metadata(code_metadata)
+    ];
+
+    let mut all_arguments = built_in_arguments;
+    all_arguments.append(&mut arguments);
+
+    DeployCallInput {
+        to: contract_address.clone(),
+        function: "upgradeContract".to_string(),
+        arguments: all_arguments,
+        gas_limit,
+        egld_value,
+        esdt_transfers: vec![],
+    }
+}
+
+fn encode_code_metadata(code_metadata: CodeMetadata) -> Vec<u8> {
+    let mut encoded_metadata: ManagedBuffer<StaticApi> = ManagedBuffer::new();
+    code_metadata.top_encode(&mut encoded_metadata).unwrap();
+
+    encoded_metadata.to_boxed_bytes().into_vec()
+}
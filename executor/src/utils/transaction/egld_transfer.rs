@@ -0,0 +1,87 @@
+use crate::network::models::network::config::NetworkGatewayConfigData;
+
+/// The gas parameters of a network needed to compute the exact gas limit of a transaction which doesn't execute any code,
+/// such as a plain EGLD transfer.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TransferGasConfig {
+    /// The gas limit of a transaction without data, the network's `erd_min_gas_limit`.
+    pub min_gas_limit: u64,
+    /// The gas consumed by each byte of a transaction's data field, the network's `erd_gas_per_data_byte`.
+    pub gas_per_data_byte: u64,
+    /// The extra gas consumed by each transaction of the sender, such as the network's `erd_extra_gas_limit_guarded_tx` for a guarded account.
+    pub extra_gas_limit: u64
+}
+
+/// The mainnet's gas parameters, used by the executors which don't know the network's configuration.
+impl Default for TransferGasConfig {
+    fn default() -> Self {
+        Self {
+            min_gas_limit: 50_000,
+            gas_per_data_byte: 1_500,
+            extra_gas_limit: 0
+        }
+    }
+}
+
+impl TransferGasConfig {
+    /// Returns the gas parameters of a network, adding the extra gas of guarded transactions if the sender is `guarded`.
+    pub fn from_network_config(config: &NetworkGatewayConfigData, guarded: bool) -> Self {
+        Self {
+            min_gas_limit: config.erd_min_gas_limit,
+            gas_per_data_byte: config.erd_gas_per_data_byte,
+            extra_gas_limit: if guarded { config.erd_extra_gas_limit_guarded_tx } else { 0 }
+        }
+    }
+
+    /// Returns the gas consumed by a transaction's `data` field, excluding the base cost of the transaction.
+    pub fn get_data_gas_cost(&self, data: &str) -> u64 {
+        self.gas_per_data_byte * data.len() as u64
+    }
+
+    /// Returns the exact gas limit of a plain EGLD transfer carrying `data`, which doesn't call any function.
+    pub fn get_egld_transfer_gas_limit(&self, data: &str) -> u64 {
+        self.min_gas_limit + self.get_data_gas_cost(data) + self.extra_gas_limit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::network::models::network::config::NetworkGatewayConfigData;
+    use crate::utils::transaction::egld_transfer::TransferGasConfig;
+
+    fn get_network_config() -> NetworkGatewayConfigData {
+        NetworkGatewayConfigData {
+            erd_chain_id: "D".to_string(),
+            erd_min_gas_price: 1000000000,
+            erd_min_gas_limit: 70_000,
+            erd_gas_per_data_byte: 2_000,
+            erd_min_transaction_version: 1,
+            erd_num_shards_without_meta: 3,
+            erd_extra_gas_limit_guarded_tx: 50_000,
+        }
+    }
+
+    #[test]
+    fn test_get_egld_transfer_gas_limit_without_data() {
+        assert_eq!(TransferGasConfig::default().get_egld_transfer_gas_limit(""), 50_000);
+    }
+
+    #[test]
+    fn test_get_egld_transfer_gas_limit_with_data() {
+        assert_eq!(TransferGasConfig::default().get_egld_transfer_gas_limit("thanks!"), 60_500);
+    }
+
+    #[test]
+    fn test_get_egld_transfer_gas_limit_from_network_config() {
+        let gas_config = TransferGasConfig::from_network_config(&get_network_config(), false);
+
+        assert_eq!(gas_config.get_egld_transfer_gas_limit("thanks!"), 84_000);
+    }
+
+    #[test]
+    fn test_get_egld_transfer_gas_limit_guarded() {
+        let gas_config = TransferGasConfig::from_network_config(&get_network_config(), true);
+
+        assert_eq!(gas_config.get_egld_transfer_gas_limit("thanks!"), 134_000);
+    }
+}
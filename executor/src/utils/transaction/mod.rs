@@ -4,4 +4,8 @@ pub mod token_transfer;
 pub mod transfers;
 pub mod normalization;
 pub mod deploy;
-pub mod results;
\ No newline at end of file
+pub mod results;
+pub mod batch;
+pub mod receiver;
+pub mod egld_transfer;
+pub mod builtin_functions;
\ No newline at end of file
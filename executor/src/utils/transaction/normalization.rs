@@ -3,13 +3,36 @@ use novax_data::Address;
 use crate::{ExecutorError, SendableTransaction, TokenTransfer};
 use crate::error::transaction::TransactionError;
 
+/// A contract call, before or after being normalized into the transaction actually sent to the blockchain.
+///
+/// This is the same path used by the executors, allowing to build the exact data payload of a transaction
+/// without sending it, for example to sign it offline:
+///
+/// ```ignore
+/// let data = NormalizationInOut {
+///     sender: "erd1...".to_string(),
+///     receiver: "erd1qqqqqqqqqqqqqpgq...".to_string(),
+///     function_name: Some("myEndpoint".to_string()),
+///     arguments: vec![vec![1, 2]],
+///     egld_value: BigUint::from(0u8),
+///     esdt_transfers: vec![TokenTransfer::fungible("WEGLD-abcdef", BigUint::from(10u8))?],
+/// }
+///     .normalize()?
+///     .get_transaction_data();
+/// ```
 #[derive(Clone, PartialEq, Debug)]
 pub struct NormalizationInOut {
+    /// The bech32 address of the account sending the transaction.
     pub sender: String,
+    /// The bech32 address of the transaction's receiver. Once normalized, it may be the sender itself for ESDT transfers.
     pub receiver: String,
+    /// The name of the function to call, if any. Once normalized, it may be a built-in function such as `ESDTTransfer`.
     pub function_name: Option<String>,
+    /// The encoded arguments of the call.
     pub arguments: Vec<Vec<u8>>,
+    /// The amount of EGLD sent along with the call.
     pub egld_value: BigUint,
+    /// The ESDTs sent along with the call. Once normalized, they are encoded in the arguments and this field is empty.
     pub esdt_transfers: Vec<TokenTransfer>
 }
 
@@ -20,8 +43,18 @@ struct TokenPaymentBytes {
 }
 
 impl NormalizationInOut {
+    /// Rewrites the transaction into the form expected by the protocol when ESDTs are transferred.
+    ///
+    /// - A single fungible ESDT uses `ESDTTransfer`, keeping the receiver.
+    /// - A single non-fungible, semi-fungible or meta ESDT uses `ESDTNFTTransfer`, with the receiver set to the sender.
+    /// - Multiple ESDTs use `MultiESDTNFTTransfer`, with the receiver set to the sender and the real destination as first argument.
+    /// - Transfers including the `EGLD-000000` pseudo-token always use `MultiESDTNFTTransfer`, even if there is a single one.
+    ///
+    /// Fails with `TransactionError::EgldAndEsdtPaymentsDetected` if both `egld_value` and ESDTs are sent, as the protocol disallows it.
+    /// To send EGLD along with ESDTs, add a `TokenTransfer::egld` transfer instead.
     pub fn normalize(mut self) -> Result<NormalizationInOut, ExecutorError> {
         let esdt_transfers_len = self.esdt_transfers.len();
+        let has_egld_transfer = self.esdt_transfers.iter().any(TokenTransfer::is_egld);
 
         if esdt_transfers_len > 0 && self.egld_value > BigUint::from(0u8) {
             return Err(TransactionError::EgldAndEsdtPaymentsDetected.into());
@@ -29,7 +62,7 @@ impl NormalizationInOut {
 
         let result = if esdt_transfers_len == 0 {
             self
-        } else if esdt_transfers_len == 1 {
+        } else if esdt_transfers_len == 1 && !has_egld_transfer {
             let transfer = self.esdt_transfers.remove(0);
             let is_fungible = transfer.nonce == 0;
             let encoded_token_payment = encode_transfer(transfer)?;
@@ -109,6 +142,9 @@ impl NormalizationInOut {
         Ok(result)
     }
 
+    /// Returns the transaction's data field: the function name followed by the hex-encoded arguments, separated by `@`.
+    ///
+    /// This should be called on a normalized value, otherwise the ESDT transfers are ignored.
     pub fn get_transaction_data(self) -> String {
         let mut args_string = vec![];
 
@@ -123,6 +159,7 @@ impl NormalizationInOut {
         args_string.join("@")
     }
 
+    /// Converts a normalized value into a `SendableTransaction` with the given gas limit.
     pub fn into_sendable_transaction(self, gas_limit: u64) -> SendableTransaction {
         SendableTransaction {
             receiver: self.receiver.clone(),
@@ -184,6 +221,8 @@ mod tests {
     const FUNGIBLE_NAME: &str = "WEGLD-abcdef";
     const FUNGIBLE_NAME_HEX: &str = "5745474c442d616263646566";
 
+    const EGLD_NAME_HEX: &str = "45474c442d303030303030";
+
     const NON_FUNGIBLE_NAME: &str = "SFT-abcdef";
     const NON_FUNGIBLE_NAME_HEX: &str = "5346542d616263646566";
 
@@ -358,6 +397,41 @@ mod tests {
         assert_eq!(result_data, expected_data)
     }
 
+    #[test]
+    fn test_normalize_single_non_fungible_payment_no_function() {
+        let value = NormalizationInOut {
+            sender: SENDER.to_string(),
+            receiver: RECEIVER.to_string(),
+            function_name: None,
+            arguments: vec![],
+            egld_value: BigUint::from(0u8),
+            esdt_transfers: vec![
+                TokenTransfer::non_fungible(NON_FUNGIBLE_NAME, 258, BigUint::from(1u8)).unwrap()
+            ]
+        };
+
+        let result = value.normalize().unwrap();
+        let result_data = result.clone().get_transaction_data();
+
+        let expected = NormalizationInOut {
+            sender: SENDER.to_string(),
+            receiver: SENDER.to_string(),
+            function_name: Some("ESDTNFTTransfer".to_string()),
+            arguments: vec![
+                hex::decode(NON_FUNGIBLE_NAME_HEX).unwrap(),
+                vec![1, 2],
+                vec![1],
+                hex::decode(RECEIVER_HEX).unwrap()
+            ],
+            egld_value: BigUint::from(0u8),
+            esdt_transfers: vec![]
+        };
+        let expected_data = format!("ESDTNFTTransfer@{NON_FUNGIBLE_NAME_HEX}@0102@01@{RECEIVER_HEX}");
+
+        assert_eq!(result, expected);
+        assert_eq!(result_data, expected_data)
+    }
+
     #[test]
     fn test_normalize_single_non_fungible_payment_with_args() {
         let value = NormalizationInOut {
@@ -509,6 +583,76 @@ mod tests {
         assert_eq!(result_data, expected_data);
     }
 
+    #[test]
+    fn test_normalize_multi_fungible_payments() {
+        let value = NormalizationInOut {
+            sender: SENDER.to_string(),
+            receiver: RECEIVER.to_string(),
+            function_name: Some(ENDPOINT_NAME.to_string()),
+            arguments: vec![],
+            egld_value: BigUint::from(0u8),
+            esdt_transfers: vec![
+                TokenTransfer::fungible(FUNGIBLE_NAME, BigUint::from(10u16)).unwrap(),
+                TokenTransfer::fungible(FUNGIBLE_NAME, BigUint::from(1000u16)).unwrap()
+            ]
+        };
+
+        let result = value.normalize().unwrap();
+        let result_data = result.clone().get_transaction_data();
+
+        let expected_data = format!("MultiESDTNFTTransfer@{RECEIVER_HEX}@02@{FUNGIBLE_NAME_HEX}@@0a@{FUNGIBLE_NAME_HEX}@@03e8@{ENDPOINT_NAME_HEX}");
+
+        assert_eq!(result.receiver, SENDER);
+        assert_eq!(result.egld_value, BigUint::from(0u8));
+        assert_eq!(result_data, expected_data);
+    }
+
+    #[test]
+    fn test_normalize_single_egld_transfer() {
+        let value = NormalizationInOut {
+            sender: SENDER.to_string(),
+            receiver: RECEIVER.to_string(),
+            function_name: Some(ENDPOINT_NAME.to_string()),
+            arguments: vec![],
+            egld_value: BigUint::from(0u8),
+            esdt_transfers: vec![
+                TokenTransfer::egld(BigUint::from(10u8))
+            ]
+        };
+
+        let result = value.normalize().unwrap();
+        let result_data = result.clone().get_transaction_data();
+
+        let expected_data = format!("MultiESDTNFTTransfer@{RECEIVER_HEX}@01@{EGLD_NAME_HEX}@@0a@{ENDPOINT_NAME_HEX}");
+
+        assert_eq!(result.receiver, SENDER);
+        assert_eq!(result_data, expected_data);
+    }
+
+    #[test]
+    fn test_normalize_egld_and_esdt_transfers() {
+        let value = NormalizationInOut {
+            sender: SENDER.to_string(),
+            receiver: RECEIVER.to_string(),
+            function_name: Some(ENDPOINT_NAME.to_string()),
+            arguments: vec![],
+            egld_value: BigUint::from(0u8),
+            esdt_transfers: vec![
+                TokenTransfer::egld(BigUint::from(10u8)),
+                TokenTransfer::fungible(FUNGIBLE_NAME, BigUint::from(100u8)).unwrap()
+            ]
+        };
+
+        let result = value.normalize().unwrap();
+        let result_data = result.clone().get_transaction_data();
+
+        let expected_data = format!("MultiESDTNFTTransfer@{RECEIVER_HEX}@02@{EGLD_NAME_HEX}@@0a@{FUNGIBLE_NAME_HEX}@@64@{ENDPOINT_NAME_HEX}");
+
+        assert_eq!(result.receiver, SENDER);
+        assert_eq!(result.egld_value, BigUint::from(0u8));
+        assert_eq!(result_data, expected_data);
+    }
+
     #[test]
     fn test_normalize_esdt_and_egld_payment() {
         let value = NormalizationInOut {
@@ -532,4 +676,25 @@ mod tests {
 
         assert_eq!(result, expected)
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_normalize_multi_esdt_and_egld_payment() {
+        let value = NormalizationInOut {
+            sender: SENDER.to_string(),
+            receiver: RECEIVER.to_string(),
+            function_name: Some(ENDPOINT_NAME.to_string()),
+            arguments: vec![],
+            egld_value: BigUint::from(1u8),
+            esdt_transfers: vec![
+                TokenTransfer::fungible(FUNGIBLE_NAME, BigUint::from(100u8)).unwrap(),
+                TokenTransfer::non_fungible(NON_FUNGIBLE_NAME, 1, BigUint::from(1u8)).unwrap()
+            ]
+        };
+
+        let result = value.normalize();
+
+        let expected: Result<NormalizationInOut, ExecutorError> = Err(TransactionError::EgldAndEsdtPaymentsDetected.into());
+
+        assert_eq!(result, expected)
+    }
+}
@@ -0,0 +1,63 @@
+use novax_data::Address;
+
+use crate::ExecutorError;
+
+/// A trait for the types that can be used as the receiver of a transaction, such as an `Address` or a bech32 string.
+///
+/// It allows calling `TransactionExecutor::sc_call_to` without parsing the bech32 string into an `Address` first.
+pub trait ToReceiver {
+    /// Converts the current instance into the `Address` of the receiver.
+    fn to_receiver(&self) -> Result<Address, ExecutorError>;
+}
+
+impl ToReceiver for Address {
+    fn to_receiver(&self) -> Result<Address, ExecutorError> {
+        Ok(self.clone())
+    }
+}
+
+impl ToReceiver for str {
+    fn to_receiver(&self) -> Result<Address, ExecutorError> {
+        Ok(Address::from_bech32_string(self)?)
+    }
+}
+
+impl ToReceiver for String {
+    fn to_receiver(&self) -> Result<Address, ExecutorError> {
+        self.as_str().to_receiver()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use novax_data::{Address, AddressError, DataError};
+
+    use crate::ExecutorError;
+    use crate::utils::transaction::receiver::ToReceiver;
+
+    const BECH32_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgqtqfhy99su9xzjjrq59kpzpp25udtc9eq0n4sr90ax6";
+
+    #[test]
+    fn test_to_receiver_address() {
+        let address = Address::from_bech32_string(BECH32_ADDRESS).unwrap();
+
+        assert_eq!(address.to_receiver().unwrap(), address);
+    }
+
+    #[test]
+    fn test_to_receiver_bech32_string() {
+        let expected = Address::from_bech32_string(BECH32_ADDRESS).unwrap();
+
+        assert_eq!(BECH32_ADDRESS.to_receiver().unwrap(), expected);
+        assert_eq!(BECH32_ADDRESS.to_string().to_receiver().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_to_receiver_invalid_bech32_string() {
+        let result = "not an address".to_receiver();
+
+        let expected: Result<Address, ExecutorError> = Err(DataError::Address(AddressError::InvalidBech32String { invalid_value: "not an address".to_string() }).into());
+
+        assert_eq!(result, expected);
+    }
+}
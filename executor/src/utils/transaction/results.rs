@@ -1,41 +1,117 @@
+use std::borrow::Cow;
+
 use base64::Engine;
-use multiversx_sc_scenario::scenario_model::decode_scr_data_or_panic;
-use multiversx_sdk::utils::base64_decode;
+use multiversx_sc::codec::TopDecodeMulti;
+use novax_data::NativeConvertible;
 
-use crate::{ExecutorError, TransactionOnNetworkTransactionLogs, TransactionOnNetworkTransactionLogsEvents, TransactionOnNetworkTransactionSmartContractResult};
+use crate::{ExecutorError, TransactionOnNetworkTransactionLogs, TransactionOnNetworkTransactionSmartContractResult};
+#[cfg(feature = "network")]
+use crate::TransactionOnNetworkTransactionLogsEvents;
 use crate::error::transaction::TransactionError;
 
+const OK_RESULT_CODE: &str = "6f6b";
+
+/// The error raised by a smart contract, found in the `signalError` event of a transaction.
+///
+/// Its fields are only read by the network executor, when reporting the error.
+#[cfg_attr(not(feature = "network"), allow(dead_code))]
 #[derive(Clone, Debug)]
 pub(crate) struct SmartContractError {
     pub status: u64,
     pub message: String
 }
 
+/// The data returned by a smart contract, as found by `find_smart_contract_result`.
+#[derive(PartialEq, Clone, Debug)]
+pub(crate) struct SmartContractResultData {
+    /// The decoded result code, such as "ok".
+    pub return_code: String,
+    /// The decoded arguments following the result code.
+    pub return_data: Vec<Vec<u8>>,
+    /// The hash of the SCR carrying the data, or the transaction's hash if the data was read from its `writeLog` events.
+    pub scr_hash: String
+}
+
+/// Decodes the output of a call from its raw arguments, then converts it to its native type.
+///
+/// On failure, the returned error holds the raw arguments and the codec's message, to help finding mismatches between the bindings and the contract.
+pub(crate) fn decode_output<OutputManaged>(raw: Vec<Vec<u8>>) -> Result<OutputManaged::Native, ExecutorError>
+    where
+        OutputManaged: TopDecodeMulti + NativeConvertible
+{
+    let output_managed = match OutputManaged::multi_decode(&mut raw.clone()) {
+        Ok(output_managed) => output_managed,
+        Err(error) => return Err(get_decode_output_error(raw, error.message_str()))
+    };
+
+    // Variadic outputs, such as `MultiValueEncoded`, only store their raw arguments when decoded: each item is decoded while converting to native.
+    // The conversion is fallible, so an invalid item returns the same error as above instead of making the managed API panic.
+    output_managed.try_to_native()
+        .map_err(|error| get_decode_output_error(raw, error.message_str()))
+}
+
+fn get_decode_output_error(raw: Vec<Vec<u8>>, reason: &str) -> ExecutorError {
+    TransactionError::CannotDecodeSmartContractResult {
+        raw,
+        reason: reason.to_string()
+    }.into()
+}
+
+#[cfg(feature = "network")]
 pub(crate) fn find_sc_deploy_event(logs: &[TransactionOnNetworkTransactionLogsEvents]) -> Option<TransactionOnNetworkTransactionLogsEvents> {
     logs.iter()
         .find(|event| event.identifier == "SCDeploy")
         .cloned()
 }
 
+/// Finds and decodes the data returned by the smart contract called by the transaction `tx_hash`, sent by `sender`.
+///
+/// The direct reply to the transaction, an SCR whose previous transaction is `tx_hash` and whose receiver is `sender`, is preferred.
+/// If there is no such SCR, or if `tx_hash` is empty, the first SCR with a non-zero nonce carrying return data is used.
+/// Finally, the `writeLog` events are inspected.
+///
+/// A result code other than "ok" is returned as `TransactionError::SmartContractExecutionFailed`.
 pub(crate) fn find_smart_contract_result(
+    tx_hash: &str,
+    sender: &str,
     opt_sc_results: &Option<Vec<TransactionOnNetworkTransactionSmartContractResult>>,
     opt_logs: Option<&TransactionOnNetworkTransactionLogs>
-) -> Result<Option<Vec<Vec<u8>>>, ExecutorError> {
+) -> Result<Option<SmartContractResultData>, ExecutorError> {
     let mut result = if let Some(sc_results) = opt_sc_results {
-        find_smart_contract_result_from_regular_sc_results(sc_results)?
+        find_smart_contract_result_from_regular_sc_results(tx_hash, sender, sc_results)?
     } else {
         None
     };
 
     if result.is_none() {
         if let Some(logs) = opt_logs {
-            result = find_smart_contract_result_from_logs(logs)?;
+            result = find_smart_contract_result_from_logs(tx_hash, logs)?;
         }
     }
 
     Ok(result)
 }
 
+/// Decodes the `@`-separated arguments of every SCR carrying return data, in the order of the transaction's SCRs.
+///
+/// Unlike `find_smart_contract_result`, the result code is not checked and is kept as the first argument.
+/// An argument which is not valid hex is kept as its raw bytes.
+pub(crate) fn decode_all_smart_contract_results(
+    sc_results: &[TransactionOnNetworkTransactionSmartContractResult]
+) -> Vec<Vec<Vec<u8>>> {
+    sc_results.iter()
+        .map(|sc_result| normalize_scr_data(&sc_result.data))
+        .filter_map(|data| {
+            data.strip_prefix('@').map(|data| data.to_string())
+        })
+        .map(|data| {
+            data.split('@')
+                .map(|encoded_arg| hex::decode(encoded_arg).unwrap_or_else(|_| encoded_arg.as_bytes().to_vec()))
+                .collect()
+        })
+        .collect()
+}
+
 pub(crate) fn find_sc_error(logs: &TransactionOnNetworkTransactionLogs) -> Result<Option<SmartContractError>, ExecutorError> {
     let opt_signal_error_event = logs.events
         .iter()
@@ -60,60 +136,133 @@ pub(crate) fn find_sc_error(logs: &TransactionOnNetworkTransactionLogs) -> Resul
 }
 
 fn find_smart_contract_result_from_regular_sc_results(
+    tx_hash: &str,
+    sender: &str,
     sc_results: &[TransactionOnNetworkTransactionSmartContractResult]
-) -> Result<Option<Vec<Vec<u8>>>, ExecutorError> {
-    let scr_found_result = sc_results.iter()
-        .find(|sc_result| sc_result.nonce != 0 && sc_result.data.starts_with('@'))
+) -> Result<Option<SmartContractResultData>, ExecutorError> {
+    let direct_reply = if tx_hash.is_empty() {
+        None
+    } else {
+        sc_results.iter()
+            .find(|sc_result| {
+                sc_result.prev_tx_hash == tx_hash
+                    && sc_result.receiver == sender
+                    && normalize_scr_data(&sc_result.data).starts_with('@')
+            })
+    };
+
+    let scr_found_result = direct_reply
+        .or_else(|| {
+            sc_results.iter()
+                .find(|sc_result| sc_result.nonce != 0 && normalize_scr_data(&sc_result.data).starts_with('@'))
+        })
         .cloned();
 
     let data = if let Some(scr) = scr_found_result {
-        let mut split = scr.data.split('@');
-        if split.next().is_none() {
-            return Err(TransactionError::CannotDecodeSmartContractResult.into())
+        Some(decode_scr_data(&normalize_scr_data(&scr.data), scr.hash)?)
+    } else {
+        None
+    };
+
+    Ok(data)
+}
+
+fn find_smart_contract_result_from_logs(
+    tx_hash: &str,
+    logs: &TransactionOnNetworkTransactionLogs
+) -> Result<Option<SmartContractResultData>, ExecutorError> {
+    for event in logs.events.iter().rev() {
+        if event.identifier != "writeLog" {
+            continue;
         }
 
-        let Some(result_code) = split.next() else {
-            return Err(TransactionError::CannotDecodeSmartContractResult.into())
+        let Some(data) = &event.data else {
+            continue;
         };
 
-        if result_code != "6f6b" {
-            return Err(TransactionError::CannotDecodeSmartContractResult.into())
+        let decoded_data = base64::engine::general_purpose::STANDARD.decode(data)
+            .map_err(|_| TransactionError::CannotDecodeBase64)?;
+
+        let decoded_data = String::from_utf8(decoded_data)
+            .map_err(|error| TransactionError::CannotDecodeSmartContractResult {
+                raw: vec![error.as_bytes().to_vec()],
+                reason: "writeLog data is not valid UTF-8".to_string()
+            })?;
+
+        if decoded_data.starts_with('@') {
+            return Ok(Some(decode_scr_data(&decoded_data, tx_hash.to_string())?));
         }
+    }
 
-        let data = split
-            .map(|encoded_arg| hex::decode(encoded_arg).expect("error hex-decoding result"))
-            .collect();
+    Ok(None)
+}
 
-        Some(data)
-    } else {
-        None
-    };
+/// Returns the data of an SCR in its `@`-separated hex form, such as "@6f6b@05".
+///
+/// Some proxies return the data base64-encoded, such as "QDZmNmJAMDU=", in which case it is decoded first.
+/// Data in neither form, such as a transfer's "ESDTTransfer@...", is returned as-is.
+fn normalize_scr_data(data: &str) -> Cow<'_, str> {
+    if data.starts_with('@') {
+        return Cow::Borrowed(data)
+    }
 
-    Ok(data)
+    base64::engine::general_purpose::STANDARD.decode(data)
+        .ok()
+        .and_then(|decoded| String::from_utf8(decoded).ok())
+        .filter(|decoded| decoded.starts_with('@'))
+        .map_or(Cow::Borrowed(data), Cow::Owned)
 }
 
-fn find_smart_contract_result_from_logs(
-    logs: &TransactionOnNetworkTransactionLogs
-) -> Result<Option<Vec<Vec<u8>>>, ExecutorError> {
-    let find_result = logs.events
-        .iter()
-        .rev()
-        .find_map(|event| {
-            if event.identifier == "writeLog" {
-                if let Some(data) = &event.data {
-                    let decoded_data = String::from_utf8(base64_decode(data)).unwrap();
-
-                    if decoded_data.starts_with('@') {
-                        let out = decode_scr_data_or_panic(decoded_data.as_str());
-                        return Some(out);
-                    }
-                }
-            }
+/// Decodes the result code and the `@`-separated arguments following it of an SCR's data, `scr_hash` being the hash of the SCR.
+///
+/// The data of a call to an endpoint returning nothing is only the ok code, `@6f6b`, which decodes to no argument.
+fn decode_scr_data(data: &str, scr_hash: String) -> Result<SmartContractResultData, ExecutorError> {
+    let mut split = data.split('@');
+    if split.next().is_none() {
+        return Err(get_invalid_scr_data_error(data, "empty data"))
+    }
+
+    let Some(result_code) = split.next() else {
+        return Err(get_invalid_scr_data_error(data, "no result code"))
+    };
+
+    if result_code != OK_RESULT_CODE {
+        let code = decode_hex_string(result_code);
+        let message = split.next()
+            .map(decode_hex_string)
+            .unwrap_or_default();
 
-            None
-    });
+        return Err(TransactionError::SmartContractExecutionFailed { code, message }.into())
+    }
 
-    Ok(find_result)
+    let return_data = split
+        .map(|encoded_arg| {
+            hex::decode(encoded_arg)
+                .map_err(|_| get_invalid_scr_data_error(data, &format!("invalid hex argument \"{encoded_arg}\"")))
+        })
+        .collect::<Result<Vec<Vec<u8>>, ExecutorError>>()?;
+
+    let result = SmartContractResultData {
+        return_code: decode_hex_string(result_code),
+        return_data,
+        scr_hash
+    };
+
+    Ok(result)
+}
+
+fn get_invalid_scr_data_error(data: &str, reason: &str) -> ExecutorError {
+    TransactionError::CannotDecodeSmartContractResult {
+        raw: vec![data.as_bytes().to_vec()],
+        reason: reason.to_string()
+    }.into()
+}
+
+fn decode_hex_string(encoded: &str) -> String {
+    hex::decode(encoded)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .unwrap_or_else(|| encoded.to_string())
 }
 
 fn decode_topic(topic: &str) -> Result<String, ExecutorError> {
@@ -126,8 +275,11 @@ fn decode_topic(topic: &str) -> Result<String, ExecutorError> {
 
 #[cfg(test)]
 mod tests {
-    use crate::TransactionOnNetworkResponse;
-    use crate::utils::transaction::results::find_smart_contract_result;
+    use crate::{ExecutorError, TransactionOnNetworkResponse, TransactionOnNetworkTransactionLogs, TransactionOnNetworkTransactionLogsEvents, TransactionOnNetworkTransactionSmartContractResult};
+    use crate::error::transaction::TransactionError;
+    use crate::utils::transaction::results::{decode_all_smart_contract_results, find_sc_error, find_smart_contract_result, SmartContractResultData};
+    #[cfg(feature = "network")]
+    use crate::utils::transaction::results::find_sc_deploy_event;
 
     #[test]
     fn test_with_multi_contract_same_shard_tx_that_has_no_sc_result() {
@@ -209,6 +361,8 @@ mod tests {
             .transaction;
 
         let results = find_smart_contract_result(
+            &tx_on_network.hash,
+            &tx_on_network.sender,
             &tx_on_network.smart_contract_results,
             tx_on_network.logs.as_ref()
         )
@@ -220,7 +374,7 @@ mod tests {
             hex::decode("0218711a00").unwrap(),
         ];
 
-        assert_eq!(results, expected)
+        assert_eq!(results.return_data, expected)
     }
 
     #[test]
@@ -347,6 +501,8 @@ mod tests {
             .transaction;
 
         let results = find_smart_contract_result(
+            &tx_on_network.hash,
+            &tx_on_network.sender,
             &tx_on_network.smart_contract_results,
             tx_on_network.logs.as_ref()
         )
@@ -355,7 +511,7 @@ mod tests {
 
         let expected: Vec<Vec<u8>> = vec![];
 
-        assert_eq!(results, expected)
+        assert_eq!(results.return_data, expected)
     }
 
     #[test]
@@ -482,6 +638,8 @@ mod tests {
             .transaction;
 
         let results = find_smart_contract_result(
+            &tx_on_network.hash,
+            &tx_on_network.sender,
             &tx_on_network.smart_contract_results,
             tx_on_network.logs.as_ref()
         )
@@ -490,7 +648,7 @@ mod tests {
 
         let expected: Vec<Vec<u8>> = vec![];
 
-        assert_eq!(results, expected)
+        assert_eq!(results.return_data, expected)
     }
 
     #[test]
@@ -617,6 +775,8 @@ mod tests {
             .transaction;
 
         let results = find_smart_contract_result(
+            &tx_on_network.hash,
+            &tx_on_network.sender,
             &tx_on_network.smart_contract_results,
             tx_on_network.logs.as_ref()
         )
@@ -625,7 +785,7 @@ mod tests {
 
         let expected: Vec<Vec<u8>> = vec![];
 
-        assert_eq!(results, expected)
+        assert_eq!(results.return_data, expected)
     }
 
     #[test]
@@ -910,6 +1070,8 @@ mod tests {
             .transaction;
 
         let results = find_smart_contract_result(
+            &tx_on_network.hash,
+            &tx_on_network.sender,
             &tx_on_network.smart_contract_results,
             tx_on_network.logs.as_ref()
         )
@@ -920,7 +1082,7 @@ mod tests {
             hex::decode("0000000c5745474c442d6132386335390000000000000000000000079b35e4dd3902b9").unwrap()
         ];
 
-        assert_eq!(results, expected)
+        assert_eq!(results.return_data, expected)
     }
 
     #[test]
@@ -1175,6 +1337,8 @@ mod tests {
             .transaction;
 
         let results = find_smart_contract_result(
+            &tx_on_network.hash,
+            &tx_on_network.sender,
             &tx_on_network.smart_contract_results,
             tx_on_network.logs.as_ref()
         )
@@ -1187,7 +1351,7 @@ mod tests {
             hex::decode("10").unwrap(),
         ];
 
-        assert_eq!(results, expected)
+        assert_eq!(results.return_data, expected)
     }
 
     #[test]
@@ -1269,6 +1433,8 @@ mod tests {
             .transaction;
 
         let results = find_smart_contract_result(
+            &tx_on_network.hash,
+            &tx_on_network.sender,
             &tx_on_network.smart_contract_results,
             tx_on_network.logs.as_ref()
         )
@@ -1280,6 +1446,310 @@ mod tests {
             hex::decode("0218711a00").unwrap(),
         ];
 
-        assert_eq!(results, expected)
+        assert_eq!(results.return_data, expected)
+    }
+
+    #[cfg(feature = "network")]
+    #[test]
+    fn test_find_sc_deploy_event() {
+        let events = vec![
+            TransactionOnNetworkTransactionLogsEvents {
+                address: "erd1uh67c2lkhyj4vh73akv7jky9sfgvus8awwcj64uju69mmfne5u7q299t7g".to_string(),
+                identifier: "writeLog".to_string(),
+                topics: vec![],
+                data: None,
+            },
+            TransactionOnNetworkTransactionLogsEvents {
+                address: "erd1qqqqqqqqqqqqqpgq4nlkk7jwhqgp4r08lal46tqt70jdv0685u7qrr3l2d".to_string(),
+                identifier: "SCDeploy".to_string(),
+                topics: vec![
+                    "AAAAAAAAAAAFAKz/a3pOuBAajef/f10sC/Pk1j9HpzY=".to_string(),
+                    "5fXsK/a5JVZf0e2Z6ViFglDOQP1zsS1XkuaLvaZ5pzw=".to_string()
+                ],
+                data: None,
+            }
+        ];
+
+        let result = find_sc_deploy_event(&events).unwrap();
+
+        assert_eq!(result.identifier, "SCDeploy");
+        assert_eq!(result.address, "erd1qqqqqqqqqqqqqpgq4nlkk7jwhqgp4r08lal46tqt70jdv0685u7qrr3l2d");
     }
-}
\ No newline at end of file
+
+    #[cfg(feature = "network")]
+    #[test]
+    fn test_find_sc_deploy_event_no_event() {
+        let events = vec![
+            TransactionOnNetworkTransactionLogsEvents {
+                address: "erd1uh67c2lkhyj4vh73akv7jky9sfgvus8awwcj64uju69mmfne5u7q299t7g".to_string(),
+                identifier: "writeLog".to_string(),
+                topics: vec![],
+                data: None,
+            }
+        ];
+
+        let result = find_sc_deploy_event(&events);
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_with_multiple_sc_results_picks_direct_reply() {
+        let tx_hash = "4d50a055663dfee2479851684d7fb83cf00695b6f03f4dbbdf0f9232477cafc4";
+        let sender = "erd1uh67c2lkhyj4vh73akv7jky9sfgvus8awwcj64uju69mmfne5u7q299t7g";
+
+        let sc_results = vec![
+            TransactionOnNetworkTransactionSmartContractResult {
+                hash: "462b56a1530e6070dc7c15f755e51a97a6972c8cd7891f3be4635b93211890c5".to_string(),
+                nonce: 1,
+                data: "@6f6b@01".to_string(),
+                sender: "erd1qqqqqqqqqqqqqpgqllqglpjdrz5kn3m0k9uf9hdqjmg3xdhk6r7se3wvlk".to_string(),
+                receiver: "erd1qqqqqqqqqqqqqpgq4nlkk7jwhqgp4r08lal46tqt70jdv0685u7qrr3l2d".to_string(),
+                prev_tx_hash: tx_hash.to_string(),
+            },
+            TransactionOnNetworkTransactionSmartContractResult {
+                hash: "41d56fdacf3e14de67e821427c732b62ebfa07c82d2e5db6de75fe3a1c828d9b".to_string(),
+                nonce: 52,
+                data: "@6f6b@02".to_string(),
+                sender: "erd1qqqqqqqqqqqqqpgqllqglpjdrz5kn3m0k9uf9hdqjmg3xdhk6r7se3wvlk".to_string(),
+                receiver: sender.to_string(),
+                prev_tx_hash: tx_hash.to_string(),
+            }
+        ];
+
+        let result = find_smart_contract_result(tx_hash, sender, &Some(sc_results.clone()), None).unwrap();
+        let result_without_hash = find_smart_contract_result("", "", &Some(sc_results), None).unwrap();
+
+        let expected = SmartContractResultData {
+            return_code: "ok".to_string(),
+            return_data: vec![vec![2]],
+            scr_hash: "41d56fdacf3e14de67e821427c732b62ebfa07c82d2e5db6de75fe3a1c828d9b".to_string(),
+        };
+
+        assert_eq!(result, Some(expected));
+        assert_eq!(result_without_hash.map(|result| result.scr_hash), Some("462b56a1530e6070dc7c15f755e51a97a6972c8cd7891f3be4635b93211890c5".to_string()));
+    }
+
+    #[test]
+    fn test_with_hex_sc_result_data() {
+        let result = find_smart_contract_result("", "", &Some(vec![get_sc_result_with_data("@6f6b@05")]), None).unwrap();
+
+        assert_eq!(result.map(|result| result.return_data), Some(vec![vec![5]]));
+    }
+
+    #[test]
+    fn test_with_base64_sc_result_data() {
+        let result = find_smart_contract_result("", "", &Some(vec![get_sc_result_with_data("QDZmNmJAMDU=")]), None).unwrap();
+
+        assert_eq!(result.map(|result| result.return_data), Some(vec![vec![5]]));
+    }
+
+    #[test]
+    fn test_with_base64_sc_result_data_not_a_result() {
+        // "Z2V0U3Vt" is the base64 encoding of "getSum", which doesn't start with '@'.
+        let result = find_smart_contract_result("", "", &Some(vec![get_sc_result_with_data("Z2V0U3Vt")]), None).unwrap();
+
+        assert_eq!(result.map(|result| result.return_data), None);
+    }
+
+    #[test]
+    fn test_decode_all_smart_contract_results_base64() {
+        let result = decode_all_smart_contract_results(&[get_sc_result_with_data("QDZmNmJAMDFA")]);
+
+        assert_eq!(result, vec![vec![b"ok".to_vec(), vec![1], vec![]]]);
+    }
+
+    fn get_sc_result_with_data(data: &str) -> TransactionOnNetworkTransactionSmartContractResult {
+        TransactionOnNetworkTransactionSmartContractResult {
+            hash: "".to_string(),
+            nonce: 1,
+            data: data.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_with_sc_result_ok_without_return_data() {
+        let tx_hash = "4d50a055663dfee2479851684d7fb83cf00695b6f03f4dbbdf0f9232477cafc4";
+        let sender = "erd1uh67c2lkhyj4vh73akv7jky9sfgvus8awwcj64uju69mmfne5u7q299t7g";
+
+        let sc_results = vec![
+            TransactionOnNetworkTransactionSmartContractResult {
+                hash: "462b56a1530e6070dc7c15f755e51a97a6972c8cd7891f3be4635b93211890c5".to_string(),
+                nonce: 52,
+                data: "@6f6b".to_string(),
+                sender: "erd1qqqqqqqqqqqqqpgqllqglpjdrz5kn3m0k9uf9hdqjmg3xdhk6r7se3wvlk".to_string(),
+                receiver: sender.to_string(),
+                prev_tx_hash: tx_hash.to_string(),
+            }
+        ];
+
+        let result = find_smart_contract_result(tx_hash, sender, &Some(sc_results.clone()), None).unwrap();
+        let result_without_hash = find_smart_contract_result("", "", &Some(sc_results), None).unwrap();
+
+        assert_eq!(result.map(|result| result.return_data), Some(vec![]));
+        assert_eq!(result_without_hash.map(|result| result.return_data), Some(vec![]));
+    }
+
+    #[test]
+    fn test_with_write_log_ok_without_return_data() {
+        let logs = TransactionOnNetworkTransactionLogs {
+            address: "".to_string(),
+            events: vec![
+                TransactionOnNetworkTransactionLogsEvents {
+                    address: "".to_string(),
+                    identifier: "writeLog".to_string(),
+                    topics: vec![],
+                    data: Some("QDZmNmI=".to_string()),
+                }
+            ],
+        };
+
+        let result = find_smart_contract_result("", "", &None, Some(&logs)).unwrap();
+
+        assert_eq!(result.map(|result| result.return_data), Some(vec![]));
+    }
+
+    #[test]
+    fn test_with_write_log_references_the_transaction() {
+        let tx_hash = "4d50a055663dfee2479851684d7fb83cf00695b6f03f4dbbdf0f9232477cafc4";
+        let logs = TransactionOnNetworkTransactionLogs {
+            address: "".to_string(),
+            events: vec![
+                TransactionOnNetworkTransactionLogsEvents {
+                    address: "".to_string(),
+                    identifier: "writeLog".to_string(),
+                    topics: vec![],
+                    data: Some("QDZmNmJAMDU=".to_string()),
+                }
+            ],
+        };
+
+        let result = find_smart_contract_result(tx_hash, "", &None, Some(&logs)).unwrap();
+
+        let expected = SmartContractResultData {
+            return_code: "ok".to_string(),
+            return_data: vec![vec![5]],
+            scr_hash: tx_hash.to_string(),
+        };
+
+        assert_eq!(result, Some(expected));
+    }
+
+    #[test]
+    fn test_with_sc_result_non_ok_code() {
+        let sc_results = vec![
+            TransactionOnNetworkTransactionSmartContractResult {
+                hash: "".to_string(),
+                nonce: 1,
+                data: "@75736572206572726f72@696e73756666696369656e742066756e6473".to_string(),
+                ..Default::default()
+            }
+        ];
+
+        let result = find_smart_contract_result("", "", &Some(sc_results), None);
+
+        let expected: Result<Option<SmartContractResultData>, ExecutorError> = Err(TransactionError::SmartContractExecutionFailed {
+            code: "user error".to_string(),
+            message: "insufficient funds".to_string(),
+        }.into());
+
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn test_with_sc_result_invalid_hex() {
+        let sc_results = vec![
+            TransactionOnNetworkTransactionSmartContractResult {
+                hash: "".to_string(),
+                nonce: 1,
+                data: "@6f6b@zz".to_string(),
+                ..Default::default()
+            }
+        ];
+
+        let result = find_smart_contract_result("", "", &Some(sc_results), None);
+
+        let expected: Result<Option<SmartContractResultData>, ExecutorError> = Err(TransactionError::CannotDecodeSmartContractResult {
+            raw: vec![b"@6f6b@zz".to_vec()],
+            reason: "invalid hex argument \"zz\"".to_string()
+        }.into());
+
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn test_decode_all_smart_contract_results() {
+        let sc_results = vec![
+            TransactionOnNetworkTransactionSmartContractResult {
+                hash: "".to_string(),
+                nonce: 1,
+                data: "@6f6b@01@".to_string(),
+                ..Default::default()
+            },
+            TransactionOnNetworkTransactionSmartContractResult {
+                hash: "".to_string(),
+                nonce: 0,
+                data: "ESDTTransfer@5745474c442d616263646566@64".to_string(),
+                ..Default::default()
+            },
+            TransactionOnNetworkTransactionSmartContractResult {
+                hash: "".to_string(),
+                nonce: 2,
+                data: "@75736572206572726f72@zz".to_string(),
+                ..Default::default()
+            }
+        ];
+
+        let result = decode_all_smart_contract_results(&sc_results);
+
+        let expected = vec![
+            vec![b"ok".to_vec(), vec![1], vec![]],
+            vec![b"user error".to_vec(), b"zz".to_vec()]
+        ];
+
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn test_find_sc_error_signal_error_event() {
+        let logs = TransactionOnNetworkTransactionLogs {
+            address: "erd1qqqqqqqqqqqqqpgq4nlkk7jwhqgp4r08lal46tqt70jdv0685u7qrr3l2d".to_string(),
+            events: vec![
+                TransactionOnNetworkTransactionLogsEvents {
+                    address: "erd1qqqqqqqqqqqqqpgq4nlkk7jwhqgp4r08lal46tqt70jdv0685u7qrr3l2d".to_string(),
+                    identifier: "signalError".to_string(),
+                    topics: vec![
+                        "5fXsK/a5JVZf0e2Z6ViFglDOQP1zsS1XkuaLvaZ5pzw=".to_string(),
+                        "aW5zdWZmaWNpZW50IGZ1bmRz".to_string()
+                    ],
+                    data: None,
+                }
+            ],
+        };
+
+        let result = find_sc_error(&logs).unwrap().unwrap();
+
+        assert_eq!(result.status, 4);
+        assert_eq!(result.message, "insufficient funds");
+    }
+
+    #[test]
+    fn test_find_sc_error_no_signal_error_event() {
+        let logs = TransactionOnNetworkTransactionLogs {
+            address: "erd1qqqqqqqqqqqqqpgq4nlkk7jwhqgp4r08lal46tqt70jdv0685u7qrr3l2d".to_string(),
+            events: vec![
+                TransactionOnNetworkTransactionLogsEvents {
+                    address: "erd1qqqqqqqqqqqqqpgq4nlkk7jwhqgp4r08lal46tqt70jdv0685u7qrr3l2d".to_string(),
+                    identifier: "completedTxEvent".to_string(),
+                    topics: vec![],
+                    data: None,
+                }
+            ],
+        };
+
+        let result = find_sc_error(&logs).unwrap();
+
+        assert!(result.is_none());
+    }
+}
@@ -1,5 +1,11 @@
 use num_bigint::BigUint;
 
+use crate::ExecutorError;
+use crate::error::transaction::TransactionError;
+
+/// The identifier of the pseudo-token carrying EGLD inside a `MultiESDTNFTTransfer`, allowing to send EGLD along with ESDTs.
+pub const EGLD_TOKEN_IDENTIFIER: &str = "EGLD-000000";
+
 /// A structure representing the data necessary for transferring tokens during a contract call.
 ///
 /// `TokenTransfer` is used to specify the details of a token transfer that is to be carried out as part
@@ -7,7 +13,7 @@ use num_bigint::BigUint;
 /// This struct is solely used for providing token transfer information as a parameter to a contract call. In case
 /// there is a token payment return from the contract, a different struct (which would be generated from the ABI along
 /// with the client) named `EsdtTokenPayment` would be used to represent that data.
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
 pub struct TokenTransfer {
     /// A string representing the identifier of the token to be transferred.
     ///
@@ -24,4 +30,134 @@ pub struct TokenTransfer {
     ///
     /// This field specifies the quantity of tokens that are to be transferred as part of the contract call.
     pub amount: BigUint
-}
\ No newline at end of file
+}
+
+impl TokenTransfer {
+    /// Creates a transfer of a fungible ESDT, whose nonce is always 0.
+    ///
+    /// Fails with `TransactionError::InvalidTokenIdentifier` if the identifier is not of the form `TICKER-abcdef`.
+    pub fn fungible(identifier: impl Into<String>, amount: BigUint) -> Result<TokenTransfer, ExecutorError> {
+        Self::non_fungible(identifier, 0, amount)
+    }
+
+    /// Creates a transfer of a non-fungible, semi-fungible or meta ESDT, identified by its collection and its nonce.
+    ///
+    /// Fails with `TransactionError::InvalidTokenIdentifier` if the identifier is not of the form `TICKER-abcdef`.
+    pub fn non_fungible(identifier: impl Into<String>, nonce: u64, amount: BigUint) -> Result<TokenTransfer, ExecutorError> {
+        let transfer = Self::unchecked(identifier, nonce, amount);
+        transfer.validate()?;
+
+        Ok(transfer)
+    }
+
+    /// Creates a transfer of EGLD through the `EGLD-000000` pseudo-token, allowing to send EGLD along with ESDTs in a single call.
+    ///
+    /// Such a transfer is always sent through `MultiESDTNFTTransfer`, even if it is the only one.
+    pub fn egld(amount: BigUint) -> TokenTransfer {
+        Self::unchecked(EGLD_TOKEN_IDENTIFIER, 0, amount)
+    }
+
+    /// Returns whether this transfer carries EGLD through the `EGLD-000000` pseudo-token.
+    pub fn is_egld(&self) -> bool {
+        self.identifier == EGLD_TOKEN_IDENTIFIER
+    }
+
+    /// Creates a transfer without validating the token identifier.
+    pub fn unchecked(identifier: impl Into<String>, nonce: u64, amount: BigUint) -> TokenTransfer {
+        TokenTransfer {
+            identifier: identifier.into(),
+            nonce,
+            amount,
+        }
+    }
+
+    /// Checks that the token identifier is of the form `TICKER-abcdef`: a ticker of 3 to 10 uppercase alphanumeric characters,
+    /// followed by a dash and 6 lowercase hexadecimal characters.
+    pub fn validate(&self) -> Result<(), ExecutorError> {
+        if is_valid_token_identifier(&self.identifier) {
+            Ok(())
+        } else {
+            Err(TransactionError::InvalidTokenIdentifier { identifier: self.identifier.clone() }.into())
+        }
+    }
+}
+
+fn is_valid_token_identifier(identifier: &str) -> bool {
+    let Some((ticker, random)) = identifier.split_once('-') else {
+        return false
+    };
+
+    let is_ticker_valid = (3..=10).contains(&ticker.len())
+        && ticker.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+
+    let is_random_valid = random.len() == 6
+        && random.chars().all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c));
+
+    is_ticker_valid && is_random_valid
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use num_bigint::BigUint;
+
+    use crate::error::transaction::TransactionError;
+    use crate::{ExecutorError, TokenTransfer};
+
+    #[test]
+    fn test_fungible_valid_identifier() {
+        let result = TokenTransfer::fungible("WEGLD-bd4d79", BigUint::from(1u8)).unwrap();
+
+        assert_eq!(result.identifier, "WEGLD-bd4d79");
+        assert_eq!(result.nonce, 0);
+    }
+
+    #[test]
+    fn test_fungible_missing_random_part() {
+        let result = TokenTransfer::fungible("WEGLD", BigUint::from(1u8));
+
+        let expected = Err(ExecutorError::from(TransactionError::InvalidTokenIdentifier { identifier: "WEGLD".to_string() }));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_non_fungible_invalid_identifiers() {
+        let invalid_identifiers = [
+            "wegld-bd4d79",
+            "WE-bd4d79",
+            "WEGLDWEGLDW-bd4d79",
+            "WEGLD-BD4D79",
+            "WEGLD-bd4d7",
+            "WEGLD-bd4d79-01",
+            "WEG_LD-bd4d79"
+        ];
+
+        for identifier in invalid_identifiers {
+            assert!(TokenTransfer::non_fungible(identifier, 1, BigUint::from(1u8)).is_err(), "{identifier} should be invalid");
+        }
+    }
+
+    #[test]
+    fn test_unchecked() {
+        let result = TokenTransfer::unchecked("WEGLD", 0, BigUint::from(1u8));
+
+        assert_eq!(result.identifier, "WEGLD");
+        assert!(result.validate().is_err());
+    }
+
+    #[test]
+    fn test_dedupe_transfers() {
+        let transfers = vec![
+            TokenTransfer::fungible("WEGLD-bd4d79", BigUint::from(10u8).pow(18)).unwrap(),
+            TokenTransfer::non_fungible("SFT-abcdef", 1, BigUint::from(1u8)).unwrap(),
+            TokenTransfer::fungible("WEGLD-bd4d79", BigUint::from(10u8).pow(18)).unwrap(),
+            TokenTransfer::non_fungible("SFT-abcdef", 2, BigUint::from(1u8)).unwrap(),
+        ];
+
+        let unique_transfers: HashSet<TokenTransfer> = transfers.into_iter().collect();
+
+        assert_eq!(unique_transfers.len(), 3);
+    }
+}
@@ -1,14 +1,65 @@
 use std::borrow::Borrow;
+use std::sync::OnceLock;
 
 use async_trait::async_trait;
 use http::StatusCode;
-use reqwest::Client;
+use reqwest::{Client, RequestBuilder};
 use serde::Serialize;
 use crate::error::client::ClientError;
 
 use crate::error::request::RequestError;
 use crate::gateway::client::GatewayClient;
 
+/// Returns the `reqwest` client shared by all the requests, so its connection pool is reused across calls.
+pub fn get_shared_client() -> &'static Client {
+    static CLIENT: OnceLock<Client> = OnceLock::new();
+
+    CLIENT.get_or_init(Client::new)
+}
+
+/// A gateway client sending its requests through a user-provided `reqwest::Client`.
+///
+/// This allows configuring proxies, TLS, default headers or timeouts on the underlying client.
+#[derive(Clone, Debug)]
+pub struct ReqwestGatewayClient {
+    url: String,
+    client: Client
+}
+
+impl ReqwestGatewayClient {
+    /// Creates a new `ReqwestGatewayClient` targeting `url` and sending its requests through `client`.
+    pub fn new(url: impl Into<String>, client: Client) -> Self {
+        ReqwestGatewayClient {
+            url: url.into(),
+            client
+        }
+    }
+}
+
+#[async_trait]
+impl GatewayClient for ReqwestGatewayClient {
+    type Owned = Self;
+
+    fn get_gateway_url(&self) -> &str {
+        &self.url
+    }
+
+    fn with_appended_url(&self, url: &str) -> Self::Owned {
+        ReqwestGatewayClient {
+            url: format!("{}{url}", self.url),
+            client: self.client.clone()
+        }
+    }
+
+    async fn get(&self) -> Result<(StatusCode, Option<String>), RequestError> {
+        send_request(self.client.get(&self.url)).await
+    }
+
+    async fn post<Body>(&self, body: &Body) -> Result<(StatusCode, Option<String>), RequestError> where Body: Serialize + Send + Sync {
+        send_request(self.client.post(&self.url).json(body)).await
+    }
+}
+
 #[async_trait]
 impl<T> GatewayClient for T
 where
@@ -25,43 +76,29 @@ where
     }
 
     async fn get(&self) -> Result<(StatusCode, Option<String>), RequestError> {
-        let response = Client::new()
-            .get(self.borrow())
-            .send()
-            .await
-            .map_err(|_| ClientError::UnknownError)?;
-
-        let status = response.status();
-
-        let text = if status.is_success() {
-            let text = response.text().await.unwrap(); // TODO
-
-            Some(text)
-        } else {
-            None
-        };
-
-        Ok((status, text))
+        send_request(get_shared_client().get(self.borrow())).await
     }
 
     async fn post<Body>(&self, body: &Body) -> Result<(StatusCode, Option<String>), RequestError> where Body: Serialize + Send + Sync {
-        let response = Client::new()
-            .post(self.borrow())
-            .json(body)
-            .send()
-            .await
-            .map_err(|_| ClientError::UnknownError)?;
+        send_request(get_shared_client().post(self.borrow()).json(body)).await
+    }
+}
 
-        let status = response.status();
+async fn send_request(request: RequestBuilder) -> Result<(StatusCode, Option<String>), RequestError> {
+    let response = request
+        .send()
+        .await
+        .map_err(|_| ClientError::UnknownError)?;
 
-        let text = if status.is_success() {
-            let text = response.text().await.unwrap(); // TODO
+    let status = response.status();
 
-            Some(text)
-        } else {
-            None
-        };
+    let text = if status.is_success() {
+        let text = response.text().await.unwrap(); // TODO
 
-        Ok((status, text))
-    }
-}
\ No newline at end of file
+        Some(text)
+    } else {
+        None
+    };
+
+    Ok((status, text))
+}
@@ -4,9 +4,10 @@ use tokio::sync::Mutex;
 use novax::Address;
 use novax::errors::NovaXError;
 use novax::tester::tester::{CustomEnum, CustomEnumWithFields, CustomEnumWithValues, CustomStruct, CustomStructWithStructAndVec, TesterContract};
-use novax::executor::{DummyExecutor, DummyTransactionExecutor, SendableTransaction, TokenTransfer};
+use novax::executor::{DummyExecutor, DummyTransactionExecutor, SendableTransaction, TokenTransfer, TransactionExecutor};
 
 const CALLER: &str = "erd1h4uhy73dev6qrfj7wxsguapzs8632mfwqjswjpsj6kzm2jfrnslqsuduqu";
+const OTHER_CALLER: &str = "erd1x39tc3q3nn72ecjnmcz7x0qp09kp97t080x99dgyhx7zh95j0n4szskhlv";
 const TESTER_CONTRACT_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p";
 
 fn get_executor() -> Arc<Mutex<DummyTransactionExecutor>> {
@@ -42,6 +43,33 @@ async fn test_call_with_biguint_result() -> Result<(), NovaXError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_call_result_is_none() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    let result = TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .call(executor.clone(), 600000000)
+        .get_sum()
+        .await?;
+
+    assert!(result.result.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_transaction_details_without_call() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    let tx = executor.lock().await.get_transaction_details();
+
+    assert!(tx.is_err());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_call_with_another_gas_limit() -> Result<(), NovaXError> {
     let executor = get_executor();
@@ -701,4 +729,184 @@ async fn test_call_with_bigint_arg_result() -> Result<(), NovaXError> {
     assert_eq!(tx, Ok(expected));
 
     Ok(())
-}
\ No newline at end of file
+}
+#[tokio::test]
+async fn test_send_egld_without_data() -> Result<(), NovaXError> {
+    let mut executor = get_executor();
+
+    executor.send_egld(
+        &Address::from_bech32_string(CALLER).unwrap(),
+        BigUint::from(10u8).pow(18),
+        None
+    ).await?;
+
+    let tx = executor.lock().await.get_transaction_details();
+
+    let expected = SendableTransaction {
+        receiver: CALLER.to_string(),
+        egld_value: BigUint::from(10u8).pow(18),
+        gas_limit: 50000,
+        data: "".to_string(),
+    };
+
+    assert_eq!(tx, Ok(expected));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_send_egld_with_data() -> Result<(), NovaXError> {
+    let mut executor = get_executor();
+
+    executor.send_egld(
+        &Address::from_bech32_string(CALLER).unwrap(),
+        BigUint::from(1000u16),
+        Some("thanks!".to_string())
+    ).await?;
+
+    let tx = executor.lock().await.get_transaction_details();
+
+    let expected = SendableTransaction {
+        receiver: CALLER.to_string(),
+        egld_value: BigUint::from(1000u16),
+        gas_limit: 60500,
+        data: "thanks!".to_string(),
+    };
+
+    assert_eq!(tx, Ok(expected));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_claim_developer_rewards() -> Result<(), NovaXError> {
+    let mut executor = get_executor();
+
+    executor.claim_developer_rewards(
+        &Address::from_bech32_string(TESTER_CONTRACT_ADDRESS).unwrap()
+    ).await?;
+
+    let tx = executor.lock().await.get_transaction_details();
+
+    let expected = SendableTransaction {
+        receiver: TESTER_CONTRACT_ADDRESS.to_string(),
+        egld_value: BigUint::from(0u8),
+        gas_limit: 5081500,
+        data: "ClaimDeveloperRewards".to_string(),
+    };
+
+    assert_eq!(tx, Ok(expected));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_change_owner() -> Result<(), NovaXError> {
+    let mut executor = get_executor();
+
+    executor.change_owner(
+        &Address::from_bech32_string(TESTER_CONTRACT_ADDRESS).unwrap(),
+        &Address::from_bech32_string(OTHER_CALLER).unwrap()
+    ).await?;
+
+    let tx = executor.lock().await.get_transaction_details();
+
+    let expected = SendableTransaction {
+        receiver: TESTER_CONTRACT_ADDRESS.to_string(),
+        egld_value: BigUint::from(0u8),
+        gas_limit: 5174500,
+        data: "ChangeOwnerAddress@344abc44119cfcace253de05e33c01796c12f96f3bcc52b504b9bc2b96927ceb".to_string(),
+    };
+
+    assert_eq!(tx, Ok(expected));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_estimate_gas() -> Result<(), NovaXError> {
+    let executor = DummyExecutor::new(&Some(Address::from_bech32_string(CALLER).unwrap()))
+        .with_gas_estimate(5000000);
+
+    let gas = executor.estimate_gas(
+        &Address::from_bech32_string(TESTER_CONTRACT_ADDRESS).unwrap(),
+        "getSum".to_string(),
+        vec![],
+        BigUint::from(0u8),
+        vec![]
+    ).await?;
+
+    assert_eq!(gas, 5000000);
+    assert!(executor.get_transaction_details().is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_call_with_caller() -> Result<(), NovaXError> {
+    let executor = DummyExecutor::new(&None)
+        .with_caller(Address::from_bech32_string(OTHER_CALLER).unwrap());
+    let executor = Arc::new(Mutex::new(executor));
+
+    TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .call(executor.clone(), 600000000)
+        .with_esdt_transfers(
+            &vec![
+                TokenTransfer {
+                    identifier: "SFT-abcdef".to_string(),
+                    nonce: 1,
+                    amount: BigUint::from(100u8),
+                }
+            ]
+        )
+        .add(&BigUint::from(10u8))
+        .await?;
+
+    let tx = executor.lock().await.get_transaction_details()?;
+
+    assert_eq!(tx.receiver, OTHER_CALLER);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_call_with_caller_changed_between_calls() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    let sft_transfers = vec![
+        TokenTransfer {
+            identifier: "SFT-abcdef".to_string(),
+            nonce: 1,
+            amount: BigUint::from(100u8),
+        }
+    ];
+
+    TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .call(executor.clone(), 600000000)
+        .with_esdt_transfers(&sft_transfers)
+        .add(&BigUint::from(10u8))
+        .await?;
+
+    let first_tx = executor.lock().await.get_transaction_details()?;
+
+    executor.lock().await.set_caller(Some(Address::from_bech32_string(OTHER_CALLER).unwrap()));
+
+    TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .call(executor.clone(), 600000000)
+        .with_esdt_transfers(&sft_transfers)
+        .add(&BigUint::from(10u8))
+        .await?;
+
+    let second_tx = executor.lock().await.get_transaction_details()?;
+
+    assert_eq!(first_tx.receiver, CALLER);
+    assert_eq!(second_tx.receiver, OTHER_CALLER);
+
+    Ok(())
+}
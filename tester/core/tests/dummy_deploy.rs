@@ -1,9 +1,9 @@
 use num_bigint::BigUint;
 use novax::{Address, CodeMetadata};
-use novax::code::DeployData;
-use novax::errors::NovaXError;
+use novax::code::{CodeMetadataBuilder, DeployData, FileCode};
+use novax::errors::{CodeError, NovaXError};
 use novax::tester::tester::TesterContract;
-use novax::executor::{DummyDeployExecutor, SendableTransaction};
+use novax::executor::{DeployExecutor, DummyDeployExecutor, SendableTransaction};
 
 const CALLER: &str = "erd1h4uhy73dev6qrfj7wxsguapzs8632mfwqjswjpsj6kzm2jfrnslqsuduqu";
 const SYSTEM_SC_DEPLOY_ADDRESS: &str = "erd1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq6gq4hu";
@@ -78,4 +78,163 @@ async fn test_deploy_with_metadatas() -> Result<(), NovaXError> {
     assert_eq!(tx, Ok(expected));
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn test_deploy_with_egld_value() -> Result<(), NovaXError> {
+    let mut executor = get_executor();
+
+    executor.sc_deploy::<()>(
+        vec![0, 97, 115, 109],
+        CodeMetadata::PAYABLE,
+        BigUint::from(10u64.pow(18)),
+        vec![vec![5]],
+        600000000u64
+    )
+        .await?;
+
+    let tx = executor.get_transaction_details();
+
+    let expected = SendableTransaction {
+        receiver: SYSTEM_SC_DEPLOY_ADDRESS.to_string(),
+        egld_value: BigUint::from(10u64.pow(18)),
+        gas_limit: 600000000u64,
+        data: "0061736d@0500@0002@05".to_string(),
+    };
+
+    assert_eq!(tx, Ok(expected));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deploy_with_metadata_builder() -> Result<(), NovaXError> {
+    let mut executor = get_executor();
+
+    executor.sc_deploy::<()>(
+        vec![0, 97, 115, 109],
+        CodeMetadataBuilder::default().payable_by_sc(true).build(),
+        BigUint::from(0u8),
+        vec![],
+        600000000u64
+    )
+        .await?;
+
+    let tx = executor.get_transaction_details();
+
+    let expected = SendableTransaction {
+        receiver: SYSTEM_SC_DEPLOY_ADDRESS.to_string(),
+        egld_value: BigUint::from(0u8),
+        gas_limit: 600000000u64,
+        data: "0061736d@0500@0504".to_string(),
+    };
+
+    assert_eq!(tx, Ok(expected));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deploy_with_metadata_builder_not_upgradeable() -> Result<(), NovaXError> {
+    let mut executor = get_executor();
+
+    executor.sc_deploy::<()>(
+        vec![0, 97, 115, 109],
+        CodeMetadataBuilder::default().upgradeable(false).into(),
+        BigUint::from(0u8),
+        vec![],
+        600000000u64
+    )
+        .await?;
+
+    let tx = executor.get_transaction_details();
+
+    let expected = SendableTransaction {
+        receiver: SYSTEM_SC_DEPLOY_ADDRESS.to_string(),
+        egld_value: BigUint::from(0u8),
+        gas_limit: 600000000u64,
+        data: "0061736d@0500@0400".to_string(),
+    };
+
+    assert_eq!(tx, Ok(expected));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deploy_with_file_code() -> Result<(), NovaXError> {
+    let mut executor = get_executor();
+    let code = FileCode::new("../../.novax/tester-contract.wasm");
+
+    let deploy_data = DeployData {
+        code: &code,
+        metadata: Default::default(),
+    };
+
+    TesterContract::deploy(
+        deploy_data,
+        &mut executor,
+        BigUint::from(0u8),
+        600000000u64,
+        &BigUint::from(5u8)
+    )
+        .await?;
+
+    let tx = executor.get_transaction_details().unwrap();
+
+    assert!(tx.data.starts_with("0061736d"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deploy_with_missing_code_file() -> Result<(), NovaXError> {
+    let mut executor = get_executor();
+
+    let deploy_data = DeployData {
+        code: "../../.novax/missing-contract.wasm",
+        metadata: Default::default(),
+    };
+
+    let result = TesterContract::deploy(
+        deploy_data,
+        &mut executor,
+        BigUint::from(0u8),
+        600000000u64,
+        &BigUint::from(5u8)
+    )
+        .await
+        .map(|_| ());
+
+    let expected = Err(NovaXError::from(CodeError::CodeFileNotFound { path: "../../.novax/missing-contract.wasm".to_string() }));
+
+    assert_eq!(result, expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deploy_with_invalid_wasm_file() -> Result<(), NovaXError> {
+    let mut executor = get_executor();
+
+    let deploy_data = DeployData {
+        code: "Cargo.toml",
+        metadata: Default::default(),
+    };
+
+    let result = TesterContract::deploy(
+        deploy_data,
+        &mut executor,
+        BigUint::from(0u8),
+        600000000u64,
+        &BigUint::from(5u8)
+    )
+        .await
+        .map(|_| ());
+
+    let expected = Err(NovaXError::from(CodeError::InvalidWasmCode { path: "Cargo.toml".to_string() }));
+
+    assert_eq!(result, expected);
+
+    Ok(())
+}
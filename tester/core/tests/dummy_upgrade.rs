@@ -0,0 +1,69 @@
+use num_bigint::BigUint;
+use novax::{Address, CodeMetadata};
+use novax::errors::NovaXError;
+use novax::executor::{DummyExecutor, SendableTransaction, UpgradeExecutor};
+
+const CALLER: &str = "erd1h4uhy73dev6qrfj7wxsguapzs8632mfwqjswjpsj6kzm2jfrnslqsuduqu";
+const TESTER_CONTRACT_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p";
+
+fn get_executor() -> DummyExecutor<SendableTransaction> {
+    DummyExecutor::new(
+        &Some(Address::from_bech32_string(CALLER).unwrap())
+    )
+}
+
+#[tokio::test]
+async fn test_upgrade_with_args() -> Result<(), NovaXError> {
+    let mut executor = get_executor();
+
+    executor.sc_upgrade::<()>(
+        &Address::from_bech32_string(TESTER_CONTRACT_ADDRESS).unwrap(),
+        vec![0, 97, 115, 109],
+        CodeMetadata::default(),
+        BigUint::from(0u8),
+        vec![vec![5]],
+        600000000u64
+    )
+        .await?;
+
+    let tx = executor.get_transaction_details();
+
+    let expected = SendableTransaction {
+        receiver: TESTER_CONTRACT_ADDRESS.to_string(),
+        egld_value: 0u8.into(),
+        gas_limit: 600000000u64,
+        data: "upgradeContract@0061736d@0000@05".to_string(),
+    };
+
+    assert_eq!(tx, Ok(expected));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_upgrade_with_metadatas() -> Result<(), NovaXError> {
+    let mut executor = get_executor();
+
+    executor.sc_upgrade::<()>(
+        &Address::from_bech32_string(TESTER_CONTRACT_ADDRESS).unwrap(),
+        vec![0, 97, 115, 109],
+        CodeMetadata::UPGRADEABLE | CodeMetadata::READABLE,
+        BigUint::from(0u8),
+        vec![],
+        600000000u64
+    )
+        .await?;
+
+    let tx = executor.get_transaction_details();
+
+    let expected = SendableTransaction {
+        receiver: TESTER_CONTRACT_ADDRESS.to_string(),
+        egld_value: 0u8.into(),
+        gas_limit: 600000000u64,
+        data: "upgradeContract@0061736d@0500".to_string(),
+    };
+
+    assert_eq!(tx, Ok(expected));
+
+    Ok(())
+}
@@ -0,0 +1,232 @@
+use std::sync::Arc;
+
+use num_bigint::BigUint;
+use tokio::sync::Mutex;
+
+use novax::errors::NovaXError;
+use novax::Address;
+use novax::executor::{AddressSigner, BaseTransactionNetworkExecutor, BlockchainInteractor, ExecutorError, ExecutorErrorKind, GatewayError, MockInteractor, MockTransactionError, TokenTransfer, TransactionOnNetwork, Wallet};
+use novax::tester::tester::TesterContract;
+
+const CALLER_PRIVATE_KEY: &str = "69417ce717e43d0d3a598f68b5e562d7d2a532a5a3ac1e8b3342515e0b2d950f"; // to anyone reading : this has been generated only for the tests below
+const CALLER: &str = "erd12wf7tlsk2z895vwmndheaknkp3uaqa7xuq847numkwlmcvy60wxql2ndlk";
+const TESTER_CONTRACT_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p";
+
+fn get_executor() -> Arc<Mutex<BaseTransactionNetworkExecutor<MockInteractor>>> {
+    let wallet = Wallet::from_private_key(CALLER_PRIVATE_KEY).unwrap();
+
+    let executor = BaseTransactionNetworkExecutor::from_interactor(MockInteractor::new(wallet));
+
+    Arc::new(Mutex::new(executor))
+}
+
+#[tokio::test]
+async fn test_mock_interactor_scripted_result() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    executor
+        .lock()
+        .await
+        .interactor_mut()
+        .expect_sc_call()
+        .with_to(TESTER_CONTRACT_ADDRESS)
+        .with_data("getSum")
+        .returns_data("@6f6b@05");
+
+    let result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .call(executor.clone(), 600000000)
+        .get_sum()
+        .await?;
+
+    assert_eq!(result.result, Some(BigUint::from(5u8)));
+    assert!(executor.lock().await.interactor().are_all_expectations_met());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_interactor_received_calls() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    executor
+        .lock()
+        .await
+        .interactor_mut()
+        .expect_sc_call();
+
+    TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .call(executor.clone(), 600000000)
+        .add(&BigUint::from(10u8))
+        .await?;
+
+    let executor = executor.lock().await;
+    let received_calls = executor.interactor().get_received_calls();
+
+    assert_eq!(received_calls.len(), 1);
+    assert_eq!(received_calls[0].to, TESTER_CONTRACT_ADDRESS);
+    assert_eq!(received_calls[0].data, "add@0a");
+    assert_eq!(received_calls[0].gas_limit, 600000000);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_interactor_received_normalized_esdt_transfer() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    executor
+        .lock()
+        .await
+        .interactor_mut()
+        .expect_sc_call()
+        .returns_data("@6f6b@68656c6c6f776f726c64");
+
+    TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .call(executor.clone(), 600000000)
+        .with_esdt_transfers(&vec![TokenTransfer::fungible("WEGLD-abcdef", BigUint::from(10u8).pow(16)).unwrap()])
+        .return_concat_multi_buffer_args(&vec!["hello".to_string(), "world".to_string()])
+        .await?;
+
+    let executor = executor.lock().await;
+    let received_call = &executor.interactor().get_received_calls()[0];
+
+    assert_eq!(received_call.to, TESTER_CONTRACT_ADDRESS);
+    assert_eq!(received_call.value, BigUint::from(0u8));
+    assert_eq!(received_call.data, "ESDTTransfer@5745474c442d616263646566@2386f26fc10000@72657475726e436f6e6361744d756c746942756666657241726773@68656c6c6f@776f726c64");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_interactor_scripted_error() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    let error: ExecutorError = GatewayError::CannotSimulateTransaction.into();
+
+    executor
+        .lock()
+        .await
+        .interactor_mut()
+        .expect_sc_call()
+        .returns_error(error.clone());
+
+    let result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .call(executor, 600000000)
+        .get_sum()
+        .await;
+
+    assert_eq!(result.err(), Some(NovaXError::Executor(error)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_interactor_no_matching_expectation() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    executor
+        .lock()
+        .await
+        .interactor_mut()
+        .expect_sc_call()
+        .with_data("getSum");
+
+    let result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .call(executor.clone(), 600000000)
+        .return_caller()
+        .await;
+
+    let expected = MockTransactionError::NoExpectationMatchingCall {
+        to: TESTER_CONTRACT_ADDRESS.to_string(),
+        data: "returnCaller".to_string(),
+    };
+
+    assert_eq!(result.err(), Some(NovaXError::Executor(expected.into())));
+    assert!(!executor.lock().await.interactor().are_all_expectations_met());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_interactor_sender_address() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    let sender = executor.lock().await.interactor().get_sender_address();
+
+    assert_eq!(sender.to_bech32_string()?, CALLER);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_interactor_sc_call_no_wait() -> Result<(), NovaXError> {
+    let mut executor = get_executor().lock_owned().await;
+
+    let mut response = TransactionOnNetwork::default();
+    response.transaction.hash = "tx_hash".to_string();
+
+    executor
+        .interactor_mut()
+        .expect_sc_call()
+        .with_data("add@0a")
+        .returns(response);
+
+    let tx_hash = executor.sc_call_no_wait(
+        &Address::from_bech32_string(TESTER_CONTRACT_ADDRESS)?,
+        "add".to_string(),
+        vec![vec![10]],
+        600000000,
+        BigUint::from(0u8),
+        vec![]
+    ).await?;
+
+    assert_eq!(tx_hash, "tx_hash");
+    assert_eq!(executor.interactor().get_received_calls().len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_interactor_sc_call_no_wait_dry_run() -> Result<(), NovaXError> {
+    let mut executor = get_executor().lock_owned().await;
+    executor.set_dry_run(true);
+
+    let result = executor.sc_call_no_wait(
+        &Address::from_bech32_string(TESTER_CONTRACT_ADDRESS)?,
+        "add".to_string(),
+        vec![vec![10]],
+        600000000,
+        BigUint::from(0u8),
+        vec![]
+    ).await;
+
+    assert_eq!(result.map_err(|error| error.kind()), Err(ExecutorErrorKind::InvalidInput));
+    assert!(executor.interactor().get_received_calls().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_interactor_with_address_signer() -> Result<(), NovaXError> {
+    let signer = AddressSigner::new(Address::from_bech32_string(CALLER)?);
+    let executor = Arc::new(Mutex::new(BaseTransactionNetworkExecutor::from_interactor(MockInteractor::new(signer))));
+
+    executor
+        .lock()
+        .await
+        .interactor_mut()
+        .expect_sc_call()
+        .returns_data("@6f6b@05");
+
+    let result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .call(executor.clone(), 600000000)
+        .get_sum()
+        .await?;
+
+    let sender = executor.lock().await.interactor().get_sender_address();
+
+    assert_eq!(result.result, Some(BigUint::from(5u8)));
+    assert_eq!(sender.to_bech32_string()?, CALLER);
+
+    Ok(())
+}
@@ -6,7 +6,7 @@ use tokio::sync::Mutex;
 
 use novax::Address;
 use novax::errors::NovaXError;
-use novax::executor::{BaseTransactionNetworkExecutor, BlockchainInteractor, ExecutorError, NetworkExecutor, TokenTransfer, TransactionOnNetwork, TransactionOnNetworkTransaction, TransactionOnNetworkTransactionLogs, TransactionOnNetworkTransactionSmartContractResult, Wallet};
+use novax::executor::{BaseTransactionNetworkExecutor, BatchCall, BlockchainInteractor, ExecutorError, NetworkExecutor, TokenTransfer, TransactionOnNetwork, TransactionOnNetworkTransaction, TransactionOnNetworkTransactionLogs, TransactionOnNetworkTransactionSmartContractResult, Wallet};
 use novax::tester::tester::{CustomEnum, CustomEnumWithFields, CustomEnumWithValues, CustomStruct, CustomStructWithStructAndVec, TesterContract};
 
 mod utils;
@@ -21,6 +21,8 @@ struct MockInteractor {
 
 #[async_trait]
 impl BlockchainInteractor for MockInteractor {
+    type Signer = Wallet;
+
     async fn new(_gateway_url: String, wallet: Wallet) -> Result<Self, ExecutorError> {
         Ok(
             MockInteractor {
@@ -130,12 +132,19 @@ impl BlockchainInteractor for MockInteractor {
 
         let response = TransactionOnNetwork {
             transaction: TransactionOnNetworkTransaction {
+                hash: "".to_string(),
+                sender: "".to_string(),
                 gas_used: 0,
+                fee: None,
+                return_message: None,
                 smart_contract_results: Some(vec![
                     TransactionOnNetworkTransactionSmartContractResult {
                         hash: "".to_string(),
                         nonce: 1,
                         data: return_data,
+                        sender: "".to_string(),
+                        receiver: "".to_string(),
+                        prev_tx_hash: "".to_string(),
                     }
                 ]),
                 status: "success".to_string(),
@@ -208,6 +217,40 @@ async fn test_call_return_caller() -> Result<(), NovaXError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_call_batch() -> Result<(), NovaXError> {
+    let executor = get_executor().await;
+    let contract_address = Address::from_bech32_string(TESTER_CONTRACT_ADDRESS).unwrap();
+
+    let get_call = |function: &str, egld_value: u8, esdt_transfers: Vec<TokenTransfer>| BatchCall {
+        to: contract_address.clone(),
+        function: function.to_string(),
+        arguments: vec![],
+        gas_limit: 600000000,
+        egld_value: BigUint::from(egld_value),
+        esdt_transfers,
+    };
+
+    let calls = vec![
+        get_call("getSum", 0, vec![]),
+        get_call("returnU8", 1, vec![TokenTransfer::fungible("WEGLD-abcdef", BigUint::from(1u8)).unwrap()]),
+        get_call("returnU8", 0, vec![])
+    ];
+
+    let results = executor
+        .lock()
+        .await
+        .sc_call_batch::<u8>(calls)
+        .await;
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().result, Some(5));
+    assert!(results[1].is_err());
+    assert_eq!(results[2].as_ref().unwrap().result, Some(3));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_call_with_biguint_result() -> Result<(), NovaXError> {
     let executor = get_executor().await;
@@ -22,6 +22,8 @@ struct MockInteractor {
 
 #[async_trait]
 impl BlockchainInteractor for MockInteractor {
+    type Signer = Wallet;
+
     async fn new(_gateway_url: String, wallet: Wallet) -> Result<Self, ExecutorError> {
         Ok(
             MockInteractor {
@@ -54,12 +56,19 @@ impl BlockchainInteractor for MockInteractor {
 
         let response = TransactionOnNetwork {
             transaction: TransactionOnNetworkTransaction {
+                hash: "".to_string(),
+                sender: "".to_string(),
                 gas_used: 0,
+                fee: None,
+                return_message: None,
                 smart_contract_results: Some(vec![
                     TransactionOnNetworkTransactionSmartContractResult {
                         hash: "".to_string(),
                         nonce: 1,
                         data: return_data,
+                        sender: "".to_string(),
+                        receiver: "".to_string(),
+                        prev_tx_hash: "".to_string(),
                     }
                 ]),
                 logs: Some(
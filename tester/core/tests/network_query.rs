@@ -3,7 +3,9 @@ use async_trait::async_trait;
 use novax::errors::NovaXError;
 use num_bigint::{BigInt, BigUint};
 use novax::tester::tester::{CustomEnum, CustomEnumWithFields, CustomEnumWithValues, CustomStruct, CustomStructWithStructAndVec, TesterContract};
-use novax::executor::{BlockchainProxy, ExecutorError, QueryNetworkExecutor, VmValuesQueryRequest, VmValuesQueryResponseData, VmValuesQueryResponseDataData};
+use novax::Address;
+use novax::data::RawBytes;
+use novax::executor::{BlockCoordinate, BlockchainProxy, ExecutorError, QueryExecutor, QueryNetworkExecutor, VmValuesQueryRequest, VmValuesQueryResponseData, VmValuesQueryResponseDataData};
 
 const TESTER_CONTRACT_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p";
 
@@ -18,7 +20,9 @@ impl BlockchainProxy for MockProxy {
 
     async fn execute_vmquery(&self, vm_request: &VmValuesQueryRequest) -> Result<VmValuesQueryResponseData, ExecutorError> {
         let mut return_data: Option<Vec<String>> = None;
-        if vm_request.func_name == "getSum" {
+        if vm_request.func_name == "getSum" && vm_request.at_block == Some(BlockCoordinate::Nonce(100)) {
+            return_data = Some(vec!["Ag==".to_string()]);
+        } else if vm_request.func_name == "getSum" {
             return_data = Some(vec!["BQ==".to_string()]);
         } else if vm_request.func_name == "add" {
             if vm_request.args == vec!["0a"] {
@@ -983,4 +987,41 @@ async fn test_query_big_int_arg_result() -> Result<(), NovaXError> {
     assert_eq!(result, expected);
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn test_query_at_block() -> Result<(), NovaXError> {
+    let executor = QueryNetworkExecutor::<MockProxy>::new("".to_string())
+        .with_block(BlockCoordinate::Nonce(100));
+
+    let result = TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .query(Arc::new(executor))
+        .get_sum()
+        .await?;
+
+    assert_eq!(result, BigUint::from(2u8));
+
+    Ok(())
+}
+
+
+#[tokio::test]
+async fn test_query_raw_bytes_result() -> Result<(), NovaXError> {
+    let executor = QueryNetworkExecutor::<MockProxy>::new("".to_string());
+
+    let result = executor.execute::<RawBytes>(
+        &Address::from_bech32_string(TESTER_CONTRACT_ADDRESS)?,
+        "returnManagedBuffer".to_string(),
+        vec![],
+        BigUint::from(0u8),
+        vec![]
+    ).await?;
+
+    let expected = vec!["test".as_bytes().to_vec()];
+
+    assert_eq!(result, expected);
+
+    Ok(())
+}
@@ -8,7 +8,7 @@ use tokio::sync::Mutex;
 
 use novax::Address;
 use novax::errors::NovaXError;
-use novax::executor::{BaseSimulationNetworkExecutor, SimulationNetworkExecutor};
+use novax::executor::{BaseSimulationNetworkExecutor, SimulationNetworkExecutor, TransactionExecutor};
 use novax::tester::tester::TesterContract;
 use novax_request::error::request::RequestError;
 use novax_request::gateway::client::GatewayClient;
@@ -91,7 +91,8 @@ impl GatewayClient for MockClient {
 
         let result = if data == r#"{"nonce":5,"value":"0","receiver":"erd1qqqqqqqqqqqqqpgq7x53hfeg9558dmzjg9lqyfar77z8wrxf5u7qrawwh0","sender":"erd1uh67c2lkhyj4vh73akv7jky9sfgvus8awwcj64uju69mmfne5u7q299t7g","gasPrice":1000000000,"gasLimit":600000000,"data":"cmV0dXJuQ2FsbGVy","chainId":"D","version":1}"# {
             get_return_caller_simulation_data()
-        } else if data == r#"{"nonce":5,"value":"0","receiver":"erd1qqqqqqqqqqqqqpgq7x53hfeg9558dmzjg9lqyfar77z8wrxf5u7qrawwh0","sender":"erd1uh67c2lkhyj4vh73akv7jky9sfgvus8awwcj64uju69mmfne5u7q299t7g","gasPrice":1000000000,"gasLimit":600000000,"data":"YWRkQDBh","chainId":"D","version":1}"# {
+        } else if data == r#"{"nonce":5,"value":"0","receiver":"erd1qqqqqqqqqqqqqpgq7x53hfeg9558dmzjg9lqyfar77z8wrxf5u7qrawwh0","sender":"erd1uh67c2lkhyj4vh73akv7jky9sfgvus8awwcj64uju69mmfne5u7q299t7g","gasPrice":1000000000,"gasLimit":600000000,"data":"YWRkQDBh","chainId":"D","version":1}"#
+            || data == r#"{"nonce":5,"value":"0","receiver":"erd1qqqqqqqqqqqqqpgq7x53hfeg9558dmzjg9lqyfar77z8wrxf5u7qrawwh0","sender":"erd1uh67c2lkhyj4vh73akv7jky9sfgvus8awwcj64uju69mmfne5u7q299t7g","gasPrice":1000000000,"gasLimit":0,"data":"YWRkQDBh","chainId":"D","version":1}"# {
             get_return_biguint_argument_simulation_data()
         } else {
             unreachable!()
@@ -142,6 +143,8 @@ async fn test_call_return_caller() -> Result<(), NovaXError> {
         .await?;
 
     assert!(result.response.is_success());
+    assert_eq!(result.response.transaction.gas_used, 2384920);
+    assert_eq!(result.response.transaction.smart_contract_results.as_ref().map(|scrs| scrs.len()), Some(1));
     assert_eq!(result.result, Some(Address::from_bech32_string(CALLER).unwrap()));
 
     Ok(())
@@ -163,4 +166,21 @@ async fn test_call_with_biguint_argument() -> Result<(), NovaXError> {
     Ok(())
 }
 
-// We don't need more tests for this executor
\ No newline at end of file
+#[tokio::test]
+async fn test_estimate_gas() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    let gas = executor.estimate_gas(
+        &Address::from_bech32_string(TESTER_CONTRACT_ADDRESS).unwrap(),
+        "add".to_string(),
+        vec![vec![10]],
+        BigUint::from(0u8),
+        vec![]
+    ).await?;
+
+    assert_eq!(gas, 2442787);
+
+    Ok(())
+}
+
+// We don't need more tests for this executor
@@ -10,6 +10,9 @@ pub enum TokenError {
     CannotParseEsdtBalances { address: String },
     UnableToParseBigUintBalanceForTokenAndAddress { token_identifier: String, address: String, balance: String },
     InvalidTokenIdentifier { identifier: String },
+    NoIssuedTokenIdentifierInTheResponse { tx_hash: String },
+    NoCreatedTokenNonceInTheResponse { tx_hash: String },
+    UnknownSenderAddress,
     NestedAppError(NovaXError)
 }
 
@@ -1,6 +1,7 @@
 pub mod properties;
 pub mod error;
 pub mod account;
+pub mod management;
 
 #[cfg(test)]
 pub(crate) mod mock;
@@ -0,0 +1,534 @@
+use async_trait::async_trait;
+use multiversx_sc::types::EsdtLocalRole;
+use num_bigint::BigUint;
+use novax::errors::NovaXError;
+use novax::executor::{TransactionExecutor, TransactionOnNetwork, TransferGasConfig};
+use novax::executor::call_result::CallResult;
+use novax_data::Address;
+use crate::error::token::TokenError;
+use crate::management::properties::FungibleTokenProperties;
+
+/// The EGLD amount to pay when issuing a token: 0.05 EGLD.
+pub const ESDT_ISSUE_COST: u64 = 50_000_000_000_000_000;
+
+/// The gas limit of the calls to the ESDT system smart contract.
+pub const ESDT_SYSTEM_SC_GAS_LIMIT: u64 = 60_000_000;
+
+/// The gas limit of an `ESDTLocalMint` or `ESDTLocalBurn` call, minting or burning a fungible token.
+pub const ESDT_LOCAL_MINT_BURN_GAS_LIMIT: u64 = 300_000;
+
+/// The gas limit of an `ESDTNFTAddQuantity` call, minting more units of a semi-fungible or meta ESDT.
+pub const ESDT_NFT_ADD_QUANTITY_GAS_LIMIT: u64 = 10_000_000;
+
+/// The gas limit of an `ESDTNFTBurn` call, burning units of a non-fungible, semi-fungible or meta ESDT.
+pub const ESDT_NFT_BURN_GAS_LIMIT: u64 = 1_000_000;
+
+/// The base gas limit of an `ESDTNFTCreate` call, creating a non-fungible, semi-fungible or meta ESDT.
+pub const ESDT_NFT_CREATE_GAS_LIMIT: u64 = 3_000_000;
+
+/// The gas consumed by each byte stored by an `ESDTNFTCreate` call: the name, royalties, hash, attributes and URIs of the created token.
+pub const ESDT_NFT_CREATE_GAS_PER_STORED_BYTE: u64 = 10_000;
+
+/// High-level helpers to issue and manage tokens through the ESDT system smart contract, available on any `TransactionExecutor`.
+#[async_trait]
+pub trait EsdtManager {
+    /// Issues a new fungible token, paying the issuance cost, and returns its identifier, such as "TICKER-abcdef".
+    ///
+    /// The whole `supply` is sent to the issuer. `supply` is expressed in the smallest unit: a supply of 1,000 tokens with 18 decimals is 1,000 * 10^18.
+    async fn issue_fungible(
+        &mut self,
+        name: &str,
+        ticker: &str,
+        supply: BigUint,
+        decimals: u32,
+        properties: FungibleTokenProperties
+    ) -> Result<String, TokenError>;
+
+    /// Gives `roles` on `token` to `address`, such as the local mint and burn roles. The sender must be the token's owner.
+    async fn set_special_roles(
+        &mut self,
+        token: &str,
+        address: &Address,
+        roles: Vec<EsdtLocalRole>
+    ) -> Result<(), TokenError>;
+
+    /// Mints `amount` of `token` into the sender's account, through the `ESDTLocalMint` built-in function,
+    /// or `ESDTNFTAddQuantity` if `nonce` isn't zero. The sender must have the matching role on the token.
+    ///
+    /// Returns `TokenError::UnknownSenderAddress` if the executor doesn't know its sender address, the call being sent to the sender itself.
+    async fn local_mint(
+        &mut self,
+        token: &str,
+        nonce: u64,
+        amount: BigUint
+    ) -> Result<(), TokenError>;
+
+    /// Burns `amount` of `token` from the sender's account, through the `ESDTLocalBurn` built-in function,
+    /// or `ESDTNFTBurn` if `nonce` isn't zero. The sender must have the matching role on the token.
+    ///
+    /// Returns `TokenError::UnknownSenderAddress` if the executor doesn't know its sender address, the call being sent to the sender itself.
+    async fn local_burn(
+        &mut self,
+        token: &str,
+        nonce: u64,
+        amount: BigUint
+    ) -> Result<(), TokenError>;
+
+    /// Creates `amount` units of a new nonce of `token`, a non-fungible, semi-fungible or meta ESDT, through the `ESDTNFTCreate` built-in function,
+    /// and returns the created nonce, read from the "ESDTNFTCreate" event. The sender must have the `ESDTRoleNFTCreate` role on the token.
+    ///
+    /// `royalties` are expressed in basis points, 10,000 being 100%. The gas limit grows with the size of the stored name, royalties, hash, attributes and URIs.
+    /// Returns `TokenError::UnknownSenderAddress` if the executor doesn't know its sender address, the call being sent to the sender itself.
+    #[allow(clippy::too_many_arguments)]
+    async fn nft_create(
+        &mut self,
+        token: &str,
+        amount: BigUint,
+        name: &str,
+        royalties: u32,
+        hash: &[u8],
+        attributes: &[u8],
+        uris: &[String]
+    ) -> Result<u64, TokenError>;
+}
+
+#[async_trait]
+impl<Executor: TransactionExecutor> EsdtManager for Executor {
+    async fn issue_fungible(
+        &mut self,
+        name: &str,
+        ticker: &str,
+        supply: BigUint,
+        decimals: u32,
+        properties: FungibleTokenProperties
+    ) -> Result<String, TokenError> {
+        let mut arguments = vec![
+            name.as_bytes().to_vec(),
+            ticker.as_bytes().to_vec(),
+            encode_biguint(&supply),
+            encode_biguint(&BigUint::from(decimals))
+        ];
+        arguments.append(&mut properties.to_arguments());
+
+        let result = self.sc_call::<()>(
+            &Address::esdt_system_sc(),
+            "issue".to_string(),
+            arguments,
+            ESDT_SYSTEM_SC_GAS_LIMIT,
+            BigUint::from(ESDT_ISSUE_COST),
+            vec![]
+        )
+            .await
+            .map_err(NovaXError::from)?;
+
+        find_issued_token_identifier(&result)
+    }
+
+    async fn set_special_roles(
+        &mut self,
+        token: &str,
+        address: &Address,
+        roles: Vec<EsdtLocalRole>
+    ) -> Result<(), TokenError> {
+        let mut arguments = vec![
+            token.as_bytes().to_vec(),
+            address.to_bytes().to_vec()
+        ];
+        arguments.extend(roles.iter().map(|role| role.as_role_name().to_vec()));
+
+        self.sc_call::<()>(
+            &Address::esdt_system_sc(),
+            "setSpecialRole".to_string(),
+            arguments,
+            ESDT_SYSTEM_SC_GAS_LIMIT,
+            BigUint::from(0u8),
+            vec![]
+        )
+            .await
+            .map_err(NovaXError::from)?;
+
+        Ok(())
+    }
+
+    async fn local_mint(
+        &mut self,
+        token: &str,
+        nonce: u64,
+        amount: BigUint
+    ) -> Result<(), TokenError> {
+        let (function, gas_limit) = if nonce == 0 {
+            ("ESDTLocalMint", ESDT_LOCAL_MINT_BURN_GAS_LIMIT)
+        } else {
+            ("ESDTNFTAddQuantity", ESDT_NFT_ADD_QUANTITY_GAS_LIMIT)
+        };
+
+        send_to_sender(self, function, get_local_mint_burn_arguments(token, nonce, &amount), gas_limit).await?;
+
+        Ok(())
+    }
+
+    async fn local_burn(
+        &mut self,
+        token: &str,
+        nonce: u64,
+        amount: BigUint
+    ) -> Result<(), TokenError> {
+        let (function, gas_limit) = if nonce == 0 {
+            ("ESDTLocalBurn", ESDT_LOCAL_MINT_BURN_GAS_LIMIT)
+        } else {
+            ("ESDTNFTBurn", ESDT_NFT_BURN_GAS_LIMIT)
+        };
+
+        send_to_sender(self, function, get_local_mint_burn_arguments(token, nonce, &amount), gas_limit).await?;
+
+        Ok(())
+    }
+
+    async fn nft_create(
+        &mut self,
+        token: &str,
+        amount: BigUint,
+        name: &str,
+        royalties: u32,
+        hash: &[u8],
+        attributes: &[u8],
+        uris: &[String]
+    ) -> Result<u64, TokenError> {
+        let mut arguments = vec![
+            token.as_bytes().to_vec(),
+            encode_biguint(&amount),
+            name.as_bytes().to_vec(),
+            encode_biguint(&BigUint::from(royalties)),
+            hash.to_vec(),
+            attributes.to_vec()
+        ];
+        arguments.extend(uris.iter().map(|uri| uri.as_bytes().to_vec()));
+
+        let function = "ESDTNFTCreate";
+        let gas_config = self.get_transfer_gas_config().await;
+        let gas_limit = get_nft_create_gas_limit(&gas_config, function, &arguments);
+
+        let result = send_to_sender(self, function, arguments, gas_limit).await?;
+
+        find_created_token_nonce(&result)
+    }
+}
+
+/// Calls the built-in `function` on the sender's own account, as required by the functions acting on its balance.
+async fn send_to_sender<Executor: TransactionExecutor>(
+    executor: &mut Executor,
+    function: &str,
+    arguments: Vec<Vec<u8>>,
+    gas_limit: u64
+) -> Result<CallResult<()>, TokenError> {
+    let Some(sender) = executor.get_sender_address().await else {
+        return Err(TokenError::UnknownSenderAddress)
+    };
+
+    let result = executor.sc_call::<()>(
+        &sender,
+        function.to_string(),
+        arguments,
+        gas_limit,
+        BigUint::from(0u8),
+        vec![]
+    )
+        .await
+        .map_err(NovaXError::from)?;
+
+    Ok(result)
+}
+
+/// Returns the gas limit of an `ESDTNFTCreate` call: the base cost, the cost of the stored bytes, every argument but the token and the amount,
+/// and the cost of the data field and the sender's extra gas on the network described by `gas_config`.
+fn get_nft_create_gas_limit(gas_config: &TransferGasConfig, function: &str, arguments: &[Vec<u8>]) -> u64 {
+    let stored_bytes: usize = arguments.iter()
+        .skip(2)
+        .map(Vec::len)
+        .sum();
+
+    let data_bytes: usize = function.len() + arguments.iter()
+        .map(|argument| 1 + argument.len() * 2)
+        .sum::<usize>();
+
+    ESDT_NFT_CREATE_GAS_LIMIT
+        + stored_bytes as u64 * ESDT_NFT_CREATE_GAS_PER_STORED_BYTE
+        + data_bytes as u64 * gas_config.gas_per_data_byte
+        + gas_config.extra_gas_limit
+}
+
+/// Returns the arguments of a local mint or burn: the token, its nonce for non-fungible tokens, and the amount.
+fn get_local_mint_burn_arguments(token: &str, nonce: u64, amount: &BigUint) -> Vec<Vec<u8>> {
+    let mut arguments = vec![token.as_bytes().to_vec()];
+
+    if nonce != 0 {
+        arguments.push(encode_biguint(&BigUint::from(nonce)));
+    }
+
+    arguments.push(encode_biguint(amount));
+
+    arguments
+}
+
+/// Returns the identifier of the issued token, which is the first topic of the "issue" event.
+fn find_issued_token_identifier(result: &CallResult<()>) -> Result<String, TokenError> {
+    let topics = result.topics_of("issue").map_err(NovaXError::from)?;
+
+    let Some(identifier) = topics.first() else {
+        return Err(TokenError::NoIssuedTokenIdentifierInTheResponse { tx_hash: get_tx_hash(&result.response) })
+    };
+
+    String::from_utf8(identifier.clone())
+        .map_err(|_| TokenError::NoIssuedTokenIdentifierInTheResponse { tx_hash: get_tx_hash(&result.response) })
+}
+
+/// Returns the nonce of the created token, which is the second topic of the "ESDTNFTCreate" event, after the token identifier.
+fn find_created_token_nonce(result: &CallResult<()>) -> Result<u64, TokenError> {
+    let topics = result.topics_of("ESDTNFTCreate").map_err(NovaXError::from)?;
+
+    let Some(nonce) = topics.get(1).filter(|nonce| !nonce.is_empty() && nonce.len() <= 8) else {
+        return Err(TokenError::NoCreatedTokenNonceInTheResponse { tx_hash: get_tx_hash(&result.response) })
+    };
+
+    Ok(nonce.iter().fold(0u64, |acc, byte| (acc << 8) | *byte as u64))
+}
+
+fn get_tx_hash(response: &TransactionOnNetwork) -> String {
+    response.transaction.hash.clone()
+}
+
+/// Encodes a `BigUint` as the ESDT system smart contract expects it, zero being encoded as an empty argument.
+fn encode_biguint(value: &BigUint) -> Vec<u8> {
+    if *value == BigUint::from(0u8) {
+        vec![]
+    } else {
+        value.to_bytes_be()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use multiversx_sc::types::EsdtLocalRole;
+    use num_bigint::BigUint;
+    use novax::executor::{DummyExecutor, DummyTransactionExecutor, SendableTransaction, TransactionOnNetwork, TransactionOnNetworkTransactionLogs, TransactionOnNetworkTransactionLogsEvents, TransferGasConfig};
+    use novax::executor::call_result::CallResult;
+    use novax_data::Address;
+    use crate::error::token::TokenError;
+    use crate::management::manager::{EsdtManager, find_created_token_nonce, find_issued_token_identifier, get_nft_create_gas_limit};
+    use crate::management::properties::FungibleTokenProperties;
+
+    const CALLER: &str = "erd1h4uhy73dev6qrfj7wxsguapzs8632mfwqjswjpsj6kzm2jfrnslqsuduqu";
+
+    fn get_executor() -> DummyTransactionExecutor {
+        DummyExecutor::new(&Some(Address::from_bech32_string(CALLER).unwrap()))
+    }
+
+    #[tokio::test]
+    async fn test_issue_fungible() {
+        let mut executor = get_executor();
+
+        let result = executor.issue_fungible(
+            "Test",
+            "TEST",
+            BigUint::from(1000u16),
+            18,
+            FungibleTokenProperties::default()
+        ).await;
+
+        let expected_tx = SendableTransaction {
+            receiver: Address::esdt_system_sc().to_bech32_string().unwrap(),
+            egld_value: BigUint::from(50000000000000000u64),
+            gas_limit: 60000000,
+            data: "issue@54657374@54455354@03e8@12@63616e467265657a65@66616c7365@63616e57697065@66616c7365@63616e5061757365@66616c7365@63616e4368616e67654f776e6572@66616c7365@63616e55706772616465@74727565@63616e4164645370656369616c526f6c6573@74727565".to_string(),
+        };
+
+        // The dummy executor doesn't execute anything, so there is no "issue" event to read the identifier from.
+        assert_eq!(result, Err(TokenError::NoIssuedTokenIdentifierInTheResponse { tx_hash: "".to_string() }));
+        assert_eq!(executor.get_transaction_details().unwrap(), expected_tx);
+    }
+
+    #[tokio::test]
+    async fn test_set_special_roles() {
+        let mut executor = get_executor();
+
+        executor.set_special_roles(
+            "TEST-abcdef",
+            &Address::from_bech32_string(CALLER).unwrap(),
+            vec![EsdtLocalRole::Mint, EsdtLocalRole::Burn]
+        ).await.unwrap();
+
+        let expected_tx = SendableTransaction {
+            receiver: Address::esdt_system_sc().to_bech32_string().unwrap(),
+            egld_value: BigUint::from(0u8),
+            gas_limit: 60000000,
+            data: "setSpecialRole@544553542d616263646566@bd79727a2dcb3401a65e71a08e742281f5156d2e04a0e90612d585b549239c3e@45534454526f6c654c6f63616c4d696e74@45534454526f6c654c6f63616c4275726e".to_string(),
+        };
+
+        assert_eq!(executor.get_transaction_details().unwrap(), expected_tx);
+    }
+
+    #[test]
+    fn test_find_issued_token_identifier() {
+        let mut response = TransactionOnNetwork::default();
+        response.transaction.logs = Some(TransactionOnNetworkTransactionLogs {
+            address: CALLER.to_string(),
+            events: vec![
+                TransactionOnNetworkTransactionLogsEvents {
+                    address: CALLER.to_string(),
+                    identifier: "issue".to_string(),
+                    topics: vec!["VEVTVC1hYmNkZWY=".to_string(), "VGVzdA==".to_string(), "VEVTVA==".to_string(), "RnVuZ2libGVFU0RU".to_string(), "Eg==".to_string()],
+                    data: None,
+                }
+            ],
+        });
+
+        let result = CallResult {
+            response,
+            result: None,
+        };
+
+        assert_eq!(find_issued_token_identifier(&result), Ok("TEST-abcdef".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_local_mint() {
+        let mut executor = get_executor();
+
+        executor.local_mint("TEST-abcdef", 0, BigUint::from(1000u16)).await.unwrap();
+
+        let expected_tx = SendableTransaction {
+            receiver: CALLER.to_string(),
+            egld_value: BigUint::from(0u8),
+            gas_limit: 300000,
+            data: "ESDTLocalMint@544553542d616263646566@03e8".to_string(),
+        };
+
+        assert_eq!(executor.get_transaction_details().unwrap(), expected_tx);
+    }
+
+    #[tokio::test]
+    async fn test_local_mint_with_nonce() {
+        let mut executor = get_executor();
+
+        executor.local_mint("SFT-abcdef", 10, BigUint::from(5u8)).await.unwrap();
+
+        let expected_tx = SendableTransaction {
+            receiver: CALLER.to_string(),
+            egld_value: BigUint::from(0u8),
+            gas_limit: 10000000,
+            data: "ESDTNFTAddQuantity@5346542d616263646566@0a@05".to_string(),
+        };
+
+        assert_eq!(executor.get_transaction_details().unwrap(), expected_tx);
+    }
+
+    #[tokio::test]
+    async fn test_local_burn() {
+        let mut executor = get_executor();
+
+        executor.local_burn("TEST-abcdef", 0, BigUint::from(1000u16)).await.unwrap();
+
+        let expected_tx = SendableTransaction {
+            receiver: CALLER.to_string(),
+            egld_value: BigUint::from(0u8),
+            gas_limit: 300000,
+            data: "ESDTLocalBurn@544553542d616263646566@03e8".to_string(),
+        };
+
+        assert_eq!(executor.get_transaction_details().unwrap(), expected_tx);
+    }
+
+    #[tokio::test]
+    async fn test_local_burn_with_nonce() {
+        let mut executor = get_executor();
+
+        executor.local_burn("SFT-abcdef", 10, BigUint::from(5u8)).await.unwrap();
+
+        let expected_tx = SendableTransaction {
+            receiver: CALLER.to_string(),
+            egld_value: BigUint::from(0u8),
+            gas_limit: 1000000,
+            data: "ESDTNFTBurn@5346542d616263646566@0a@05".to_string(),
+        };
+
+        assert_eq!(executor.get_transaction_details().unwrap(), expected_tx);
+    }
+
+    #[tokio::test]
+    async fn test_local_mint_unknown_sender() {
+        let mut executor: DummyTransactionExecutor = DummyExecutor::new(&None);
+
+        let result = executor.local_mint("TEST-abcdef", 0, BigUint::from(1000u16)).await;
+
+        assert_eq!(result, Err(TokenError::UnknownSenderAddress));
+    }
+
+    #[tokio::test]
+    async fn test_nft_create() {
+        let mut executor = get_executor();
+
+        let result = executor.nft_create(
+            "NFT-abcdef",
+            BigUint::from(1u8),
+            "Art",
+            500,
+            &[0xab, 0xcd],
+            b"tags:art",
+            &["https://example.com/1.png".to_string()]
+        ).await;
+
+        let data = "ESDTNFTCreate@4e46542d616263646566@01@417274@01f4@abcd@746167733a617274@68747470733a2f2f6578616d706c652e636f6d2f312e706e67";
+        // 3 + 2 + 2 + 8 + 25 bytes stored: the name, the royalties, the hash, the attributes and the URI.
+        let expected_gas_limit = 3_000_000 + 40 * 10_000 + data.len() as u64 * 1_500;
+
+        let expected_tx = SendableTransaction {
+            receiver: CALLER.to_string(),
+            egld_value: BigUint::from(0u8),
+            gas_limit: expected_gas_limit,
+            data: data.to_string(),
+        };
+
+        // The dummy executor doesn't execute anything, so there is no "ESDTNFTCreate" event to read the nonce from.
+        assert_eq!(result, Err(TokenError::NoCreatedTokenNonceInTheResponse { tx_hash: "".to_string() }));
+        assert_eq!(executor.get_transaction_details().unwrap(), expected_tx);
+    }
+
+    #[test]
+    fn test_get_nft_create_gas_limit_from_network_gas_config() {
+        let gas_config = TransferGasConfig {
+            min_gas_limit: 50_000,
+            gas_per_data_byte: 2_000,
+            extra_gas_limit: 50_000,
+        };
+
+        let arguments = vec![b"NFT-abcdef".to_vec(), vec![1], b"Art".to_vec()];
+
+        // "ESDTNFTCreate@4e46542d616263646566@01@417274" is 44 bytes long, the name being the only stored argument.
+        let gas_limit = get_nft_create_gas_limit(&gas_config, "ESDTNFTCreate", &arguments);
+
+        assert_eq!(gas_limit, 3_000_000 + 3 * 10_000 + 44 * 2_000 + 50_000);
+    }
+
+    #[test]
+    fn test_find_created_token_nonce() {
+        let mut response = TransactionOnNetwork::default();
+        response.transaction.logs = Some(TransactionOnNetworkTransactionLogs {
+            address: CALLER.to_string(),
+            events: vec![
+                TransactionOnNetworkTransactionLogsEvents {
+                    address: CALLER.to_string(),
+                    identifier: "ESDTNFTCreate".to_string(),
+                    topics: vec!["TkZULWFiY2RlZg==".to_string(), "AQI=".to_string(), "AQ==".to_string(), "".to_string()],
+                    data: None,
+                }
+            ],
+        });
+
+        let result = CallResult {
+            response,
+            result: None,
+        };
+
+        assert_eq!(find_created_token_nonce(&result), Ok(258));
+    }
+}
@@ -0,0 +1,45 @@
+/// The properties of a fungible token, set when issuing it.
+///
+/// The default properties are the ones of most tokens: the token can be upgraded and special roles can be added to it,
+/// but it cannot be frozen, wiped, paused and its ownership cannot be transferred.
+#[derive(Clone, PartialEq, Debug)]
+pub struct FungibleTokenProperties {
+    pub can_freeze: bool,
+    pub can_wipe: bool,
+    pub can_pause: bool,
+    pub can_change_owner: bool,
+    pub can_upgrade: bool,
+    pub can_add_special_roles: bool,
+}
+
+impl Default for FungibleTokenProperties {
+    fn default() -> Self {
+        FungibleTokenProperties {
+            can_freeze: false,
+            can_wipe: false,
+            can_pause: false,
+            can_change_owner: false,
+            can_upgrade: true,
+            can_add_special_roles: true,
+        }
+    }
+}
+
+impl FungibleTokenProperties {
+    /// Returns the properties as the name and value pairs expected by the ESDT system smart contract, such as `canFreeze` and `true`.
+    pub(crate) fn to_arguments(&self) -> Vec<Vec<u8>> {
+        let properties = [
+            ("canFreeze", self.can_freeze),
+            ("canWipe", self.can_wipe),
+            ("canPause", self.can_pause),
+            ("canChangeOwner", self.can_change_owner),
+            ("canUpgrade", self.can_upgrade),
+            ("canAddSpecialRoles", self.can_add_special_roles),
+        ];
+
+        properties
+            .into_iter()
+            .flat_map(|(name, value)| [name.as_bytes().to_vec(), value.to_string().into_bytes()])
+            .collect()
+    }
+}